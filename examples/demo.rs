@@ -6,12 +6,13 @@ use egui::ViewportBuilder;
 //use egui_dock::{DockArea, DockState, NodeIndex, Style, SurfaceIndex};
 
 /// Demo application showcasing Mobius-ECS framework
-#[derive(Default)]
 pub struct MobiusDemoApp {
     world: World,
     active_tab: TabType,
     app_entity: Option<Entity>,
     current_template: String,
+    tabs: Vec<TemplateTab>,
+    plugins: MobiusPlugins,
 }
 
 impl MobiusDemoApp {
@@ -20,15 +21,108 @@ impl MobiusDemoApp {
     }
 }
 
+impl Default for MobiusDemoApp {
+    fn default() -> Self {
+        Self {
+            world: World::default(),
+            active_tab: TabType::default(),
+            app_entity: None,
+            current_template: String::new(),
+            tabs: Vec::new(),
+            plugins: MobiusPlugins::new().add_plugin(CorePlugin).add_plugin(DemoPlugin),
+        }
+    }
+}
+
+/// Explanations and test buttons on top of the standard tabs
+struct DemoPlugin;
+
+impl MobiusPlugin for DemoPlugin {
+    fn name(&self) -> &str {
+        "demo"
+    }
+
+    fn build(&self, plugins: &mut MobiusPlugins) {
+        plugins
+            .add_tab(TabType::MainWork, "📋 Main", |ui, world| {
+                show_main_work_area(ui, world);
+                
+                // Add some demo-specific content
+                ui.separator();
+                ui.collapsing("💡 Demo Info", |ui| {
+                    ui.label("This is the main work area where your application content goes.");
+                    ui.label("In a real application, this might contain:");
+                    ui.label("• Canvas for drawing/viewing");
+                    ui.label("• Text editor");
+                    ui.label("• 3D viewport");
+                    ui.label("• Data tables");
+                    ui.label("• etc.");
+                });
+            })
+            .add_tab(TabType::EventLogger, "📜 Logs", |ui, world| {
+                show_event_logger_panel(ui, world);
+                
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button("➕ Add Test Log").clicked() {
+                        add_log(world, LogLevel::Info, "Test log entry added by user");
+                    }
+                    if ui.button("⚠️ Add Warning").clicked() {
+                        add_log(world, LogLevel::Warn, "This is a warning message");
+                    }
+                    if ui.button("❌ Add Error").clicked() {
+                        add_log(world, LogLevel::Error, "This is an error message");
+                    }
+                });
+            })
+            .add_tab(TabType::Controls, "🎛️ Controls", |ui, world| {
+                show_controls_panel(ui, world);
+                
+                ui.separator();
+                ui.collapsing("💡 Controls Info", |ui| {
+                    ui.label("Controls panels contain action buttons and tools.");
+                    ui.label("These are defined in the template and can be customized.");
+                    ui.label("In a real app, these might trigger commands, open dialogs, etc.");
+                });
+            })
+            .add_settings_section("💡 Settings Info", |ui, _world| {
+                ui.label("Settings are automatically saved in the ECS world.");
+                ui.label("Changes here update the underlying components.");
+                ui.label("Try changing the units or clock format above!");
+            })
+            .add_system(trim_logs);
+    }
+}
+
+fn add_log(world: &mut World, level: LogLevel, message: &str) {
+    let mut query = world.query::<&mut EventLoggerPanel>();
+    if let Some(mut logger) = query.iter_mut(world).next() {
+        logger.entries.push(LogEntry {
+            timestamp: chrono::Local::now().format("%H:%M:%S").to_string(),
+            level,
+            message: message.to_string(),
+        });
+    }
+}
+
+/// Drop the oldest entries beyond the logger's limit
+fn trim_logs(world: &mut World) {
+    let mut query = world.query::<&mut EventLoggerPanel>();
+    for mut logger in query.iter_mut(world) {
+        let excess = logger.entries.len().saturating_sub(logger.max_entries);
+        if excess > 0 {
+            logger.entries.drain(..excess);
+        }
+    }
+}
+
 impl App for MobiusDemoApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut Frame) {
         // Initialize if needed
         if self.app_entity.is_none() {
-            let registry = MobiusTemplateRegistry::default();
-            let mut commands = self.world.commands();
-            self.app_entity = registry.spawn_from_template(&mut commands, "gerber_viewer");
-            self.current_template = "gerber_viewer".to_string();
+            self.switch_template("gerber_viewer");
         }
+        self.plugins.run_systems(&mut self.world);
         
         // Top menu bar
         egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
@@ -63,10 +157,9 @@ impl App for MobiusDemoApp {
                 });
                 
                 ui.menu_button("View", |ui| {
-                    ui.selectable_value(&mut self.active_tab, TabType::MainWork, "📋 Main Work Area");
-                    ui.selectable_value(&mut self.active_tab, TabType::Settings, "⚙️ Settings");
-                    ui.selectable_value(&mut self.active_tab, TabType::EventLogger, "📜 Event Logger");
-                    ui.selectable_value(&mut self.active_tab, TabType::Controls, "🎛️ Controls");
+                    for tab in &self.tabs {
+                        ui.selectable_value(&mut self.active_tab, tab.tab_type.clone(), &tab.title);
+                    }
                 });
                 
                 ui.separator();
@@ -77,10 +170,9 @@ impl App for MobiusDemoApp {
         // Tab bar
         egui::TopBottomPanel::top("tab_panel").show(ctx, |ui| {
             ui.horizontal(|ui| {
-                ui.selectable_value(&mut self.active_tab, TabType::MainWork, "📋 Main");
-                ui.selectable_value(&mut self.active_tab, TabType::Settings, "⚙️ Settings");
-                ui.selectable_value(&mut self.active_tab, TabType::EventLogger, "📜 Logs");
-                ui.selectable_value(&mut self.active_tab, TabType::Controls, "🎛️ Controls");
+                for tab in &self.tabs {
+                    ui.selectable_value(&mut self.active_tab, tab.tab_type.clone(), &tab.title);
+                }
                 
                 ui.separator();
                 ui.label("👆 Click tabs to switch views");
@@ -105,62 +197,7 @@ impl App for MobiusDemoApp {
 
         // Main content area
         egui::CentralPanel::default().show(ctx, |ui| {
-            match &self.active_tab {
-                TabType::MainWork => {
-                    show_main_work_area(ui, &mut self.world);
-                    
-                    // Add some demo-specific content
-                    ui.separator();
-                    ui.collapsing("💡 Demo Info", |ui| {
-                        ui.label("This is the main work area where your application content goes.");
-                        ui.label("In a real application, this might contain:");
-                        ui.label("• Canvas for drawing/viewing");
-                        ui.label("• Text editor");
-                        ui.label("• 3D viewport");
-                        ui.label("• Data tables");
-                        ui.label("• etc.");
-                    });
-                }
-                TabType::Settings => {
-                    show_settings_panel(ui, &mut self.world);
-                    
-                    ui.separator();
-                    ui.collapsing("💡 Settings Info", |ui| {
-                        ui.label("Settings are automatically saved in the ECS world.");
-                        ui.label("Changes here update the underlying components.");
-                        ui.label("Try changing the units or clock format above!");
-                    });
-                }
-                TabType::EventLogger => {
-                    show_event_logger_panel(ui, &mut self.world);
-                    
-                    ui.separator();
-                    ui.horizontal(|ui| {
-                        if ui.button("➕ Add Test Log").clicked() {
-                            self.add_test_log();
-                        }
-                        if ui.button("⚠️ Add Warning").clicked() {
-                            self.add_warning_log();
-                        }
-                        if ui.button("❌ Add Error").clicked() {
-                            self.add_error_log();
-                        }
-                    });
-                }
-                TabType::Controls => {
-                    show_controls_panel(ui, &mut self.world);
-                    
-                    ui.separator();
-                    ui.collapsing("💡 Controls Info", |ui| {
-                        ui.label("Controls panels contain action buttons and tools.");
-                        ui.label("These are defined in the template and can be customized.");
-                        ui.label("In a real app, these might trigger commands, open dialogs, etc.");
-                    });
-                }
-                TabType::Generic(name) => {
-                    show_generic_tab(ui, &self.world, name);
-                }
-            }
+            self.plugins.show_tab(ui, &mut self.world, &self.active_tab);
         });
     }
 }
@@ -173,41 +210,16 @@ impl MobiusDemoApp {
         let mut commands = self.world.commands();
         self.app_entity = registry.spawn_from_template(&mut commands, template_name);
         self.current_template = template_name.to_string();
-        self.active_tab = TabType::MainWork;
-    }
-    
-    fn add_test_log(&mut self) {
-        let mut query = self.world.query::<&mut EventLoggerPanel>();
-        if let Some(mut logger) = query.iter_mut(&mut self.world).next() {
-            logger.entries.push(LogEntry {
-                timestamp: chrono::Local::now().format("%H:%M:%S").to_string(),
-                level: LogLevel::Info,
-                message: "Test log entry added by user".to_string(),
-            });
-        }
-    }
-    
-    fn add_warning_log(&mut self) {
-        let mut query = self.world.query::<&mut EventLoggerPanel>();
-        if let Some(mut logger) = query.iter_mut(&mut self.world).next() {
-            logger.entries.push(LogEntry {
-                timestamp: chrono::Local::now().format("%H:%M:%S").to_string(),
-                level: LogLevel::Warn,
-                message: "This is a warning message".to_string(),
-            });
-        }
-    }
-    
-    fn add_error_log(&mut self) {
-        let mut query = self.world.query::<&mut EventLoggerPanel>();
-        if let Some(mut logger) = query.iter_mut(&mut self.world).next() {
-            logger.entries.push(LogEntry {
-                timestamp: chrono::Local::now().format("%H:%M:%S").to_string(),
-                level: LogLevel::Error,
-                message: "This is an error message".to_string(),
-            });
+        self.world.flush();
+
+        // Tabs in the template's order, starting on its first
+        if let Some(app) = self.app_entity.and_then(|entity| self.world.get::<MobiusApp>(entity)) {
+            self.tabs = self.plugins.app_tabs(&app.tabs);
+            self.active_tab = app.active_tab.clone();
         }
+        self.plugins.spawn(&mut self.world);
     }
+
 }
 
 fn main() -> Result<(), eframe::Error> {
@@ -9,6 +9,8 @@ pub struct UiButtonBundle {
     pub tab: UiElementTab,
     pub selected: UiElementSelected,
     pub container: UiElementContainer,
+    pub page: UiElementPage,
+    pub style: UiElementStyle,
 }
 
 #[derive(Bundle)]
@@ -19,6 +21,8 @@ pub struct UiTextInputBundle {
     pub tab: UiElementTab,
     pub selected: UiElementSelected,
     pub container: UiElementContainer,
+    pub page: UiElementPage,
+    pub style: UiElementStyle,
 }
 
 #[derive(Bundle)]
@@ -29,6 +33,8 @@ pub struct UiCheckboxBundle {
     pub tab: UiElementTab,
     pub selected: UiElementSelected,
     pub container: UiElementContainer,
+    pub page: UiElementPage,
+    pub style: UiElementStyle,
 }
 
 #[derive(Bundle)]
@@ -39,6 +45,8 @@ pub struct UiRadioButtonBundle {
     pub tab: UiElementTab,
     pub selected: UiElementSelected,
     pub container: UiElementContainer,
+    pub page: UiElementPage,
+    pub style: UiElementStyle,
 }
 
 #[derive(Bundle)]
@@ -49,4 +57,6 @@ pub struct UiGroupBoxBundle {
     pub tab: UiElementTab,
     pub selected: UiElementSelected,
     pub container: UiElementContainer,
+    pub page: UiElementPage,
+    pub style: UiElementStyle,
 }
\ No newline at end of file
@@ -1,11 +1,292 @@
 use bevy_ecs::prelude::*;
+use crate::codegen_thread::{UiElementSnapshot, WorldSnapshot};
 use crate::components::*;
 use crate::integration::TabKind;
+use crate::widgets::{DesignerWidget, WidgetRegistry};
+use std::collections::HashMap;
 use std::fmt::Write;
+use std::sync::Arc;
 
 pub struct CodeGenerator {
     indent_level: usize,
     code: String,
+    /// Named styles of the snapshot being generated
+    style_sheet: StyleSheet,
+    /// `styles` module helper prefix for each named style
+    style_idents: HashMap<String, String>,
+    /// Widget types generating the code of the snapshot's elements
+    widgets: WidgetRegistry,
+    /// Enum variant of each radio button in its group's generated enum
+    radio_variants: HashMap<Entity, String>,
+    /// Elements of the generated tab, top to bottom
+    elements: Vec<UiElementSnapshot>,
+    /// Elements placed in each container of the generated tab, top to bottom
+    children: HashMap<Entity, Vec<UiElementSnapshot>>,
+    /// Expressions replacing the label literals of elements, see `generate_children_labeled`
+    label_exprs: HashMap<Entity, String>,
+    /// Elements whose state is kept by a container instead of the `App`, see `DesignerWidget::encapsulates_children`
+    encapsulated: std::collections::HashSet<Entity>,
+}
+
+/// Turn a label into a snake_case Rust identifier
+pub fn snake_ident(label: &str) -> String {
+    let mut ident = String::new();
+    for c in label.trim().chars() {
+        if c.is_ascii_alphanumeric() {
+            ident.push(c.to_ascii_lowercase());
+        } else if (c == ' ' || c == '_' || c == '-') && !ident.ends_with('_') {
+            ident.push('_');
+        }
+    }
+    let ident = ident.trim_matches('_').to_string();
+    if ident.is_empty() {
+        "field".to_string()
+    } else if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", ident)
+    } else if RUST_KEYWORDS.contains(&ident.as_str()) {
+        format!("{}_", ident)
+    } else {
+        ident
+    }
+}
+
+/// Turn a label into a PascalCase Rust identifier
+pub fn pascal_ident(label: &str) -> String {
+    let ident: String = snake_ident(label)
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect();
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("Tab{}", ident)
+    } else if RUST_KEYWORDS.contains(&ident.as_str()) {
+        format!("{}_", ident)
+    } else {
+        ident
+    }
+}
+
+/// Keywords, including reserved ones, that can't be used as identifiers
+const RUST_KEYWORDS: &[&str] = &[
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let", "loop", "macro", "match",
+    "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "self", "static", "struct", "super", "trait",
+    "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Whether a string can be used as a Rust identifier as-is
+pub fn is_rust_ident(name: &str) -> bool {
+    let mut chars = name.chars();
+    let valid = match chars.next() {
+        Some(first) if first.is_ascii_alphabetic() || first == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    };
+    valid && name != "_" && !RUST_KEYWORDS.contains(&name)
+}
+
+/// Rust expression for a set of shortcut modifiers
+fn modifiers_expr(modifiers: egui::Modifiers) -> String {
+    let mut parts = Vec::new();
+    if modifiers.command {
+        parts.push("egui::Modifiers::COMMAND");
+    }
+    if modifiers.shift {
+        parts.push("egui::Modifiers::SHIFT");
+    }
+    if modifiers.alt {
+        parts.push("egui::Modifiers::ALT");
+    }
+    match parts.split_first() {
+        None => "egui::Modifiers::NONE".to_string(),
+        Some((first, rest)) => rest.iter().fold(first.to_string(), |expr, part| format!("{}.plus({})", expr, part)),
+    }
+}
+
+/// Exact Rust expression for a color; translucent colors keep their premultiplied channels
+pub fn color_expr(color: egui::Color32) -> String {
+    let [r, g, b, a] = color.to_array();
+    if a == 255 {
+        format!("egui::Color32::from_rgb({}, {}, {})", r, g, b)
+    } else {
+        format!("egui::Color32::from_rgba_premultiplied({}, {}, {}, {})", r, g, b, a)
+    }
+}
+
+fn stroke_expr(stroke: &StrokeStyle) -> String {
+    format!("egui::Stroke::new({:.1}, {})", stroke.width, color_expr(stroke.color))
+}
+
+fn has_text_props(style: &UiElementStyle) -> bool {
+    style.text_color.is_some() || style.is_bold() || style.is_italic()
+}
+
+fn has_button_props(style: &UiElementStyle) -> bool {
+    style.fill.is_some() || style.stroke.is_some() || style.corner_radius.is_some()
+}
+
+/// Whether `style` turns off bold or italics set by `base`, which `RichText` cannot undo
+fn unsets_emphasis(style: &UiElementStyle, base: &UiElementStyle) -> bool {
+    (style.bold == Some(false) && base.is_bold()) || (style.italic == Some(false) && base.is_italic())
+}
+
+/// `text` with the color and emphasis set in `style`
+fn text_props(mut text: String, style: &UiElementStyle) -> String {
+    if let Some(color) = style.text_color {
+        text.push_str(&format!(".color({})", color_expr(color)));
+    }
+    if style.is_bold() {
+        text.push_str(".strong()");
+    }
+    if style.is_italic() {
+        text.push_str(".italics()");
+    }
+    text
+}
+
+/// `button` with the fill, stroke and corner radius set in `style`
+fn button_props(mut button: String, style: &UiElementStyle) -> String {
+    if let Some(fill) = style.fill {
+        button.push_str(&format!(".fill({})", color_expr(fill)));
+    }
+    if let Some(stroke) = style.stroke {
+        button.push_str(&format!(".stroke({})", stroke_expr(&stroke)));
+    }
+    if let Some(radius) = style.corner_radius {
+        button.push_str(&format!(".corner_radius({:.1})", radius));
+    }
+    button
+}
+
+/// `frame` with the fill, stroke, corner radius and padding set in `style`
+fn frame_props(frame: String, style: &UiElementStyle) -> String {
+    let mut frame = button_props(frame, style);
+    if let Some(padding) = style.padding {
+        frame.push_str(&format!(".inner_margin(egui::Margin::symmetric({}, {}))", padding.x as i8, padding.y as i8));
+    }
+    frame
+}
+
+/// How an element's style is written: through the `styles` helpers of its named style,
+/// with the element's own properties applied on top
+pub struct ElementStyleCode {
+    /// Helper prefix and fully resolved properties of the named style
+    class: Option<(String, UiElementStyle)>,
+    overrides: UiElementStyle,
+    resolved: UiElementStyle,
+    /// The element's label and the expression written in place of its literal
+    label_expr: Option<(String, String)>,
+}
+
+impl ElementStyleCode {
+    fn new(style: &UiElementStyle, style_sheet: &StyleSheet, style_idents: &HashMap<String, String>) -> Self {
+        let class = style.class.as_ref().and_then(|class| {
+            let ident = style_idents.get(class)?;
+            Some((ident.clone(), style_sheet.resolve_named(class)))
+        });
+        Self {
+            resolved: style_sheet.resolve(Some(style)),
+            overrides: style.clone(),
+            class,
+            label_expr: None,
+        }
+    }
+
+    pub fn has_frame(&self) -> bool {
+        self.resolved.has_frame()
+    }
+
+    /// Widget text for a label: a plain string literal unless size or style need `RichText`
+    pub fn text(&self, label: &str, font_size: f32) -> String {
+        let literal = match &self.label_expr {
+            Some((own, expr)) if own == label => expr.clone(),
+            _ => format!("\"{}\"", label.escape_default()),
+        };
+        let size = if font_size > 0.0 && font_size != DEFAULT_FONT_SIZE {
+            format!(".size({:.1})", font_size)
+        } else {
+            String::new()
+        };
+        if let Some((ident, class)) = &self.class {
+            if has_text_props(class) && !unsets_emphasis(&self.overrides, class) {
+                let text = format!("styles::{}_text(egui::RichText::new({}){})", ident, literal, size);
+                return text_props(text, &self.overrides);
+            }
+        }
+        if size.is_empty() && !has_text_props(&self.resolved) {
+            return literal;
+        }
+        text_props(format!("egui::RichText::new({}){}", literal, size), &self.resolved)
+    }
+
+    pub fn button(&self, text: String) -> String {
+        let button = format!("egui::Button::new({})", text);
+        match &self.class {
+            Some((ident, class)) if has_button_props(class) => {
+                button_props(format!("styles::{}_button({})", ident, button), &self.overrides)
+            }
+            _ => button_props(button, &self.resolved),
+        }
+    }
+
+    /// Statement setting the button padding, if any
+    pub fn button_padding(&self) -> Option<String> {
+        if let Some(padding) = self.overrides.padding {
+            return Some(format!("ui.spacing_mut().button_padding = egui::vec2({:.1}, {:.1});", padding.x, padding.y));
+        }
+        match &self.class {
+            Some((ident, class)) if class.padding.is_some() => Some(format!("styles::{}_button_padding(ui);", ident)),
+            _ => None,
+        }
+    }
+
+    pub fn frame(&self, base: &str) -> String {
+        match &self.class {
+            Some((ident, class)) if class.has_frame() => {
+                frame_props(format!("styles::{}_frame({})", ident, base), &self.overrides)
+            }
+            _ => frame_props(base.to_string(), &self.resolved),
+        }
+    }
+
+    pub fn tooltip(&self) -> String {
+        match self.resolved.tooltip_text() {
+            Some(tooltip) => format!(".on_hover_text(\"{}\")", tooltip.escape_default()),
+            None => String::new(),
+        }
+    }
+
+    pub fn has_tooltip(&self) -> bool {
+        self.resolved.tooltip_text().is_some()
+    }
+
+    /// Expression for the text color, if the style sets one
+    pub fn text_color(&self) -> Option<String> {
+        self.resolved.text_color.map(color_expr)
+    }
+}
+
+/// Generated identifiers for one menu item, in the depth-first order of `MenuBarDesign::items`
+struct MenuItemIdents {
+    handler: String,
+    /// `bool` field on `App` holding the state of a checkable item
+    state_field: Option<String>,
+    /// Name of the `KeyboardShortcut` constant
+    shortcut_const: Option<String>,
+}
+
+/// Generated identifiers for one design page
+struct PageIdents {
+    page: DesignPage,
+    function: String,
+    variant: String,
 }
 
 impl CodeGenerator {
@@ -13,356 +294,227 @@ impl CodeGenerator {
         Self {
             indent_level: 0,
             code: String::new(),
+            style_sheet: StyleSheet { styles: Vec::new() },
+            style_idents: HashMap::new(),
+            widgets: WidgetRegistry::default(),
+            radio_variants: HashMap::new(),
+            elements: Vec::new(),
+            children: HashMap::new(),
+            label_exprs: HashMap::new(),
+            encapsulated: std::collections::HashSet::new(),
         }
     }
 
-    fn indent(&self) -> String {
-        "    ".repeat(self.indent_level)
+    /// Widget type generating the code of elements of `kind`
+    pub fn widget(&self, kind: &str) -> Option<Arc<dyn DesignerWidget>> {
+        self.widgets.get(kind).cloned()
     }
 
-    fn writeln(&mut self, line: &str) {
-        let indent = self.indent();
-        writeln!(&mut self.code, "{}{}", indent, line).unwrap();
+    /// Elements of the generated tab, top to bottom
+    pub fn elements(&self) -> &[UiElementSnapshot] {
+        &self.elements
     }
 
-    fn write_raw(&mut self, text: &str) {
-        write!(&mut self.code, "{}", text).unwrap();
+    /// Variant of a radio button's group enum, `None` for elements that are not radio buttons
+    pub fn radio_variant(&self, entity: Entity) -> Option<String> {
+        self.radio_variants.get(&entity).cloned()
     }
 
-    pub fn generate_panel_function(world: &mut World, panel_name: &str) -> String {
-        let mut gen = CodeGenerator::new();
-        
-        // Generate imports
-        gen.writeln("use egui;");
-        gen.writeln("");
-        
-        // Generate function signature
-        gen.writeln(&format!("pub fn show_{}_panel(ui: &mut egui::Ui, app: &mut App) {{", 
-            panel_name.to_lowercase().replace(" ", "_")));
-        gen.indent_level += 1;
-        
-        // Add panel heading
-        gen.writeln(&format!("ui.heading(\"{}\");", panel_name));
-        gen.writeln("ui.separator();");
-        gen.writeln("");
-        
-        // Collect all UI elements for MainWork tab
-        let mut elements = Vec::new();
-        let mut query = world.query::<(
-            Entity,
-            &UiElementPosition,
-            &UiElementSize,
-            &UiElementTab,
-            Option<&UiButton>,
-            Option<&UiTextInput>,
-            Option<&UiCheckbox>,
-            Option<&UiRadioButton>,
-            Option<&UiGroupBox>,
-        )>();
-        
-        for (entity, pos, size, tab, button, text_input, checkbox, radio, group_box) in query.iter(world) {
-            if matches!(tab.tab_kind, TabKind::MainWork) {
-                elements.push((entity, pos, size, button, text_input, checkbox, radio, group_box));
-            }
-        }
-        
-        // Sort elements by position (top to bottom, left to right)
-        elements.sort_by(|a, b| {
-            let y_cmp = a.1.y.partial_cmp(&b.1.y).unwrap();
-            if y_cmp == std::cmp::Ordering::Equal {
-                a.1.x.partial_cmp(&b.1.x).unwrap()
-            } else {
-                y_cmp
-            }
-        });
-        
-        // Generate positioned elements using egui::Area for exact positioning
-        gen.writeln("// Elements positioned exactly as in the designer");
-        for element in elements {
-            gen.generate_positioned_element_code(element);
-            gen.writeln("");
-        }
-        
-        gen.indent_level -= 1;
-        gen.writeln("}");
-        
-        gen.code
-    }
-    
-    fn generate_positioned_element_code(&mut self, element: (
-        Entity,
-        &UiElementPosition,
-        &UiElementSize,
-        Option<&UiButton>,
-        Option<&UiTextInput>,
-        Option<&UiCheckbox>,
-        Option<&UiRadioButton>,
-        Option<&UiGroupBox>,
-    )) {
-        let (entity, pos, size, button, text_input, checkbox, radio, group_box) = element;
-        
-        // Generate Area with fixed position
-        let area_id = format!("element_{:?}", entity);
-        self.writeln(&format!("egui::Area::new(egui::Id::new(\"{}\"))", area_id));
-        self.indent_level += 1;
-        self.writeln(&format!(".fixed_pos(egui::pos2({:.1}, {:.1}))", pos.x, pos.y));
-        self.writeln(".show(ui.ctx(), |ui| {");
-        self.indent_level += 1;
-        
-        if let Some(button) = button {
-            self.generate_positioned_button_code(button, size);
-        } else if let Some(text_input) = text_input {
-            self.generate_positioned_text_input_code(text_input, size);
-        } else if let Some(checkbox) = checkbox {
-            self.generate_positioned_checkbox_code(checkbox);
-        } else if let Some(radio) = radio {
-            self.generate_positioned_radio_button_code(radio);
-        } else if let Some(group_box) = group_box {
-            self.generate_positioned_group_box_code(group_box, size);
-        }
-        
-        self.indent_level -= 1;
-        self.writeln("});");
-        self.indent_level -= 1;
+    /// Whether any element was placed in the container, in `slot` or in any slot when `None`
+    pub fn has_children(&self, container: Entity, slot: Option<usize>) -> bool {
+        self.children.get(&container).is_some_and(|children| {
+            children.iter().any(|child| slot.is_none_or(|slot| child.container.is_some_and(|(_, s)| s == slot)))
+        })
     }
-    
-    fn generate_element_code(&mut self, element: (
-        Entity,
-        &UiElementPosition,
-        &UiElementSize,
-        Option<&UiButton>,
-        Option<&UiTextInput>,
-        Option<&UiCheckbox>,
-        Option<&UiRadioButton>,
-        Option<&UiGroupBox>,
-    )) {
-        let (_, _pos, size, button, text_input, checkbox, radio, group_box) = element;
-        
-        if let Some(button) = button {
-            self.generate_button_code(button, size);
-        } else if let Some(text_input) = text_input {
-            self.generate_text_input_code(text_input, size);
-        } else if let Some(checkbox) = checkbox {
-            self.generate_checkbox_code(checkbox);
-        } else if let Some(radio) = radio {
-            self.generate_radio_button_code(radio);
-        } else if let Some(group_box) = group_box {
-            self.generate_group_box_code(group_box, size);
-        }
-    }
-    
-    fn generate_button_code(&mut self, button: &UiButton, size: &UiElementSize) {
-        self.writeln(&format!("if ui.add_sized("));
-        self.indent_level += 1;
-        self.writeln(&format!("egui::vec2({:.1}, {:.1}),", size.width, size.height));
-        self.writeln(&format!("egui::Button::new(\"{}\"){}",
-            button.label,
-            if !button.enabled { ".enabled(false)" } else { "" }
-        ));
-        self.indent_level -= 1;
-        self.writeln(").clicked() {");
-        self.indent_level += 1;
-        self.writeln(&format!("// TODO: Handle {} button click", button.label));
-        self.indent_level -= 1;
-        self.writeln("}");
+
+    /// Code of the elements placed in `slot` of a container, each at its offset from the
+    /// container's top-left in the designer. `origin` is an expression for that point.
+    pub fn generate_children(&mut self, container: &UiElementSnapshot, slot: usize, origin: &str) {
+        let children: Vec<UiElementSnapshot> = self.children.get(&container.entity_id)
+            .map(|children| children.iter().filter(|child| child.container.is_some_and(|(_, s)| s == slot)).cloned().collect())
+            .unwrap_or_default();
+        for child in &children {
+            let offset = (child.position.x - container.position.x, child.position.y - container.position.y);
+            // Self-sized children may use the rest of the container
+            let width = if child.size.width > 0.0 { child.size.width } else { (container.size.width - offset.0).max(20.0) };
+            let height = if child.size.height > 0.0 { child.size.height } else { (container.size.height - offset.1).max(20.0) };
+            self.writeln(&format!(
+                "ui.scope_builder(egui::UiBuilder::new().max_rect(egui::Rect::from_min_size({} + egui::vec2({:.1}, {:.1}), egui::vec2({:.1}, {:.1}))), |ui| {{",
+                origin, offset.0, offset.1, width, height
+            ));
+            self.indent_level += 1;
+            self.generate_element_body(child);
+            self.indent_level -= 1;
+            self.writeln("});");
+        }
     }
-    
-    fn generate_text_input_code(&mut self, text_input: &UiTextInput, size: &UiElementSize) {
-        self.writeln(&format!("ui.horizontal(|ui| {{"));
-        self.indent_level += 1;
-        self.writeln(&format!("ui.label(\"{}\");", text_input.label));
-        self.writeln(&format!("ui.add_sized("));
-        self.indent_level += 1;
-        self.writeln(&format!("egui::vec2({:.1}, {:.1}),", size.width, size.height));
-        self.writeln(&format!("egui::TextEdit::singleline(&mut app.{}){}",
-            text_input.label.to_lowercase().replace(" ", "_"),
-            if !text_input.enabled { ".enabled(false)" } else { "" }
-        ));
-        self.indent_level -= 1;
-        self.writeln(");");
-        self.indent_level -= 1;
-        self.writeln("});");
+
+    /// The elements inside a container, its children's children included, each before its own children
+    pub fn descendants(&self, container: Entity) -> Vec<UiElementSnapshot> {
+        let mut descendants = Vec::new();
+        for child in self.children.get(&container).into_iter().flatten() {
+            descendants.push(child.clone());
+            descendants.extend(self.descendants(child.entity_id));
+        }
+        descendants
     }
-    
-    fn generate_checkbox_code(&mut self, checkbox: &UiCheckbox) {
-        self.writeln(&format!("ui.checkbox(&mut app.{}, \"{}\"){};",
-            checkbox.label.to_lowercase().replace(" ", "_").replace("?", ""),
-            checkbox.label,
-            if !checkbox.enabled { ".enabled(false)" } else { "" }
-        ));
+
+    /// `generate_children` with the label of each element in `labels` written as its expression
+    /// instead of a string literal
+    pub fn generate_children_labeled(&mut self, container: &UiElementSnapshot, slot: usize, origin: &str, labels: HashMap<Entity, String>) {
+        self.label_exprs = labels;
+        self.generate_children(container, slot, origin);
+        self.label_exprs.clear();
     }
-    
-    fn generate_radio_button_code(&mut self, radio: &UiRadioButton) {
-        self.writeln(&format!("ui.radio_value(&mut app.{}_selection, \"{}\", \"{}\"){};",
-            radio.group_id,
-            radio.label,
-            radio.label,
-            if !radio.enabled { ".enabled(false)" } else { "" }
-        ));
+
+    fn indent(&self) -> String {
+        "    ".repeat(self.indent_level)
     }
-    
-    fn generate_group_box_code(&mut self, group_box: &UiGroupBox, size: &UiElementSize) {
-        self.writeln(&format!("ui.group(|ui| {{"));
-        self.indent_level += 1;
-        self.writeln(&format!("ui.set_min_size(egui::vec2({:.1}, {:.1}));", size.width, size.height));
-        self.writeln(&format!("ui.label(\"{}\");", group_box.label));
-        self.writeln("ui.separator();");
-        self.writeln("// TODO: Add group box contents");
-        self.indent_level -= 1;
-        self.writeln("});");
+
+    /// Write one line at the current indentation
+    pub fn writeln(&mut self, line: &str) {
+        let indent = self.indent();
+        writeln!(&mut self.code, "{}{}", indent, line).unwrap();
     }
-    
-    // Positioned versions for exact layout matching
-    fn generate_positioned_button_code(&mut self, button: &UiButton, size: &UiElementSize) {
-        self.writeln(&format!("if ui.add_sized("));
-        self.indent_level += 1;
-        self.writeln(&format!("egui::vec2({:.1}, {:.1}),", size.width, size.height));
-        self.writeln(&format!("egui::Button::new(\"{}\"){}",
-            button.label,
-            if !button.enabled { ".enabled(false)" } else { "" }
-        ));
-        self.indent_level -= 1;
-        self.writeln(").clicked() {");
+
+    pub fn push_indent(&mut self) {
         self.indent_level += 1;
-        self.writeln(&format!("// TODO: Handle {} button click", button.label));
-        self.indent_level -= 1;
-        self.writeln("}");
     }
-    
-    fn generate_positioned_text_input_code(&mut self, text_input: &UiTextInput, size: &UiElementSize) {
-        self.writeln(&format!("ui.vertical(|ui| {{"));
-        self.indent_level += 1;
-        self.writeln(&format!("ui.label(\"{}\");", text_input.label));
-        self.writeln(&format!("ui.add_sized("));
-        self.indent_level += 1;
-        self.writeln(&format!("egui::vec2({:.1}, {:.1}),", size.width, size.height));
-        self.writeln(&format!("egui::TextEdit::singleline(&mut app.{}){}",
-            text_input.label.to_lowercase().replace(" ", "_"),
-            if !text_input.enabled { ".enabled(false)" } else { "" }
-        ));
-        self.indent_level -= 1;
-        self.writeln(");");
+
+    pub fn pop_indent(&mut self) {
         self.indent_level -= 1;
-        self.writeln("});");
     }
-    
-    fn generate_positioned_checkbox_code(&mut self, checkbox: &UiCheckbox) {
-        self.writeln(&format!("ui.checkbox(&mut app.{}, \"{}\"){};",
-            checkbox.label.to_lowercase().replace(" ", "_").replace("?", ""),
-            checkbox.label,
-            if !checkbox.enabled { ".enabled(false)" } else { "" }
-        ));
+
+    /// Generate one panel function per design page from the live world
+    pub fn generate_panel_functions(world: &mut World) -> String {
+        let snapshot = WorldSnapshot::from_world(world);
+        Self::panel_functions_from_snapshot(&snapshot, &TabKind::MainWork)
     }
-    
-    fn generate_positioned_radio_button_code(&mut self, radio: &UiRadioButton) {
-        self.writeln(&format!("ui.radio_value(&mut app.{}_selection, \"{}\", \"{}\"){};",
-            radio.group_id,
-            radio.label,
-            radio.label,
-            if !radio.enabled { ".enabled(false)" } else { "" }
-        ));
+
+    /// Generate a complete eframe application from the live world
+    pub fn generate_full_app_code(world: &mut World) -> String {
+        let snapshot = WorldSnapshot::from_world(world);
+        Self::full_app_from_snapshot(&snapshot, &TabKind::MainWork)
     }
-    
-    fn generate_positioned_group_box_code(&mut self, group_box: &UiGroupBox, size: &UiElementSize) {
-        self.writeln(&format!("ui.group(|ui| {{"));
-        self.indent_level += 1;
-        self.writeln(&format!("ui.set_min_size(egui::vec2({:.1}, {:.1}));", size.width, size.height));
-        self.writeln(&format!("ui.label(\"{}\");", group_box.label));
-        self.writeln("ui.separator();");
-        self.writeln("// TODO: Add group box contents");
-        self.indent_level -= 1;
-        self.writeln("});");
+
+    /// Generate the panel functions for every page of a snapshot
+    pub fn panel_functions_from_snapshot(snapshot: &WorldSnapshot, tab_kind: &TabKind) -> String {
+        let mut gen = CodeGenerator::new();
+        gen.prepare(snapshot, tab_kind);
+        gen.writeln("// Generated Panel Functions");
+        gen.writeln("use eframe::egui;");
+        gen.writeln("");
+        gen.generate_page_functions(snapshot, tab_kind);
+        gen.code
     }
-    
-    pub fn generate_full_app_code(world: &mut World) -> String {
+
+    /// Generate a complete eframe application for a snapshot
+    pub fn full_app_from_snapshot(snapshot: &WorldSnapshot, tab_kind: &TabKind) -> String {
         let mut gen = CodeGenerator::new();
-        
+        gen.prepare(snapshot, tab_kind);
+        let dock_root = if snapshot.dock_layout.enabled {
+            Self::placed_dock_root(snapshot)
+        } else {
+            None
+        };
+        let use_dock = dock_root.is_some();
+
         // Generate file header
         gen.writeln("// Generated by Mobius Designer");
         gen.writeln("// This code provides a starting point for your egui application");
         gen.writeln("");
+        for (name, purpose) in Self::required_crates(snapshot, tab_kind) {
+            gen.writeln(&format!("// Requires the `{}` crate for {}", name, purpose));
+        }
         gen.writeln("use eframe::egui;");
+        if use_dock {
+            gen.writeln("use egui_dock::{DockArea, DockState, NodeIndex, Style, TabViewer};");
+        }
         gen.writeln("");
-        
+
+        // Generate an enum for each radio group
+        for group in Self::radio_groups(snapshot, tab_kind) {
+            gen.generate_radio_group_enum(&group);
+        }
+
         // Generate app struct
         gen.writeln("#[derive(Default)]");
         gen.writeln("pub struct App {");
         gen.indent_level += 1;
-        
+
         // Add fields based on UI elements
-        gen.generate_app_fields(world);
-        
+        gen.generate_app_fields(snapshot, tab_kind);
+
+        // Add state of checkable menu items
+        let menu_items = Self::menu_item_idents(&snapshot.menu_bar);
+        let mut state_fields = std::collections::HashSet::new();
+        for ident in &menu_items {
+            if let Some(field) = &ident.state_field {
+                if state_fields.insert(field.clone()) {
+                    gen.writeln(&format!("{}: bool,", field));
+                }
+            }
+        }
+
         gen.indent_level -= 1;
         gen.writeln("}");
         gen.writeln("");
-        
+
         // Generate implementation
+        let mut initial_values: Vec<(String, String)> = snapshot.menu_bar.items().iter()
+            .zip(&menu_items)
+            .filter(|(item, _)| item.checkable && item.checked)
+            .filter_map(|(_, ident)| ident.state_field.clone())
+            .map(|field| (field, "true".to_string()))
+            .collect();
+        initial_values.extend(gen.app_field_values(snapshot, tab_kind));
         gen.writeln("impl App {");
         gen.indent_level += 1;
-        gen.writeln("pub fn new(_cc: &eframe::CreationContext<'_>) -> Self {");
+        gen.writeln("pub fn new(cc: &eframe::CreationContext<'_>) -> Self {");
         gen.indent_level += 1;
-        gen.writeln("Default::default()");
-        gen.indent_level -= 1;
-        gen.writeln("}");
-        gen.indent_level -= 1;
-        gen.writeln("}");
-        gen.writeln("");
-        
-        // Generate eframe::App implementation
-        gen.writeln("impl eframe::App for App {");
-        gen.indent_level += 1;
-        gen.writeln("fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {");
-        gen.indent_level += 1;
-        gen.writeln("// Top menu bar");
-        gen.writeln("egui::TopBottomPanel::top(\"top_panel\").show(ctx, |ui| {");
-        gen.indent_level += 1;
-        gen.writeln("egui::menu::bar(ui, |ui| {");
-        gen.indent_level += 1;
-        gen.writeln("ui.menu_button(\"File\", |ui| {");
-        gen.indent_level += 1;
-        gen.writeln("if ui.button(\"New\").clicked() {");
-        gen.indent_level += 1;
-        gen.writeln("// TODO: Implement new file");
-        gen.indent_level -= 1;
-        gen.writeln("}");
-        gen.writeln("if ui.button(\"Open\").clicked() {");
-        gen.indent_level += 1;
-        gen.writeln("// TODO: Implement open file");
-        gen.indent_level -= 1;
-        gen.writeln("}");
-        gen.writeln("if ui.button(\"Save\").clicked() {");
-        gen.indent_level += 1;
-        gen.writeln("// TODO: Implement save file");
-        gen.indent_level -= 1;
-        gen.writeln("}");
-        gen.indent_level -= 1;
-        gen.writeln("});");
-        gen.indent_level -= 1;
-        gen.writeln("});");
-        gen.indent_level -= 1;
-        gen.writeln("});");
-        gen.writeln("");
-        gen.writeln("// Main content area with generated UI");
-        gen.writeln("egui::CentralPanel::default().show(ctx, |ui| {");
-        gen.indent_level += 1;
-        gen.writeln("ui.heading(\"My Generated App\");");
-        gen.writeln("ui.separator();");
-        gen.writeln("");
-        gen.writeln("// Call the generated panel function");
-        gen.writeln("show_main_panel(ui, self);");
-        gen.indent_level -= 1;
-        gen.writeln("});");
+        gen.writeln("apply_theme(&cc.egui_ctx);");
+        if initial_values.is_empty() {
+            gen.writeln("Default::default()");
+        } else {
+            gen.writeln("Self {");
+            gen.indent_level += 1;
+            let mut written = std::collections::HashSet::new();
+            for (field, value) in &initial_values {
+                if written.insert(field) {
+                    gen.writeln(&format!("{}: {},", field, value));
+                }
+            }
+            gen.writeln("..Default::default()");
+            gen.indent_level -= 1;
+            gen.writeln("}");
+        }
         gen.indent_level -= 1;
         gen.writeln("}");
+        gen.generate_validate(snapshot, tab_kind);
         gen.indent_level -= 1;
         gen.writeln("}");
         gen.writeln("");
-        
-        // Generate panel function
-        let panel_code = Self::generate_panel_function(world, "Main");
-        gen.write_raw(&panel_code);
-        gen.writeln("");
-        
+
+        gen.generate_apply_theme(&snapshot.theme);
+        gen.generate_support_code(snapshot, tab_kind);
+
+        let has_menu_bar = !snapshot.menu_bar.menus.is_empty();
+        if has_menu_bar {
+            gen.generate_menu_bar(&snapshot.menu_bar, &menu_items);
+        }
+
+        let pages = Self::page_idents(snapshot);
+        let app_type = if let Some(root) = &dock_root {
+            gen.generate_dock_layout(root, &pages, has_menu_bar);
+            "MainWindow"
+        } else {
+            gen.generate_single_panel_app(&pages, has_menu_bar);
+            "App"
+        };
+
+        // Generate panel functions
+        gen.generate_page_functions(snapshot, tab_kind);
+
         // Generate main function
         gen.writeln("fn main() -> Result<(), eframe::Error> {");
         gen.indent_level += 1;
@@ -377,45 +529,864 @@ impl CodeGenerator {
         gen.indent_level += 1;
         gen.writeln("\"My egui App\",");
         gen.writeln("options,");
-        gen.writeln("Box::new(|cc| Box::new(App::new(cc))),");
+        gen.writeln(&format!("Box::new(|cc| Ok(Box::new({}::new(cc)))),", app_type));
         gen.indent_level -= 1;
         gen.writeln(")");
         gen.indent_level -= 1;
         gen.writeln("}");
-        
+
         gen.code
     }
-    
-    fn generate_app_fields(&mut self, world: &mut World) {
-        // Collect unique field names from UI elements
-        let mut fields = std::collections::HashSet::new();
-        
-        let mut query = world.query::<(
-            &UiElementTab,
-            Option<&UiTextInput>,
-            Option<&UiCheckbox>,
-            Option<&UiRadioButton>,
-        )>();
-        
-        for (tab, text_input, checkbox, radio) in query.iter(world) {
-            if matches!(tab.tab_kind, TabKind::MainWork) {
-                if let Some(text_input) = text_input {
-                    let field_name = text_input.label.to_lowercase().replace(" ", "_");
-                    if fields.insert(field_name.clone()) {
-                        self.writeln(&format!("{}: String,", field_name));
-                    }
-                } else if let Some(checkbox) = checkbox {
-                    let field_name = checkbox.label.to_lowercase().replace(" ", "_").replace("?", "");
-                    if fields.insert(field_name.clone()) {
-                        self.writeln(&format!("{}: bool,", field_name));
+
+    /// Assign unique function and enum variant names to every page
+    fn page_idents(snapshot: &WorldSnapshot) -> Vec<PageIdents> {
+        let mut used_functions = std::collections::HashSet::new();
+        let mut used_variants = std::collections::HashSet::new();
+        let mut idents = Vec::new();
+
+        for page in &snapshot.pages {
+            let mut function = format!("show_{}_panel", snake_ident(&page.name));
+            if !used_functions.insert(function.clone()) {
+                function = format!("show_{}_{}_panel", snake_ident(&page.name), page.id);
+                used_functions.insert(function.clone());
+            }
+            let mut variant = pascal_ident(&page.name);
+            if !used_variants.insert(variant.clone()) {
+                variant = format!("{}{}", variant, page.id);
+                used_variants.insert(variant.clone());
+            }
+            idents.push(PageIdents { page: page.clone(), function, variant });
+        }
+
+        idents
+    }
+
+    fn generate_single_panel_app(&mut self, pages: &[PageIdents], has_menu_bar: bool) {
+        self.writeln("impl eframe::App for App {");
+        self.indent_level += 1;
+        self.writeln("fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {");
+        self.indent_level += 1;
+        if has_menu_bar {
+            self.writeln("show_menu_bar(ctx, self);");
+            self.writeln("");
+        }
+        self.writeln("// Main content area with generated UI");
+        self.writeln("egui::CentralPanel::default().show(ctx, |ui| {");
+        self.indent_level += 1;
+        self.writeln("ui.heading(\"My Generated App\");");
+        self.writeln("ui.separator();");
+        if let Some(first_page) = pages.first() {
+            self.writeln("");
+            self.writeln("// Call the generated panel function");
+            self.writeln(&format!("{}(ui, self);", first_page.function));
+        }
+        self.indent_level -= 1;
+        self.writeln("});");
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.writeln("");
+    }
+
+    /// `apply_theme` setting the designed theme on both the dark and light styles
+    fn generate_apply_theme(&mut self, theme: &ThemeDesign) {
+        self.writeln("/// Global theme designed in Mobius Designer");
+        self.writeln("pub fn apply_theme(ctx: &egui::Context) {");
+        self.indent_level += 1;
+        self.writeln("ctx.all_styles_mut(|style| {");
+        self.indent_level += 1;
+        let base = if theme.dark_base { "dark" } else { "light" };
+        self.writeln(&format!("let mut visuals = egui::Visuals::{}();", base));
+        for (field, color) in [
+            ("panel_fill", theme.panel_fill),
+            ("window_fill", theme.window_fill),
+            ("extreme_bg_color", theme.extreme_bg_color),
+            ("faint_bg_color", theme.faint_bg_color),
+            ("widgets.inactive.bg_fill", theme.widget_fill),
+            ("widgets.inactive.weak_bg_fill", theme.widget_fill),
+            ("widgets.hovered.bg_fill", theme.hovered_fill),
+            ("widgets.hovered.weak_bg_fill", theme.hovered_fill),
+            ("widgets.active.bg_fill", theme.active_fill),
+            ("widgets.active.weak_bg_fill", theme.active_fill),
+            ("selection.bg_fill", theme.selection),
+            ("hyperlink_color", theme.hyperlink),
+            ("warn_fg_color", theme.warn),
+            ("error_fg_color", theme.error),
+        ] {
+            self.writeln(&format!("visuals.{} = {};", field, color_expr(color)));
+        }
+        if let Some(color) = theme.text_color {
+            self.writeln(&format!("visuals.override_text_color = Some({});", color_expr(color)));
+        }
+        self.writeln(&format!(
+            "let corner_radius = egui::CornerRadius::same({});",
+            theme.widget_corner_radius as u8
+        ));
+        self.writeln("for widget in [");
+        self.indent_level += 1;
+        for state in ["noninteractive", "inactive", "hovered", "active", "open"] {
+            self.writeln(&format!("&mut visuals.widgets.{},", state));
+        }
+        self.indent_level -= 1;
+        self.writeln("] {");
+        self.indent_level += 1;
+        self.writeln("widget.corner_radius = corner_radius;");
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.writeln("visuals.menu_corner_radius = corner_radius;");
+        self.writeln(&format!(
+            "visuals.window_corner_radius = egui::CornerRadius::same({});",
+            theme.window_corner_radius as u8
+        ));
+        self.writeln("style.visuals = visuals;");
+        self.writeln("");
+        self.writeln(&format!("style.spacing.item_spacing = egui::vec2({:.1}, {:.1});", theme.item_spacing.x, theme.item_spacing.y));
+        self.writeln(&format!("style.spacing.button_padding = egui::vec2({:.1}, {:.1});", theme.button_padding.x, theme.button_padding.y));
+        self.writeln(&format!("style.spacing.window_margin = egui::Margin::same({});", theme.window_margin as i8));
+        self.writeln(&format!("style.spacing.indent = {:.1};", theme.indent));
+        self.writeln("");
+        for (text_style, size) in theme.text_style_sizes() {
+            let font = if text_style == egui::TextStyle::Monospace { "monospace" } else { "proportional" };
+            self.writeln(&format!(
+                "style.text_styles.insert(egui::TextStyle::{:?}, egui::FontId::{}({:.1}));",
+                text_style, font, size
+            ));
+        }
+        self.indent_level -= 1;
+        self.writeln("});");
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.writeln("");
+    }
+
+    /// Assign handler methods, state fields and shortcut constants to every menu item.
+    /// Items may share a handler when they agree on being checkable.
+    fn menu_item_idents(menu_bar: &MenuBarDesign) -> Vec<MenuItemIdents> {
+        let mut handlers: std::collections::HashMap<String, bool> = std::collections::HashMap::new();
+        let mut idents = Vec::new();
+
+        for item in menu_bar.items() {
+            let mut handler = if is_rust_ident(&item.handler) {
+                item.handler.clone()
+            } else {
+                format!("on_{}", snake_ident(&item.label))
+            };
+            let base = handler.clone();
+            let mut suffix = 2;
+            while handlers.get(&handler).is_some_and(|checkable| *checkable != item.checkable) {
+                handler = format!("{}_{}", base, suffix);
+                suffix += 1;
+            }
+            handlers.insert(handler.clone(), item.checkable);
+
+            let state_field = item.checkable.then(|| {
+                format!("{}_checked", handler.strip_prefix("on_").unwrap_or(&handler))
+            });
+            let shortcut_const = item.keyboard_shortcut().map(|shortcut| {
+                let mut name = String::from("SHORTCUT");
+                if shortcut.modifiers.command {
+                    name.push_str("_COMMAND");
+                }
+                if shortcut.modifiers.shift {
+                    name.push_str("_SHIFT");
+                }
+                if shortcut.modifiers.alt {
+                    name.push_str("_ALT");
+                }
+                format!("{}_{}", name, format!("{:?}", shortcut.logical_key).to_uppercase())
+            });
+
+            idents.push(MenuItemIdents { handler, state_field, shortcut_const });
+        }
+
+        idents
+    }
+
+    /// Emit the `MenuHandler` trait, shortcut constants and the `show_menu_bar` function
+    fn generate_menu_bar(&mut self, menu_bar: &MenuBarDesign, idents: &[MenuItemIdents]) {
+        let items = menu_bar.items();
+
+        // Handler trait with default no-op methods
+        self.writeln("/// Actions of the menu bar. Override these methods in `impl MenuHandler for App`.");
+        self.writeln("pub trait MenuHandler {");
+        self.indent_level += 1;
+        let mut declared = std::collections::HashSet::new();
+        for ident in idents {
+            if !declared.insert(ident.handler.clone()) {
+                continue;
+            }
+            if ident.state_field.is_some() {
+                self.writeln(&format!("fn {}(&mut self, _checked: bool) {{}}", ident.handler));
+            } else {
+                self.writeln(&format!("fn {}(&mut self) {{}}", ident.handler));
+            }
+        }
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.writeln("");
+        self.writeln("impl MenuHandler for App {}");
+        self.writeln("");
+
+        // Keyboard shortcut constants
+        let mut shortcuts = Vec::new();
+        for (item, ident) in items.iter().zip(idents) {
+            if let (Some(shortcut), Some(name)) = (item.keyboard_shortcut(), &ident.shortcut_const) {
+                if !shortcuts.iter().any(|(existing, _)| existing == name) {
+                    shortcuts.push((name.clone(), ident));
+                    self.writeln(&format!(
+                        "const {}: egui::KeyboardShortcut = egui::KeyboardShortcut::new({}, egui::Key::{:?});",
+                        name,
+                        modifiers_expr(shortcut.modifiers),
+                        shortcut.logical_key
+                    ));
+                }
+            }
+        }
+        if !shortcuts.is_empty() {
+            self.writeln("");
+        }
+
+        self.writeln("/// Menu bar of the generated app");
+        self.writeln("pub fn show_menu_bar(ctx: &egui::Context, app: &mut App) {");
+        self.indent_level += 1;
+        if !shortcuts.is_empty() {
+            // Shortcuts with more modifiers must be consumed first, so Ctrl+Shift+S is not taken by Ctrl+S
+            let mut ordered: Vec<(&MenuItemDesign, &MenuItemIdents)> = Vec::new();
+            for (name, ident) in &shortcuts {
+                if let Some(index) = idents.iter().position(|other| other.shortcut_const.as_ref() == Some(name)) {
+                    ordered.push((items[index], ident));
+                }
+            }
+            ordered.sort_by_key(|(item, _)| {
+                let modifiers = item.keyboard_shortcut().map(|shortcut| shortcut.modifiers).unwrap_or_default();
+                std::cmp::Reverse(modifiers.command as u8 + modifiers.shift as u8 + modifiers.alt as u8)
+            });
+            self.writeln("// Keyboard shortcuts");
+            for (_, ident) in ordered {
+                let Some(name) = &ident.shortcut_const else { continue };
+                self.writeln(&format!("if ctx.input_mut(|i| i.consume_shortcut(&{})) {{", name));
+                self.indent_level += 1;
+                self.generate_menu_action(ident);
+                self.indent_level -= 1;
+                self.writeln("}");
+            }
+            self.writeln("");
+        }
+        self.writeln("egui::TopBottomPanel::top(\"menu_bar\").show(ctx, |ui| {");
+        self.indent_level += 1;
+        self.writeln("egui::MenuBar::new().ui(ui, |ui| {");
+        self.indent_level += 1;
+        let mut next_item = 0;
+        for menu in &menu_bar.menus {
+            self.writeln(&format!("ui.menu_button(\"{}\", |ui| {{", menu.title.escape_default()));
+            self.indent_level += 1;
+            self.generate_menu_entries(&menu.entries, idents, &mut next_item);
+            self.indent_level -= 1;
+            self.writeln("});");
+        }
+        self.indent_level -= 1;
+        self.writeln("});");
+        self.indent_level -= 1;
+        self.writeln("});");
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.writeln("");
+    }
+
+    fn generate_menu_entries(&mut self, entries: &[MenuEntry], idents: &[MenuItemIdents], next_item: &mut usize) {
+        for entry in entries {
+            match entry {
+                MenuEntry::Item(item) => {
+                    let ident = &idents[*next_item];
+                    *next_item += 1;
+                    let mut button = format!("egui::Button::new(\"{}\")", item.label.escape_default());
+                    if let Some(field) = &ident.state_field {
+                        button.push_str(&format!(".selected(app.{})", field));
                     }
-                } else if let Some(radio) = radio {
-                    let field_name = format!("{}_selection", radio.group_id);
-                    if fields.insert(field_name.clone()) {
-                        self.writeln(&format!("{}: String,", field_name));
+                    if let Some(name) = &ident.shortcut_const {
+                        button.push_str(&format!(".shortcut_text(ui.ctx().format_shortcut(&{}))", name));
                     }
+                    self.writeln(&format!("if ui.add({}).clicked() {{", button));
+                    self.indent_level += 1;
+                    self.generate_menu_action(ident);
+                    self.indent_level -= 1;
+                    self.writeln("}");
+                }
+                MenuEntry::Separator => self.writeln("ui.separator();"),
+                MenuEntry::Submenu { title, entries } => {
+                    self.writeln(&format!("ui.menu_button(\"{}\", |ui| {{", title.escape_default()));
+                    self.indent_level += 1;
+                    self.generate_menu_entries(entries, idents, next_item);
+                    self.indent_level -= 1;
+                    self.writeln("});");
                 }
             }
         }
     }
-}
\ No newline at end of file
+
+    fn generate_menu_action(&mut self, ident: &MenuItemIdents) {
+        match &ident.state_field {
+            Some(field) => {
+                self.writeln(&format!("app.{} = !app.{};", field, field));
+                self.writeln(&format!("app.{}(app.{});", ident.handler, field));
+            }
+            None => self.writeln(&format!("app.{}();", ident.handler)),
+        }
+    }
+
+    /// The designed dock tree restricted to existing pages, without empty tab groups
+    fn placed_dock_root(snapshot: &WorldSnapshot) -> Option<DockLayoutNode> {
+        let mut root = snapshot.dock_layout.root.clone();
+        for page_id in root.page_ids() {
+            if !snapshot.pages.iter().any(|page| page.id == page_id) {
+                root.remove_page(page_id);
+            }
+        }
+        root.pruned()
+    }
+
+    /// Emit the tab enum, `DockState` construction, `TabViewer` and the window that owns them
+    fn generate_dock_layout(&mut self, root: &DockLayoutNode, pages: &[PageIdents], has_menu_bar: bool) {
+        let placed = root.page_ids();
+        let placed_pages: Vec<&PageIdents> = pages.iter()
+            .filter(|page| placed.contains(&page.page.id))
+            .collect();
+        let variant_of = |page_id: usize| {
+            pages.iter()
+                .find(|page| page.page.id == page_id)
+                .map(|page| format!("AppTab::{}", page.variant))
+        };
+
+        // Tab enum, one variant per placed page
+        self.writeln("/// Tabs of the generated dock layout, one per design page");
+        self.writeln("#[derive(Clone, Copy, Debug, PartialEq, Eq)]");
+        self.writeln("pub enum AppTab {");
+        self.indent_level += 1;
+        for page in &placed_pages {
+            self.writeln(&format!("{},", page.variant));
+        }
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.writeln("");
+        self.writeln("impl AppTab {");
+        self.indent_level += 1;
+        self.writeln("pub fn title(&self) -> &'static str {");
+        self.indent_level += 1;
+        self.writeln("match self {");
+        self.indent_level += 1;
+        for page in &placed_pages {
+            self.writeln(&format!("AppTab::{} => \"{}\",", page.variant, page.page.name.escape_default()));
+        }
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.writeln("");
+
+        // DockState construction mirroring the designed split tree
+        let tab_list = |ids: &[usize]| {
+            ids.iter().filter_map(|id| variant_of(*id)).collect::<Vec<_>>().join(", ")
+        };
+        self.writeln("/// Build the dock layout arranged in the designer");
+        self.writeln("pub fn create_dock_state() -> DockState<AppTab> {");
+        self.indent_level += 1;
+        self.writeln(&format!(
+            "let mut dock_state = DockState::new(vec![{}]);",
+            tab_list(root.first_leaf_pages())
+        ));
+        if matches!(root, DockLayoutNode::Split { .. }) {
+            self.writeln("let surface = dock_state.main_surface_mut();");
+            let mut next_node = 0;
+            self.generate_dock_splits(root, "NodeIndex::root()", &mut next_node, &tab_list);
+        }
+        self.writeln("dock_state");
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.writeln("");
+
+        // TabViewer routing each tab to its panel function
+        self.writeln("struct AppTabViewer<'a> {");
+        self.indent_level += 1;
+        self.writeln("app: &'a mut App,");
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.writeln("");
+        self.writeln("impl TabViewer for AppTabViewer<'_> {");
+        self.indent_level += 1;
+        self.writeln("type Tab = AppTab;");
+        self.writeln("");
+        self.writeln("fn title(&mut self, tab: &mut Self::Tab) -> egui::WidgetText {");
+        self.indent_level += 1;
+        self.writeln("tab.title().into()");
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.writeln("");
+        self.writeln("fn ui(&mut self, ui: &mut egui::Ui, tab: &mut Self::Tab) {");
+        self.indent_level += 1;
+        self.writeln("match tab {");
+        self.indent_level += 1;
+        for page in &placed_pages {
+            self.writeln(&format!("AppTab::{} => {}(ui, self.app),", page.variant, page.function));
+        }
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.writeln("");
+
+        // Window owning the app state and the dock state
+        self.writeln("pub struct MainWindow {");
+        self.indent_level += 1;
+        self.writeln("app: App,");
+        self.writeln("dock_state: DockState<AppTab>,");
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.writeln("");
+        self.writeln("impl MainWindow {");
+        self.indent_level += 1;
+        self.writeln("pub fn new(cc: &eframe::CreationContext<'_>) -> Self {");
+        self.indent_level += 1;
+        self.writeln("Self {");
+        self.indent_level += 1;
+        self.writeln("app: App::new(cc),");
+        self.writeln("dock_state: create_dock_state(),");
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.writeln("");
+        self.writeln("impl eframe::App for MainWindow {");
+        self.indent_level += 1;
+        self.writeln("fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {");
+        self.indent_level += 1;
+        if has_menu_bar {
+            self.writeln("show_menu_bar(ctx, &mut self.app);");
+            self.writeln("");
+        }
+        self.writeln("DockArea::new(&mut self.dock_state)");
+        self.indent_level += 1;
+        self.writeln(".style(Style::from_egui(ctx.style().as_ref()))");
+        self.writeln(".show(ctx, &mut AppTabViewer { app: &mut self.app });");
+        self.indent_level -= 1;
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.writeln("");
+    }
+
+    /// Recursively emit `split_*` calls. The first child stays in `parent`; the
+    /// second child's first leaf becomes the tabs of the new node.
+    fn generate_dock_splits(
+        &mut self,
+        node: &DockLayoutNode,
+        parent: &str,
+        next_node: &mut usize,
+        tab_list: &dyn Fn(&[usize]) -> String,
+    ) {
+        if let DockLayoutNode::Split { direction, fraction, first, second } = node {
+            let first_name = if matches!(**first, DockLayoutNode::Split { .. }) {
+                format!("node_{}", *next_node)
+            } else {
+                "_".to_string()
+            };
+            let second_name = if matches!(**second, DockLayoutNode::Split { .. }) {
+                format!("node_{}", *next_node + 1)
+            } else {
+                "_".to_string()
+            };
+            *next_node += 2;
+
+            let split_fn = match direction {
+                DockSplitDirection::Horizontal => "split_right",
+                DockSplitDirection::Vertical => "split_below",
+            };
+            self.writeln(&format!(
+                "let [{}, {}] = surface.{}({}, {:.2}, vec![{}]);",
+                first_name, second_name, split_fn, parent, fraction, tab_list(second.first_leaf_pages())
+            ));
+
+            self.generate_dock_splits(first, &first_name, next_node, tab_list);
+            self.generate_dock_splits(second, &second_name, next_node, tab_list);
+        }
+    }
+
+    /// Take the styles, widget types and elements of the generated tab from the snapshot
+    fn prepare(&mut self, snapshot: &WorldSnapshot, tab_kind: &TabKind) {
+        self.style_sheet = snapshot.style_sheet.clone();
+        self.style_idents = Self::style_idents(&snapshot.style_sheet);
+        self.widgets = snapshot.widgets.clone();
+        self.radio_variants = Self::radio_groups(snapshot, tab_kind)
+            .iter()
+            .flat_map(|group| group.members.iter().map(|member| member.entity).zip(group.variant_idents()))
+            .collect();
+
+        // Sort elements by position (top to bottom, left to right)
+        self.elements = snapshot.ui_elements.iter().filter(|element| element.tab.tab_kind == *tab_kind).cloned().collect();
+        self.elements.sort_by(|a, b| {
+            let y_cmp = a.position.y.partial_cmp(&b.position.y).unwrap();
+            if y_cmp == std::cmp::Ordering::Equal {
+                a.position.x.partial_cmp(&b.position.x).unwrap()
+            } else {
+                y_cmp
+            }
+        });
+
+        self.children = HashMap::new();
+        for element in &self.elements {
+            if let Some((parent, _)) = element.container {
+                if self.elements.iter().any(|other| other.entity_id == parent && other.page.page_id == element.page.page_id) {
+                    self.children.entry(parent).or_default().push(element.clone());
+                }
+            }
+        }
+
+        self.encapsulated.clear();
+        for element in &self.elements {
+            let encapsulates = self.widgets.get(element.element_type.kind()).is_some_and(|widget| widget.encapsulates_children());
+            if encapsulates {
+                self.encapsulated.extend(self.descendants(element.entity_id).iter().map(|child| child.entity_id));
+            }
+        }
+    }
+
+    fn generate_page_functions(&mut self, snapshot: &WorldSnapshot, tab_kind: &TabKind) {
+        let uses_styles = snapshot.ui_elements.iter().any(|element| {
+            element.tab.tab_kind == *tab_kind
+                && element.style.class.as_ref().is_some_and(|class| self.style_idents.contains_key(class))
+        });
+
+        let elements = self.elements.clone();
+        for page in Self::page_idents(snapshot) {
+            let page_elements: Vec<&UiElementSnapshot> = elements.iter().filter(|element| element.page.page_id == page.page.id).collect();
+            self.generate_panel_function(&page, &page_elements);
+            self.writeln("");
+        }
+
+        if uses_styles {
+            self.generate_styles_module();
+        }
+    }
+
+    fn style_idents(style_sheet: &StyleSheet) -> HashMap<String, String> {
+        let mut used = std::collections::HashSet::new();
+        let mut idents = HashMap::new();
+        for (index, named) in style_sheet.styles.iter().enumerate() {
+            let mut ident = snake_ident(&named.name);
+            if !used.insert(ident.clone()) {
+                ident = format!("{}_{}", ident, index);
+                used.insert(ident.clone());
+            }
+            idents.insert(named.name.clone(), ident);
+        }
+        idents
+    }
+
+    /// Helpers applying each named style, chained through the inheritance of the styles
+    fn generate_styles_module(&mut self) {
+        let style_sheet = self.style_sheet.clone();
+        self.writeln("/// Named styles of the design; each helper applies the style on top of its parent's");
+        self.writeln("#[allow(dead_code)]");
+        self.writeln("pub mod styles {");
+        self.indent_level += 1;
+        self.writeln("use eframe::egui;");
+
+        for named in &style_sheet.styles {
+            let ident = self.style_idents[&named.name].clone();
+            let own = &named.style;
+            let parent = style_sheet.effective_parent(&named.name).map(|parent| {
+                (self.style_idents[&parent.name].clone(), style_sheet.resolve_named(&parent.name))
+            });
+
+            self.writeln("");
+            self.writeln(&format!("// \"{}\"", named.name.escape_default()));
+            let text = match &parent {
+                Some((_, inherited)) if unsets_emphasis(own, inherited) => {
+                    text_props("text".to_string(), &style_sheet.resolve_named(&named.name))
+                }
+                Some((parent_ident, _)) => text_props(format!("{}_text(text)", parent_ident), own),
+                None => text_props("text".to_string(), own),
+            };
+            self.generate_style_helper(&format!("{}_text(text: egui::RichText) -> egui::RichText", ident), &text);
+
+            let parent_call = |name: &str, arg: &str| match &parent {
+                Some((parent_ident, _)) => format!("{}_{}({})", parent_ident, name, arg),
+                None => arg.to_string(),
+            };
+            let button = button_props(parent_call("button", "button"), own);
+            self.generate_style_helper(&format!("{}_button(button: egui::Button<'_>) -> egui::Button<'_>", ident), &button);
+            let frame = frame_props(parent_call("frame", "frame"), own);
+            self.generate_style_helper(&format!("{}_frame(frame: egui::Frame) -> egui::Frame", ident), &frame);
+
+            match (own.padding, &parent) {
+                (Some(padding), _) => {
+                    self.writeln(&format!("pub fn {}_button_padding(ui: &mut egui::Ui) {{", ident));
+                    self.indent_level += 1;
+                    self.writeln(&format!("ui.spacing_mut().button_padding = egui::vec2({:.1}, {:.1});", padding.x, padding.y));
+                    self.indent_level -= 1;
+                    self.writeln("}");
+                }
+                (None, Some((parent_ident, _))) => {
+                    self.writeln(&format!("pub fn {}_button_padding(ui: &mut egui::Ui) {{", ident));
+                    self.indent_level += 1;
+                    self.writeln(&format!("{}_button_padding(ui);", parent_ident));
+                    self.indent_level -= 1;
+                    self.writeln("}");
+                }
+                (None, None) => self.writeln(&format!("pub fn {}_button_padding(_ui: &mut egui::Ui) {{}}", ident)),
+            }
+        }
+
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.writeln("");
+    }
+
+    fn generate_style_helper(&mut self, signature: &str, body: &str) {
+        self.writeln(&format!("pub fn {} {{", signature));
+        self.indent_level += 1;
+        self.writeln(body);
+        self.indent_level -= 1;
+        self.writeln("}");
+    }
+
+    fn generate_panel_function(&mut self, page: &PageIdents, elements: &[&UiElementSnapshot]) {
+        // Generate function signature; pages without stateful elements leave `app` unused
+        let uses_app = elements.iter().any(|element| {
+            self.widgets.get(element.element_type.kind()).is_some_and(|widget| !widget.app_fields(element).is_empty())
+        });
+        let app_param = if uses_app { "app" } else { "_app" };
+        self.writeln(&format!("pub fn {}(ui: &mut egui::Ui, {}: &mut App) {{", page.function, app_param));
+        self.indent_level += 1;
+
+        // Add panel heading
+        self.writeln(&format!("ui.heading(\"{}\");", page.page.name.escape_default()));
+        self.writeln("ui.separator();");
+        self.writeln("");
+
+        // Elements at their designer position within the panel, which may be one of several dock tabs or splits
+        self.writeln("// Elements positioned exactly as in the designer");
+        // Elements placed in a container are written by it
+        let nested = |element: &UiElementSnapshot| {
+            element.container.is_some_and(|(parent, _)| self.children.get(&parent).is_some_and(|children| {
+                children.iter().any(|child| child.entity_id == element.entity_id)
+            }))
+        };
+        let top_level: Vec<&UiElementSnapshot> = elements.iter().copied().filter(|element| !nested(element)).collect();
+        if !top_level.is_empty() {
+            self.writeln("let panel = ui.max_rect();");
+        }
+        for element in top_level {
+            self.generate_positioned_element_code(element);
+            self.writeln("");
+        }
+
+        self.indent_level -= 1;
+        self.writeln("}");
+    }
+
+    fn generate_positioned_element_code(&mut self, element: &UiElementSnapshot) {
+        if self.widgets.get(element.element_type.kind()).is_none() {
+            self.writeln(&format!("// Unknown widget type '{}'", element.element_type.kind().escape_default()));
+            return;
+        }
+
+        // Offset from the panel's top-left; self-sized elements may use the rest of the panel
+        let (x, y) = (element.position.x, element.position.y);
+        let width = if element.size.width > 0.0 { format!("{:.1}", element.size.width) } else { format!("(panel.width() - {:.1}).max(20.0)", x) };
+        let height = if element.size.height > 0.0 { format!("{:.1}", element.size.height) } else { format!("(panel.height() - {:.1}).max(20.0)", y) };
+        self.writeln(&format!(
+            "ui.scope_builder(egui::UiBuilder::new().max_rect(egui::Rect::from_min_size(panel.min + egui::vec2({:.1}, {:.1}), egui::vec2({}, {}))), |ui| {{",
+            x, y, width, height
+        ));
+        self.indent_level += 1;
+        self.generate_element_body(element);
+        self.indent_level -= 1;
+        self.writeln("});");
+    }
+
+    /// The element's own code with its disabled state and style frame around it
+    fn generate_element_body(&mut self, element: &UiElementSnapshot) {
+        let Some(widget) = self.widgets.get(element.element_type.kind()).cloned() else {
+            self.writeln(&format!("// Unknown widget type '{}'", element.element_type.kind().escape_default()));
+            return;
+        };
+        let enabled = element.element_type.enabled();
+        if !enabled {
+            self.writeln("ui.add_enabled_ui(false, |ui| {");
+            self.indent_level += 1;
+        }
+
+        let mut style = ElementStyleCode::new(&element.style, &self.style_sheet, &self.style_idents);
+        if let Some(expr) = self.label_exprs.get(&element.entity_id) {
+            style.label_expr = Some((element.element_type.label().to_string(), expr.clone()));
+        }
+        let style = &style;
+        // Widgets like buttons and group boxes carry the style themselves; others get a surrounding frame
+        let framed = style.has_frame() && !widget.draws_own_frame();
+        if framed {
+            self.writeln(&format!("{}.show(ui, |ui| {{", style.frame("egui::Frame::new()")));
+            self.indent_level += 1;
+        }
+        widget.generate_code(self, element, style);
+        if framed {
+            self.indent_level -= 1;
+            self.writeln("});");
+        }
+
+        if !enabled {
+            self.indent_level -= 1;
+            self.writeln("});");
+        }
+    }
+
+    /// Radio groups formed by the radio buttons of the generated tab, named like in the whole design
+    fn radio_groups(snapshot: &WorldSnapshot, tab_kind: &TabKind) -> Vec<RadioGroup> {
+        let on_tab: std::collections::HashSet<Entity> = snapshot.ui_elements.iter()
+            .filter(|element| element.tab.tab_kind == *tab_kind)
+            .map(|element| element.entity_id)
+            .collect();
+        let mut groups = RadioGroup::collect(snapshot.ui_elements.iter().filter_map(snapshot_radio_member));
+        for group in &mut groups {
+            group.members.retain(|member| on_tab.contains(&member.entity));
+        }
+        groups.retain(|group| !group.members.is_empty());
+        groups
+    }
+
+    fn generate_radio_group_enum(&mut self, group: &RadioGroup) {
+        let default_index = group.default_index();
+        self.writeln(&format!("/// Options of the \"{}\" radio group", group.id.escape_default()));
+        self.writeln("#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]");
+        self.writeln(&format!("pub enum {} {{", group.enum_name));
+        self.indent_level += 1;
+        for (index, variant) in group.variant_idents().iter().enumerate() {
+            if index == default_index {
+                self.writeln("#[default]");
+            }
+            self.writeln(&format!("{},", variant));
+        }
+        self.indent_level -= 1;
+        self.writeln("}");
+        self.writeln("");
+    }
+
+    /// `(crate, purpose)` of the crates the generated elements need besides eframe
+    fn required_crates(snapshot: &WorldSnapshot, tab_kind: &TabKind) -> Vec<(&'static str, &'static str)> {
+        let mut crates = Vec::new();
+        for element in &snapshot.ui_elements {
+            if element.tab.tab_kind != *tab_kind {
+                continue;
+            }
+            let Some(widget) = snapshot.widgets.get(element.element_type.kind()) else {
+                continue;
+            };
+            for required in widget.required_crates(element) {
+                if !crates.contains(&required) {
+                    crates.push(required);
+                }
+            }
+        }
+        crates
+    }
+
+    fn generate_support_code(&mut self, snapshot: &WorldSnapshot, tab_kind: &TabKind) {
+        // Widget types in the order their first element appears
+        let mut used: Vec<(Arc<dyn DesignerWidget>, Vec<&UiElementSnapshot>)> = Vec::new();
+        for element in &snapshot.ui_elements {
+            if element.tab.tab_kind != *tab_kind {
+                continue;
+            }
+            let Some(widget) = snapshot.widgets.get(element.element_type.kind()) else {
+                continue;
+            };
+            match used.iter_mut().find(|(other, _)| other.kind() == widget.kind()) {
+                Some((_, elements)) => elements.push(element),
+                None => used.push((widget.clone(), vec![element])),
+            }
+        }
+        for (widget, elements) in used {
+            widget.generate_support_code(self, &elements);
+        }
+    }
+
+    /// `(label, check)` of the elements with a validation rule
+    fn validation_checks(&self, snapshot: &WorldSnapshot, tab_kind: &TabKind) -> Vec<(String, String)> {
+        let mut checks: Vec<(String, String)> = Vec::new();
+        for element in &snapshot.ui_elements {
+            if element.tab.tab_kind != *tab_kind || self.encapsulated.contains(&element.entity_id) {
+                continue;
+            }
+            let Some(check) = snapshot.widgets.get(element.element_type.kind()).and_then(|widget| widget.validation_check(element)) else {
+                continue;
+            };
+            let label = element.element_type.label().to_string();
+            if !checks.contains(&(label.clone(), check.clone())) {
+                checks.push((label, check));
+            }
+        }
+        checks
+    }
+
+    fn generate_validate(&mut self, snapshot: &WorldSnapshot, tab_kind: &TabKind) {
+        let checks = self.validation_checks(snapshot, tab_kind);
+        if checks.is_empty() {
+            return;
+        }
+        self.writeln("");
+        self.writeln("/// Labels of the inputs whose values break their validation rule; empty when all are valid");
+        self.writeln("pub fn validate(&self) -> Vec<&'static str> {");
+        self.indent_level += 1;
+        self.writeln("let mut invalid = Vec::new();");
+        for (label, check) in checks {
+            self.writeln(&format!("if {} {{", check));
+            self.indent_level += 1;
+            self.writeln(&format!("invalid.push(\"{}\");", label.escape_default()));
+            self.indent_level -= 1;
+            self.writeln("}");
+        }
+        self.writeln("invalid");
+        self.indent_level -= 1;
+        self.writeln("}");
+    }
+
+    /// `(field, expression)` of the element fields that don't start at their default
+    fn app_field_values(&self, snapshot: &WorldSnapshot, tab_kind: &TabKind) -> Vec<(String, String)> {
+        let mut values = Vec::new();
+        for element in &snapshot.ui_elements {
+            if element.tab.tab_kind != *tab_kind || self.encapsulated.contains(&element.entity_id) {
+                continue;
+            }
+            if let Some(widget) = snapshot.widgets.get(element.element_type.kind()) {
+                values.extend(widget.app_field_values(element));
+            }
+        }
+        values
+    }
+
+    fn generate_app_fields(&mut self, snapshot: &WorldSnapshot, tab_kind: &TabKind) {
+        // Collect unique field names from UI elements
+        let mut fields = std::collections::HashSet::new();
+
+        for element in &snapshot.ui_elements {
+            if element.tab.tab_kind != *tab_kind || self.encapsulated.contains(&element.entity_id) {
+                continue;
+            }
+            let Some(widget) = snapshot.widgets.get(element.element_type.kind()) else {
+                continue;
+            };
+            for (field_name, field_type) in widget.app_fields(element) {
+                if fields.insert(field_name.clone()) {
+                    self.writeln(&format!("{}: {},", field_name, field_type));
+                }
+            }
+        }
+    }
+}
@@ -10,6 +10,7 @@ use crate::events::{CodeGenEvent, CodeGenResponse, CodeGenMode};
 use crate::components::*;
 use crate::integration::TabKind;
 use crate::codegen::CodeGenerator;
+use crate::widgets::{current_widgets, element_parent, WidgetRegistry};
 
 /// Background thread for code generation
 /// This runs in a separate thread to avoid blocking the UI
@@ -82,6 +83,13 @@ pub fn start_codegen_thread(
 pub struct WorldSnapshot {
     pub ui_elements: Vec<UiElementSnapshot>,
     pub grid_settings: GridSettings,
+    pub pages: Vec<DesignPage>,
+    pub dock_layout: DockLayoutDesign,
+    pub menu_bar: MenuBarDesign,
+    pub style_sheet: StyleSheet,
+    pub theme: ThemeDesign,
+    /// Widget types the elements were snapshotted with, used to generate their code
+    pub widgets: WidgetRegistry,
     pub hash: u64,
 }
 
@@ -91,7 +99,11 @@ pub struct UiElementSnapshot {
     pub position: UiElementPosition,
     pub size: UiElementSize,
     pub tab: UiElementTab,
+    pub page: UiElementPage,
+    pub style: UiElementStyle,
     pub element_type: UiElementType,
+    /// Container the element was dropped into and its slot there; its code goes inside the container's
+    pub container: Option<(Entity, usize)>,
 }
 
 #[derive(Clone, Debug)]
@@ -106,6 +118,13 @@ pub enum UiElementType {
         value: String,
         enabled: bool,
         font_size: f32,
+        multiline: bool,
+        rows: usize,
+        password: bool,
+        hint: String,
+        max_length: usize,
+        validation: TextValidation,
+        pattern: String,
     },
     Checkbox {
         label: String,
@@ -119,12 +138,96 @@ pub enum UiElementType {
         enabled: bool,
         font_size: f32,
         group_id: String,
+        variant: String,
+        order: usize,
+        /// Enum and `App` field of the button's group, named against the design's other groups
+        enum_name: String,
+        field: String,
     },
     GroupBox {
         label: String,
         enabled: bool,
         font_size: f32,
     },
+    /// Element of a widget type registered through `DesignerWidget`
+    Custom(CustomElement),
+}
+
+/// Snapshot of an element of a registered widget type
+#[derive(Clone, Debug)]
+pub struct CustomElement {
+    /// `DesignerWidget::kind` of the widget type
+    pub kind: String,
+    pub label: String,
+    pub enabled: bool,
+    pub font_size: f32,
+    /// Widget-specific properties, typically the serialized component
+    pub properties: serde_json::Value,
+}
+
+impl CustomElement {
+    /// Snapshot storing `component` as the element's properties
+    pub fn new(kind: &str, label: &str, enabled: bool, font_size: f32, component: &impl serde::Serialize) -> Self {
+        Self {
+            kind: kind.to_string(),
+            label: label.to_string(),
+            enabled,
+            font_size,
+            properties: serde_json::to_value(component).unwrap_or_default(),
+        }
+    }
+
+    /// The component stored by `CustomElement::new`
+    pub fn component<T: serde::de::DeserializeOwned>(&self) -> Option<T> {
+        serde_json::from_value(self.properties.clone()).ok()
+    }
+}
+
+impl UiElementType {
+    /// `DesignerWidget::kind` of the widget type that generates this element
+    pub fn kind(&self) -> &str {
+        match self {
+            UiElementType::Button { .. } => "button",
+            UiElementType::TextInput { .. } => "text_input",
+            UiElementType::Checkbox { .. } => "checkbox",
+            UiElementType::RadioButton { .. } => "radio_button",
+            UiElementType::GroupBox { .. } => "group_box",
+            UiElementType::Custom(custom) => &custom.kind,
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        match self {
+            UiElementType::Button { label, .. }
+            | UiElementType::TextInput { label, .. }
+            | UiElementType::Checkbox { label, .. }
+            | UiElementType::RadioButton { label, .. }
+            | UiElementType::GroupBox { label, .. } => label,
+            UiElementType::Custom(custom) => &custom.label,
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        match self {
+            UiElementType::Button { enabled, .. }
+            | UiElementType::TextInput { enabled, .. }
+            | UiElementType::Checkbox { enabled, .. }
+            | UiElementType::RadioButton { enabled, .. }
+            | UiElementType::GroupBox { enabled, .. } => *enabled,
+            UiElementType::Custom(custom) => custom.enabled,
+        }
+    }
+
+    pub fn font_size(&self) -> f32 {
+        match self {
+            UiElementType::Button { font_size, .. }
+            | UiElementType::TextInput { font_size, .. }
+            | UiElementType::Checkbox { font_size, .. }
+            | UiElementType::RadioButton { font_size, .. }
+            | UiElementType::GroupBox { font_size, .. } => *font_size,
+            UiElementType::Custom(custom) => custom.font_size,
+        }
+    }
 }
 
 impl std::hash::Hash for UiElementType {
@@ -136,12 +239,31 @@ impl std::hash::Hash for UiElementType {
                 enabled.hash(state);
                 font_size.to_bits().hash(state);
             }
-            UiElementType::TextInput { label, value, enabled, font_size } => {
+            UiElementType::TextInput {
+                label,
+                value,
+                enabled,
+                font_size,
+                multiline,
+                rows,
+                password,
+                hint,
+                max_length,
+                validation,
+                pattern,
+            } => {
                 1u8.hash(state);
                 label.hash(state);
                 value.hash(state);
                 enabled.hash(state);
                 font_size.to_bits().hash(state);
+                multiline.hash(state);
+                rows.hash(state);
+                password.hash(state);
+                hint.hash(state);
+                max_length.hash(state);
+                validation.hash(state);
+                pattern.hash(state);
             }
             UiElementType::Checkbox { label, checked, enabled, font_size } => {
                 2u8.hash(state);
@@ -150,13 +272,17 @@ impl std::hash::Hash for UiElementType {
                 enabled.hash(state);
                 font_size.to_bits().hash(state);
             }
-            UiElementType::RadioButton { label, selected, enabled, font_size, group_id } => {
+            UiElementType::RadioButton { label, selected, enabled, font_size, group_id, variant, order, enum_name, field } => {
                 3u8.hash(state);
                 label.hash(state);
                 selected.hash(state);
                 enabled.hash(state);
                 font_size.to_bits().hash(state);
                 group_id.hash(state);
+                variant.hash(state);
+                order.hash(state);
+                enum_name.hash(state);
+                field.hash(state);
             }
             UiElementType::GroupBox { label, enabled, font_size } => {
                 4u8.hash(state);
@@ -164,6 +290,14 @@ impl std::hash::Hash for UiElementType {
                 enabled.hash(state);
                 font_size.to_bits().hash(state);
             }
+            UiElementType::Custom(custom) => {
+                5u8.hash(state);
+                custom.kind.hash(state);
+                custom.label.hash(state);
+                custom.enabled.hash(state);
+                custom.font_size.to_bits().hash(state);
+                custom.properties.to_string().hash(state);
+            }
         }
     }
 }
@@ -171,68 +305,51 @@ impl std::hash::Hash for UiElementType {
 impl WorldSnapshot {
     /// Create a snapshot from the current world state
     pub fn from_world(world: &mut World) -> Self {
+        let widgets = current_widgets(world);
         let mut ui_elements = Vec::new();
         
-        // Collect UI elements
+        // Collect UI elements of every registered widget type
         let mut query = world.query::<(
             Entity,
             &UiElementPosition,
             &UiElementSize,
             &UiElementTab,
-            Option<&UiButton>,
-            Option<&UiTextInput>,
-            Option<&UiCheckbox>,
-            Option<&UiRadioButton>,
-            Option<&UiGroupBox>,
+            Option<&UiElementPage>,
+            Option<&UiElementStyle>,
         )>();
         
-        for (entity, pos, size, tab, button, text_input, checkbox, radio, group_box) in query.iter(world) {
-            let element_type = if let Some(button) = button {
-                UiElementType::Button {
-                    label: button.label.clone(),
-                    enabled: button.enabled,
-                    font_size: button.font_size,
-                }
-            } else if let Some(text_input) = text_input {
-                UiElementType::TextInput {
-                    label: text_input.label.clone(),
-                    value: text_input.value.clone(),
-                    enabled: text_input.enabled,
-                    font_size: text_input.font_size,
-                }
-            } else if let Some(checkbox) = checkbox {
-                UiElementType::Checkbox {
-                    label: checkbox.label.clone(),
-                    checked: checkbox.checked,
-                    enabled: checkbox.enabled,
-                    font_size: checkbox.font_size,
-                }
-            } else if let Some(radio) = radio {
-                UiElementType::RadioButton {
-                    label: radio.label.clone(),
-                    selected: radio.selected,
-                    enabled: radio.enabled,
-                    font_size: radio.font_size,
-                    group_id: radio.group_id.clone(),
-                }
-            } else if let Some(group_box) = group_box {
-                UiElementType::GroupBox {
-                    label: group_box.label.clone(),
-                    enabled: group_box.enabled,
-                    font_size: group_box.font_size,
-                }
-            } else {
+        for (entity, pos, size, tab, page, style) in query.iter(world) {
+            let element = world.entity(entity);
+            // Instances of a component draw it by calling its generated function
+            if element.contains::<UiSymbolCopy>() {
+                continue;
+            }
+            let Some(element_type) = widgets.widget_of(element).and_then(|widget| widget.snapshot(element)) else {
                 continue; // Skip unknown element types
             };
+            let container = element_parent(world, entity)
+                .filter(|(parent, _)| widgets.widget_of(world.entity(*parent)).is_some_and(|widget| widget.child_slot(world.entity(*parent)).is_some()));
             
             ui_elements.push(UiElementSnapshot {
                 entity_id: entity,
                 position: pos.clone(),
                 size: size.clone(),
                 tab: tab.clone(),
+                page: page.cloned().unwrap_or_default(),
+                style: style.cloned().unwrap_or_default(),
                 element_type,
+                container,
             });
         }
+
+        for widget in widgets.iter() {
+            let mut elements: Vec<&mut UiElementSnapshot> = ui_elements.iter_mut()
+                .filter(|element| element.element_type.kind() == widget.kind())
+                .collect();
+            if !elements.is_empty() {
+                widget.finish_snapshots(&mut elements);
+            }
+        }
         
         // Get grid settings
         let grid_settings = world.query::<&GridSettings>()
@@ -241,15 +358,54 @@ impl WorldSnapshot {
             .cloned()
             .unwrap_or_default();
         
+        // Get design pages and the generated app's dock layout
+        let pages = world.query::<&DesignPages>()
+            .iter(world)
+            .next()
+            .cloned()
+            .unwrap_or_default()
+            .pages;
+        let dock_layout = world.query::<&DockLayoutDesign>()
+            .iter(world)
+            .next()
+            .cloned()
+            .unwrap_or_default();
+        let menu_bar = world.query::<&MenuBarDesign>()
+            .iter(world)
+            .next()
+            .cloned()
+            .unwrap_or_default();
+        let style_sheet = world.query::<&StyleSheet>()
+            .iter(world)
+            .next()
+            .cloned()
+            .unwrap_or_default();
+        let theme = world.query::<&ThemeDesign>()
+            .iter(world)
+            .next()
+            .cloned()
+            .unwrap_or_default();
+        
         // Calculate hash for change detection
         let mut hasher = DefaultHasher::new();
         ui_elements.hash(&mut hasher);
         grid_settings.hash(&mut hasher);
+        pages.hash(&mut hasher);
+        dock_layout.hash(&mut hasher);
+        menu_bar.hash(&mut hasher);
+        style_sheet.hash(&mut hasher);
+        theme.hash(&mut hasher);
         let hash = hasher.finish();
         
         Self {
             ui_elements,
             grid_settings,
+            pages,
+            dock_layout,
+            menu_bar,
+            style_sheet,
+            theme,
+            widgets,
             hash,
         }
     }
@@ -261,98 +417,8 @@ fn generate_code_from_snapshot(
     mode: &CodeGenMode,
     tab_kind: &TabKind,
 ) -> Result<String, String> {
-    // Filter elements for the specific tab
-    let tab_elements: Vec<&UiElementSnapshot> = snapshot.ui_elements
-        .iter()
-        .filter(|element| element.tab.tab_kind == *tab_kind)
-        .collect();
-    
     match mode {
-        CodeGenMode::FullApp => generate_full_app_from_snapshot(&tab_elements),
-        CodeGenMode::PanelFunction => generate_panel_from_snapshot(&tab_elements),
+        CodeGenMode::FullApp => Ok(CodeGenerator::full_app_from_snapshot(snapshot, tab_kind)),
+        CodeGenMode::PanelFunction => Ok(CodeGenerator::panel_functions_from_snapshot(snapshot, tab_kind)),
     }
 }
-
-fn generate_full_app_from_snapshot(elements: &[&UiElementSnapshot]) -> Result<String, String> {
-    // First generate the panel function
-    let panel_code = generate_panel_from_snapshot(elements)?;
-    
-    // Now generate the full app that uses the panel function
-    let mut code = String::from("// Generated by Mobius Designer\n");
-    code.push_str("// Full Application Code\n\n");
-    code.push_str("use eframe::egui;\n\n");
-    
-    // Add the panel function first
-    code.push_str(&panel_code);
-    code.push_str("\n\n");
-    
-    // Add the App struct
-    code.push_str("#[derive(Default)]\n");
-    code.push_str("pub struct App {\n");
-    code.push_str("    // Add your app state fields here\n");
-    code.push_str("}\n\n");
-    
-    // Implement the App trait
-    code.push_str("impl eframe::App for App {\n");
-    code.push_str("    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {\n");
-    code.push_str("        egui::CentralPanel::default().show(ctx, |ui| {\n");
-    code.push_str("            show_generated_panel(ui, self);\n");
-    code.push_str("        });\n");
-    code.push_str("    }\n");
-    code.push_str("}\n\n");
-    
-    // Add the main function
-    code.push_str("fn main() -> Result<(), eframe::Error> {\n");
-    code.push_str("    let options = eframe::NativeOptions::default();\n");
-    code.push_str("    eframe::run_native(\n");
-    code.push_str("        \"Generated App\",\n");
-    code.push_str("        options,\n");
-    code.push_str("        Box::new(|_cc| Ok(Box::new(App::default()))),\n");
-    code.push_str("    )\n");
-    code.push_str("}\n");
-    
-    Ok(code)
-}
-
-fn generate_panel_from_snapshot(elements: &[&UiElementSnapshot]) -> Result<String, String> {
-    // Generate panel function code
-    let mut code = String::from("// Generated Panel Function\n");
-    code.push_str("use egui;\n\n");
-    code.push_str("pub fn show_generated_panel(ui: &mut egui::Ui, app: &mut App) {\n");
-    code.push_str("    ui.heading(\"Generated Panel\");\n");
-    code.push_str("    ui.separator();\n\n");
-    
-    for element in elements {
-        match &element.element_type {
-            UiElementType::Button { label, enabled, .. } => {
-                code.push_str(&format!(
-                    "    egui::Area::new(egui::Id::new(\"btn_{:?}\"))\n",
-                    element.entity_id
-                ));
-                code.push_str(&format!(
-                    "        .fixed_pos(egui::pos2({:.1}, {:.1}))\n",
-                    element.position.x, element.position.y
-                ));
-                code.push_str("        .show(ui.ctx(), |ui| {\n");
-                code.push_str(&format!(
-                    "            if ui.add_sized(egui::vec2({:.1}, {:.1}), egui::Button::new(\"{}\"){}).clicked() {{\n",
-                    element.size.width, element.size.height, label,
-                    if !enabled { ".enabled(false)" } else { "" }
-                ));
-                code.push_str(&format!(
-                    "                // Handle {} button click\n",
-                    label
-                ));
-                code.push_str("            }\n");
-                code.push_str("        });\n");
-            }
-            _ => {
-                code.push_str(&format!("    // TODO: Implement {:?}\n", element.element_type));
-            }
-        }
-    }
-    
-    code.push_str("}\n");
-    
-    Ok(code)
-}
\ No newline at end of file
@@ -0,0 +1,95 @@
+use bevy_ecs::prelude::*;
+use bevy_ecs::reflect::ReflectComponent;
+use bevy_reflect::Reflect;
+use serde::{Deserialize, Serialize};
+use crate::codegen::snake_ident;
+use super::inspector::*;
+use super::style::DEFAULT_FONT_SIZE;
+
+/// Header showing or hiding the elements placed under it, as `egui::CollapsingHeader`
+#[derive(Component, Clone, Debug, Reflect, Serialize, Deserialize)]
+#[reflect(Component, @InspectorSection::new("🔽 Collapsing Header Properties", 0))]
+pub struct UiCollapsingHeader {
+    pub label: String,
+    pub enabled: bool,
+    #[reflect(@InspectorRange::new(8.0, 48.0), @InspectorUnit("pt"))]
+    pub font_size: f32,
+    /// Whether the generated header starts open
+    pub default_open: bool,
+    /// Whether the header is open on the design canvas
+    #[reflect(@InspectorHidden)]
+    pub expanded: bool,
+}
+
+impl UiCollapsingHeader {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            enabled: true,
+            font_size: DEFAULT_FONT_SIZE,
+            default_open: true,
+            expanded: true,
+        }
+    }
+}
+
+/// Fixed-size viewport scrolling the elements placed in it, as `egui::ScrollArea`
+#[derive(Component, Clone, Debug, Reflect, Serialize, Deserialize)]
+#[reflect(Component, @InspectorSection::new("↕ Scroll Area Properties", 0))]
+pub struct UiScrollArea {
+    /// Name shown in the designer
+    pub name: String,
+    pub horizontal: bool,
+    pub vertical: bool,
+    /// Whether the canvas also shows the elements placed outside the viewport
+    #[reflect(@InspectorHidden)]
+    pub expanded: bool,
+}
+
+impl UiScrollArea {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            horizontal: false,
+            vertical: true,
+            expanded: false,
+        }
+    }
+}
+
+/// Row of tabs, each showing its own elements. The generated `App` keeps the open tab.
+#[derive(Component, Clone, Debug, Reflect, Serialize, Deserialize)]
+#[reflect(Component, @InspectorSection::new("📑 Tab Strip Properties", 0))]
+pub struct UiTabStrip {
+    /// Name of the strip; the `App` field holding the open tab is named after it
+    pub name: String,
+    pub enabled: bool,
+    #[reflect(@InspectorRange::new(8.0, 48.0), @InspectorUnit("pt"))]
+    pub font_size: f32,
+    #[reflect(@InspectorHidden)]
+    pub tabs: Vec<String>,
+    /// Tab the generated app starts on
+    #[reflect(@InspectorHidden)]
+    pub default_tab: usize,
+    /// Tab open on the design canvas; elements dropped on the strip go to it
+    #[reflect(@InspectorHidden)]
+    pub current: usize,
+}
+
+impl UiTabStrip {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            enabled: true,
+            font_size: DEFAULT_FONT_SIZE,
+            tabs: vec!["General".to_string(), "Advanced".to_string()],
+            default_tab: 0,
+            current: 0,
+        }
+    }
+
+    /// Name of the `App` field holding the index of the open tab
+    pub fn tab_field(&self) -> String {
+        format!("{}_tab", snake_ident(&self.name))
+    }
+}
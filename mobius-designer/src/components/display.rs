@@ -0,0 +1,78 @@
+use bevy_ecs::prelude::*;
+use bevy_ecs::reflect::ReflectComponent;
+use bevy_reflect::Reflect;
+use serde::{Deserialize, Serialize};
+use super::inspector::*;
+
+/// Static text. Color and emphasis come from the element style.
+#[derive(Component, Clone, Debug, Reflect, Serialize, Deserialize)]
+#[reflect(Component, @InspectorSection::new("🏷 Label Properties", 0))]
+pub struct UiLabel {
+    pub text: String,
+    pub enabled: bool,
+    #[reflect(@InspectorRange::new(8.0, 48.0), @InspectorUnit("pt"))]
+    pub font_size: f32,
+    pub heading: bool,
+    pub monospace: bool,
+    pub weak: bool,
+    /// Wrap at the element's width instead of growing to fit the text
+    pub wrap: bool,
+}
+
+#[derive(Component, Clone, Debug, Reflect, Serialize, Deserialize)]
+#[reflect(Component, @InspectorSection::new("🔗 Hyperlink Properties", 0))]
+pub struct UiHyperlink {
+    pub label: String,
+    pub url: String,
+    pub enabled: bool,
+    #[reflect(@InspectorRange::new(8.0, 48.0), @InspectorUnit("pt"))]
+    pub font_size: f32,
+    pub open_in_new_tab: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum SeparatorOrientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+/// Line dividing the elements around it
+#[derive(Component, Clone, Debug, Reflect, Serialize, Deserialize)]
+#[reflect(Component, @InspectorSection::new("➖ Separator Properties", 0))]
+pub struct UiSeparator {
+    /// Name shown in the designer
+    pub name: String,
+    pub orientation: SeparatorOrientation,
+}
+
+/// Invisible element that only takes up its size
+#[derive(Component, Clone, Debug, Reflect, Serialize, Deserialize)]
+#[reflect(Component, @InspectorSection::new("⬜ Spacer Properties", 0))]
+pub struct UiSpacer {
+    /// Name shown in the designer
+    pub name: String,
+}
+
+/// How an image fills its element
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum ImageFit {
+    /// Scaled to fit inside the element, keeping its aspect ratio
+    #[default]
+    Contain,
+    /// Scaled to cover the element, keeping its aspect ratio and cropping the overflow
+    Cover,
+    /// Stretched to the element's size
+    Stretch,
+}
+
+/// Picture loaded from a PNG or JPEG file of the project
+#[derive(Component, Clone, Debug, Reflect, Serialize, Deserialize)]
+#[reflect(Component, @InspectorSection::new("🖼 Image Properties", 0))]
+pub struct UiImage {
+    /// Name shown in the designer
+    pub name: String,
+    /// Image file, relative to the project directory
+    pub path: String,
+    pub fit: ImageFit,
+}
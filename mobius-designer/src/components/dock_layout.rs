@@ -0,0 +1,117 @@
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Direction of a split in the generated application's dock layout
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DockSplitDirection {
+    /// Second child is placed to the right of the first
+    Horizontal,
+    /// Second child is placed below the first
+    Vertical,
+}
+
+/// A node of the dock layout tree designed for the generated application
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum DockLayoutNode {
+    /// A tab group holding design pages by id
+    Leaf { pages: Vec<usize> },
+    /// Two nodes sharing space; `fraction` is the share given to `first`
+    Split {
+        direction: DockSplitDirection,
+        fraction: f32,
+        first: Box<DockLayoutNode>,
+        second: Box<DockLayoutNode>,
+    },
+}
+
+impl Default for DockLayoutNode {
+    fn default() -> Self {
+        DockLayoutNode::Leaf { pages: vec![0] }
+    }
+}
+
+impl std::hash::Hash for DockLayoutNode {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        match self {
+            DockLayoutNode::Leaf { pages } => {
+                0u8.hash(state);
+                pages.hash(state);
+            }
+            DockLayoutNode::Split { direction, fraction, first, second } => {
+                1u8.hash(state);
+                direction.hash(state);
+                fraction.to_bits().hash(state);
+                first.hash(state);
+                second.hash(state);
+            }
+        }
+    }
+}
+
+impl DockLayoutNode {
+    /// All page ids placed anywhere in this subtree, in layout order
+    pub fn page_ids(&self) -> Vec<usize> {
+        match self {
+            DockLayoutNode::Leaf { pages } => pages.clone(),
+            DockLayoutNode::Split { first, second, .. } => {
+                let mut ids = first.page_ids();
+                ids.extend(second.page_ids());
+                ids
+            }
+        }
+    }
+
+    /// Pages of the top-left-most leaf. egui_dock keeps these in the node being split.
+    pub fn first_leaf_pages(&self) -> &[usize] {
+        match self {
+            DockLayoutNode::Leaf { pages } => pages,
+            DockLayoutNode::Split { first, .. } => first.first_leaf_pages(),
+        }
+    }
+
+    /// Remove a page from every leaf it appears in
+    pub fn remove_page(&mut self, page_id: usize) {
+        match self {
+            DockLayoutNode::Leaf { pages } => pages.retain(|id| *id != page_id),
+            DockLayoutNode::Split { first, second, .. } => {
+                first.remove_page(page_id);
+                second.remove_page(page_id);
+            }
+        }
+    }
+
+    /// Collapse a split back into a single leaf holding the pages of both children
+    pub fn merge(&mut self) {
+        let pages = self.page_ids();
+        *self = DockLayoutNode::Leaf { pages };
+    }
+
+    /// Copy of this subtree with empty leaves removed and their splits collapsed.
+    /// Returns `None` when no page is placed at all.
+    pub fn pruned(&self) -> Option<DockLayoutNode> {
+        match self {
+            DockLayoutNode::Leaf { pages } if pages.is_empty() => None,
+            DockLayoutNode::Leaf { .. } => Some(self.clone()),
+            DockLayoutNode::Split { direction, fraction, first, second } => {
+                match (first.pruned(), second.pruned()) {
+                    (Some(first), Some(second)) => Some(DockLayoutNode::Split {
+                        direction: *direction,
+                        fraction: *fraction,
+                        first: Box::new(first),
+                        second: Box::new(second),
+                    }),
+                    (Some(node), None) | (None, Some(node)) => Some(node),
+                    (None, None) => None,
+                }
+            }
+        }
+    }
+}
+
+/// Dock layout of the generated application. When disabled the generated app
+/// shows the first page in a single `CentralPanel`.
+#[derive(Component, Clone, Debug, Default, Hash)]
+pub struct DockLayoutDesign {
+    pub enabled: bool,
+    pub root: DockLayoutNode,
+}
@@ -0,0 +1,104 @@
+use bevy_ecs::prelude::*;
+use bevy_ecs::reflect::ReflectComponent;
+use bevy_reflect::Reflect;
+use serde::{Deserialize, Serialize};
+use super::inspector::*;
+
+/// Language a code editor highlights
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum CodeLanguage {
+    PlainText,
+    #[default]
+    Rust,
+    Python,
+    JavaScript,
+    Json,
+    Html,
+    Css,
+    Sql,
+    Yaml,
+    Markdown,
+    Shell,
+}
+
+impl CodeLanguage {
+    pub const ALL: [CodeLanguage; 11] = [
+        CodeLanguage::PlainText,
+        CodeLanguage::Rust,
+        CodeLanguage::Python,
+        CodeLanguage::JavaScript,
+        CodeLanguage::Json,
+        CodeLanguage::Html,
+        CodeLanguage::Css,
+        CodeLanguage::Sql,
+        CodeLanguage::Yaml,
+        CodeLanguage::Markdown,
+        CodeLanguage::Shell,
+    ];
+
+    /// File extension syntect finds the language's syntax by
+    pub fn extension(&self) -> &'static str {
+        match self {
+            CodeLanguage::PlainText => "txt",
+            CodeLanguage::Rust => "rs",
+            CodeLanguage::Python => "py",
+            CodeLanguage::JavaScript => "js",
+            CodeLanguage::Json => "json",
+            CodeLanguage::Html => "html",
+            CodeLanguage::Css => "css",
+            CodeLanguage::Sql => "sql",
+            CodeLanguage::Yaml => "yaml",
+            CodeLanguage::Markdown => "md",
+            CodeLanguage::Shell => "sh",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CodeLanguage::PlainText => "Plain Text",
+            CodeLanguage::Rust => "Rust",
+            CodeLanguage::Python => "Python",
+            CodeLanguage::JavaScript => "JavaScript",
+            CodeLanguage::Json => "JSON",
+            CodeLanguage::Html => "HTML",
+            CodeLanguage::Css => "CSS",
+            CodeLanguage::Sql => "SQL",
+            CodeLanguage::Yaml => "YAML",
+            CodeLanguage::Markdown => "Markdown",
+            CodeLanguage::Shell => "Shell",
+        }
+    }
+}
+
+/// Editable multiline code or text with syntax highlighting, kept in a `String` field of the generated app
+#[derive(Component, Clone, Debug, Reflect, Serialize, Deserialize)]
+#[reflect(Component, @InspectorSection::new("📝 Code Editor Properties", 0))]
+pub struct UiCodeEditor {
+    /// Name of the editor; the `App` field is named after it
+    pub name: String,
+    pub enabled: bool,
+    #[reflect(@InspectorRange::new(8.0, 32.0), @InspectorUnit("pt"))]
+    pub font_size: f32,
+    pub language: CodeLanguage,
+    /// Rows of text the editor shows
+    #[reflect(@InspectorRange::new(1.0, 60.0))]
+    pub rows: usize,
+    pub line_numbers: bool,
+    /// Text the app starts with; edited in the Inspector
+    #[reflect(@InspectorHidden)]
+    pub text: String,
+}
+
+impl UiCodeEditor {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            enabled: true,
+            font_size: 13.0,
+            language: CodeLanguage::Rust,
+            rows: 10,
+            line_numbers: true,
+            text: "fn main() {\n    println!(\"Hello, world!\");\n}\n".to_string(),
+        }
+    }
+}
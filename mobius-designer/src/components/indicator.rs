@@ -0,0 +1,105 @@
+use bevy_ecs::prelude::*;
+use bevy_ecs::reflect::ReflectComponent;
+use bevy_reflect::Reflect;
+use egui::Color32;
+use serde::{Deserialize, Serialize};
+use super::inspector::*;
+use super::style::DEFAULT_FONT_SIZE;
+
+/// Bar filled to a fraction bound to an `f32` field of the generated app
+#[derive(Component, Clone, Debug, Reflect, Serialize, Deserialize)]
+#[reflect(Component, @InspectorSection::new("▬ Progress Bar Properties", 0))]
+pub struct UiProgressBar {
+    /// Name of the bar; the `App` field is named after it
+    pub name: String,
+    /// Fraction the app starts with, from 0 to 1
+    #[reflect(@InspectorRange::new(0.0, 1.0))]
+    pub value: f32,
+    /// Write the fraction as a percentage on the bar
+    pub show_percentage: bool,
+    /// Spin a small indicator on the bar while it isn't full
+    pub animate: bool,
+}
+
+impl UiProgressBar {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            value: 0.4,
+            show_percentage: true,
+            animate: false,
+        }
+    }
+}
+
+/// Busy indicator shown while a `bool` field of the generated app is set
+#[derive(Component, Clone, Debug, Reflect, Serialize, Deserialize)]
+#[reflect(Component, @InspectorSection::new("◌ Spinner Properties", 0))]
+pub struct UiSpinner {
+    /// Name of the spinner; the `App` field is named after it
+    pub name: String,
+    /// Whether the app starts with the spinner showing
+    pub active: bool,
+    #[reflect(@InspectorRange::new(8.0, 64.0), @InspectorUnit("px"))]
+    pub size: f32,
+}
+
+impl UiSpinner {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            active: true,
+            size: 20.0,
+        }
+    }
+}
+
+/// State of a status LED; the generated code gets the same enum
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum LedStatus {
+    #[default]
+    Off,
+    Ok,
+    Warning,
+    Error,
+}
+
+impl LedStatus {
+    pub const ALL: [LedStatus; 4] = [LedStatus::Off, LedStatus::Ok, LedStatus::Warning, LedStatus::Error];
+
+    pub fn color(&self) -> Color32 {
+        match self {
+            LedStatus::Off => Color32::from_gray(90),
+            LedStatus::Ok => Color32::from_rgb(40, 200, 80),
+            LedStatus::Warning => Color32::from_rgb(240, 180, 0),
+            LedStatus::Error => Color32::from_rgb(220, 50, 50),
+        }
+    }
+}
+
+/// Colored light with a label, showing a `LedStatus` field of the generated app
+#[derive(Component, Clone, Debug, Reflect, Serialize, Deserialize)]
+#[reflect(Component, @InspectorSection::new("● Status LED Properties", 0))]
+pub struct UiStatusLed {
+    /// Text next to the light; the `App` field is named after it
+    pub label: String,
+    pub enabled: bool,
+    #[reflect(@InspectorRange::new(8.0, 48.0), @InspectorUnit("pt"))]
+    pub font_size: f32,
+    /// Status the app starts with
+    pub status: LedStatus,
+    #[reflect(@InspectorRange::new(6.0, 48.0), @InspectorUnit("px"))]
+    pub diameter: f32,
+}
+
+impl UiStatusLed {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            enabled: true,
+            font_size: DEFAULT_FONT_SIZE,
+            status: LedStatus::Ok,
+            diameter: 12.0,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use bevy_reflect::Reflect;
+
+/// Inspector section a reflected component is shown in, attached to the type
+/// with `#[reflect(@InspectorSection::new(..))]`. Sections are sorted by `order`.
+#[derive(Reflect, Clone, Debug)]
+pub struct InspectorSection {
+    pub title: &'static str,
+    pub order: i32,
+}
+
+impl InspectorSection {
+    pub const fn new(title: &'static str, order: i32) -> Self {
+        Self { title, order }
+    }
+}
+
+/// Allowed range of a numeric field
+#[derive(Reflect, Clone, Copy, Debug)]
+pub struct InspectorRange {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl InspectorRange {
+    pub const fn new(min: f64, max: f64) -> Self {
+        Self { min, max }
+    }
+}
+
+/// Unit shown after a numeric field, e.g. `"px"` or `"pt"`
+#[derive(Reflect, Clone, Copy, Debug)]
+pub struct InspectorUnit(pub &'static str);
+
+/// Field that is reflected but not shown in the Inspector
+#[derive(Reflect, Clone, Copy, Debug)]
+pub struct InspectorHidden;
@@ -1,8 +1,14 @@
 use bevy_ecs::prelude::*;
+use bevy_ecs::reflect::ReflectComponent;
+use bevy_reflect::Reflect;
+use super::inspector::*;
 
-#[derive(Component, Clone, Debug)]
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, @InspectorSection::new("📍 Position", 10))]
 pub struct UiElementPosition {
+    #[reflect(@InspectorRange::new(0.0, 2000.0), @InspectorUnit("px"))]
     pub x: f32,
+    #[reflect(@InspectorRange::new(0.0, 2000.0), @InspectorUnit("px"))]
     pub y: f32,
 }
 
@@ -13,9 +19,12 @@ impl std::hash::Hash for UiElementPosition {
     }
 }
 
-#[derive(Component, Clone, Default, Debug)]
+#[derive(Component, Clone, Default, Debug, Reflect)]
+#[reflect(Component, @InspectorSection::new("📐 Size", 11))]
 pub struct UiElementSize {
+    #[reflect(@InspectorRange::new(10.0, 500.0), @InspectorUnit("px"))]
     pub width: f32,
+    #[reflect(@InspectorRange::new(10.0, 500.0), @InspectorUnit("px"))]
     pub height: f32,
 }
 
@@ -32,9 +41,12 @@ pub struct UiElementDragging {
     pub offset_y: f32,
 }
 
+/// Container element the element was dropped into, see `DesignerWidget::child_slot`
 #[derive(Component, Clone)]
 pub struct UiElementContainer {
     pub parent_group: Option<Entity>,
+    /// Part of the container the element is in, such as the page of a tab strip
+    pub slot: usize,
 }
 
 #[derive(Component, Clone, Default)]
@@ -0,0 +1,120 @@
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A clickable menu item. The generated code calls `handler` on the `MenuHandler` trait.
+#[derive(Clone, Debug, PartialEq, Hash, Serialize, Deserialize)]
+pub struct MenuItemDesign {
+    pub label: String,
+    /// Name of the generated handler method, e.g. `on_save`
+    pub handler: String,
+    /// Keyboard shortcut such as `Ctrl+S` or `Ctrl+Shift+N`; empty for none
+    pub shortcut: String,
+    /// Checkable items toggle a `bool` field and pass the new state to the handler
+    pub checkable: bool,
+    /// Initial state of a checkable item
+    pub checked: bool,
+}
+
+impl MenuItemDesign {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            handler: format!("on_{}", crate::codegen::snake_ident(label)),
+            shortcut: String::new(),
+            checkable: false,
+            checked: false,
+        }
+    }
+
+    pub fn with_shortcut(mut self, shortcut: &str) -> Self {
+        self.shortcut = shortcut.to_string();
+        self
+    }
+
+    /// The parsed shortcut, `None` if empty or not understood
+    pub fn keyboard_shortcut(&self) -> Option<egui::KeyboardShortcut> {
+        parse_shortcut(&self.shortcut)
+    }
+}
+
+/// Parse shortcuts written as `Ctrl+Shift+S`. `Ctrl` and `Cmd` both map to
+/// `Modifiers::COMMAND` so shortcuts follow the platform convention.
+pub fn parse_shortcut(text: &str) -> Option<egui::KeyboardShortcut> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+
+    let mut modifiers = egui::Modifiers::NONE;
+    let mut parts: Vec<&str> = text.split('+').map(|part| part.trim()).collect();
+    let key_name = parts.pop()?;
+    for part in parts {
+        match part.to_ascii_lowercase().as_str() {
+            "ctrl" | "cmd" | "command" => modifiers = modifiers.plus(egui::Modifiers::COMMAND),
+            "shift" => modifiers = modifiers.plus(egui::Modifiers::SHIFT),
+            "alt" | "option" => modifiers = modifiers.plus(egui::Modifiers::ALT),
+            _ => return None,
+        }
+    }
+
+    let key = egui::Key::from_name(key_name)
+        .or_else(|| egui::Key::from_name(&key_name.to_ascii_uppercase()))?;
+    Some(egui::KeyboardShortcut::new(modifiers, key))
+}
+
+/// An entry inside a menu or submenu
+#[derive(Clone, Debug, PartialEq, Hash, Serialize, Deserialize)]
+pub enum MenuEntry {
+    Item(MenuItemDesign),
+    Separator,
+    Submenu { title: String, entries: Vec<MenuEntry> },
+}
+
+/// A top-level menu of the generated application's menu bar
+#[derive(Clone, Debug, PartialEq, Hash, Serialize, Deserialize)]
+pub struct MenuDesign {
+    pub title: String,
+    pub entries: Vec<MenuEntry>,
+}
+
+/// Menu bar of the generated application
+#[derive(Component, Clone, Debug, PartialEq, Hash, Serialize, Deserialize)]
+pub struct MenuBarDesign {
+    pub menus: Vec<MenuDesign>,
+}
+
+impl Default for MenuBarDesign {
+    fn default() -> Self {
+        Self {
+            menus: vec![MenuDesign {
+                title: "File".to_string(),
+                entries: vec![
+                    MenuEntry::Item(MenuItemDesign::new("New").with_shortcut("Ctrl+N")),
+                    MenuEntry::Item(MenuItemDesign::new("Open").with_shortcut("Ctrl+O")),
+                    MenuEntry::Item(MenuItemDesign::new("Save").with_shortcut("Ctrl+S")),
+                ],
+            }],
+        }
+    }
+}
+
+impl MenuBarDesign {
+    /// Every item in the menu bar, depth first
+    pub fn items(&self) -> Vec<&MenuItemDesign> {
+        fn collect<'a>(entries: &'a [MenuEntry], items: &mut Vec<&'a MenuItemDesign>) {
+            for entry in entries {
+                match entry {
+                    MenuEntry::Item(item) => items.push(item),
+                    MenuEntry::Separator => {}
+                    MenuEntry::Submenu { entries, .. } => collect(entries, items),
+                }
+            }
+        }
+
+        let mut items = Vec::new();
+        for menu in &self.menus {
+            collect(&menu.entries, &mut items);
+        }
+        items
+    }
+}
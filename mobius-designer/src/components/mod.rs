@@ -2,8 +2,40 @@ pub mod ui;
 pub mod layout;
 pub mod grid;
 pub mod panels;
+pub mod pages;
+pub mod dock_layout;
+pub mod menu_bar;
+pub mod preview;
+pub mod style;
+pub mod theme;
+pub mod inspector;
+pub mod radio_group;
+pub mod display;
+pub mod table;
+pub mod picker;
+pub mod indicator;
+pub mod container;
+pub mod editor;
+pub mod tree;
+pub mod symbol;
 
 pub use ui::*;
 pub use layout::*;
 pub use grid::*;
-pub use panels::*;
\ No newline at end of file
+pub use panels::*;
+pub use pages::*;
+pub use dock_layout::*;
+pub use menu_bar::*;
+pub use preview::*;
+pub use style::*;
+pub use theme::*;
+pub use inspector::*;
+pub use radio_group::*;
+pub use display::*;
+pub use table::*;
+pub use picker::*;
+pub use indicator::*;
+pub use container::*;
+pub use editor::*;
+pub use tree::*;
+pub use symbol::*;
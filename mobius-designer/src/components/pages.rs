@@ -0,0 +1,53 @@
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A named design page. Each page is generated as its own panel function.
+#[derive(Clone, Debug, PartialEq, Hash, Serialize, Deserialize)]
+pub struct DesignPage {
+    pub id: usize,
+    pub name: String,
+}
+
+/// The set of pages in the current design and the one shown on the canvas
+#[derive(Component, Clone, Debug)]
+pub struct DesignPages {
+    pub pages: Vec<DesignPage>,
+    pub active_page: usize,
+    pub next_id: usize,
+}
+
+impl Default for DesignPages {
+    fn default() -> Self {
+        Self {
+            pages: vec![DesignPage { id: 0, name: "Main".to_string() }],
+            active_page: 0,
+            next_id: 1,
+        }
+    }
+}
+
+impl DesignPages {
+    /// Add a new page and return its id
+    pub fn add_page(&mut self, name: String) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.pages.push(DesignPage { id, name });
+        id
+    }
+
+    pub fn page(&self, id: usize) -> Option<&DesignPage> {
+        self.pages.iter().find(|page| page.id == id)
+    }
+
+    pub fn page_name(&self, id: usize) -> String {
+        self.page(id)
+            .map(|page| page.name.clone())
+            .unwrap_or_else(|| format!("Page {}", id))
+    }
+}
+
+/// The design page an element lives on
+#[derive(Component, Clone, Debug, Default, PartialEq, Hash)]
+pub struct UiElementPage {
+    pub page_id: usize,
+}
@@ -0,0 +1,91 @@
+use bevy_ecs::prelude::*;
+use bevy_ecs::reflect::ReflectComponent;
+use bevy_reflect::Reflect;
+use chrono::{NaiveDate, NaiveTime};
+use egui::Color32;
+use serde::{Deserialize, Serialize};
+use super::inspector::*;
+use super::style::DEFAULT_FONT_SIZE;
+
+/// Date chosen from a calendar popup, kept in a `chrono::NaiveDate` field of the generated app
+#[derive(Component, Clone, Debug, Reflect, Serialize, Deserialize)]
+#[reflect(Component, @InspectorSection::new("📅 Date Picker Properties", 0))]
+pub struct UiDatePicker {
+    pub label: String,
+    pub enabled: bool,
+    #[reflect(@InspectorRange::new(8.0, 48.0), @InspectorUnit("pt"))]
+    pub font_size: f32,
+    /// Date the app starts with; edited in the Inspector
+    #[reflect(ignore)]
+    pub date: NaiveDate,
+    /// `strftime` format of the date on the button
+    pub format: String,
+    /// Show week numbers in the calendar
+    pub calendar_week: bool,
+}
+
+impl UiDatePicker {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            enabled: true,
+            font_size: DEFAULT_FONT_SIZE,
+            date: chrono::Local::now().date_naive(),
+            format: "%Y-%m-%d".to_string(),
+            calendar_week: true,
+        }
+    }
+}
+
+/// Time of day edited as hours and minutes, kept in a `chrono::NaiveTime` field of the generated app
+#[derive(Component, Clone, Debug, Reflect, Serialize, Deserialize)]
+#[reflect(Component, @InspectorSection::new("🕓 Time Input Properties", 0))]
+pub struct UiTimeInput {
+    pub label: String,
+    pub enabled: bool,
+    #[reflect(@InspectorRange::new(8.0, 48.0), @InspectorUnit("pt"))]
+    pub font_size: f32,
+    /// Time the app starts with; edited in the Inspector
+    #[reflect(ignore)]
+    pub time: NaiveTime,
+    pub show_seconds: bool,
+}
+
+impl UiTimeInput {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            enabled: true,
+            font_size: DEFAULT_FONT_SIZE,
+            time: NaiveTime::from_hms_opt(9, 0, 0).unwrap_or_default(),
+            show_seconds: false,
+        }
+    }
+}
+
+/// Color chosen with `color_edit_button_srgba`, kept in a `Color32` field of the generated app
+#[derive(Component, Clone, Debug, Reflect, Serialize, Deserialize)]
+#[reflect(Component, @InspectorSection::new("🎨 Color Picker Properties", 0))]
+pub struct UiColorPicker {
+    pub label: String,
+    pub enabled: bool,
+    #[reflect(@InspectorRange::new(8.0, 48.0), @InspectorUnit("pt"))]
+    pub font_size: f32,
+    /// Color the app starts with; edited in the Inspector
+    #[reflect(ignore)]
+    pub color: Color32,
+    /// Let the color be translucent
+    pub alpha: bool,
+}
+
+impl UiColorPicker {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            enabled: true,
+            font_size: DEFAULT_FONT_SIZE,
+            color: Color32::from_rgb(0, 120, 215),
+            alpha: false,
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use bevy_ecs::prelude::*;
+use egui::Color32;
+use super::ThemeDesign;
+
+/// Visuals a preview variant is rendered with
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PreviewTheme {
+    Dark,
+    Light,
+    Custom,
+    /// The project's `ThemeDesign`, including its spacing and text sizes
+    Project,
+}
+
+impl PreviewTheme {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PreviewTheme::Dark => "Dark",
+            PreviewTheme::Light => "Light",
+            PreviewTheme::Custom => "Custom",
+            PreviewTheme::Project => "Project",
+        }
+    }
+}
+
+/// One rendering of the current page shown in the Variant Preview
+#[derive(Clone, Debug, PartialEq)]
+pub struct PreviewVariant {
+    pub theme: PreviewTheme,
+    /// Simulated display scale; the variant is drawn at the physical size it would have on that display
+    pub pixels_per_point: f32,
+    /// Multiplier applied to every font size, like an accessibility text-size setting
+    pub font_scale: f32,
+}
+
+impl PreviewVariant {
+    pub fn title(&self) -> String {
+        format!("{} · {:.2} ppp · {:.0}% text", self.theme.name(), self.pixels_per_point, self.font_scale * 100.0)
+    }
+}
+
+/// Colors of the `Custom` preview theme
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomPreviewVisuals {
+    pub dark_base: bool,
+    pub panel_fill: Color32,
+    pub text_color: Color32,
+    pub widget_fill: Color32,
+    pub accent: Color32,
+}
+
+impl Default for CustomPreviewVisuals {
+    fn default() -> Self {
+        Self {
+            dark_base: true,
+            panel_fill: Color32::from_rgb(30, 34, 48),
+            text_color: Color32::from_rgb(230, 230, 240),
+            widget_fill: Color32::from_rgb(52, 58, 82),
+            accent: Color32::from_rgb(90, 140, 255),
+        }
+    }
+}
+
+impl CustomPreviewVisuals {
+    pub fn visuals(&self) -> egui::Visuals {
+        let mut visuals = if self.dark_base { egui::Visuals::dark() } else { egui::Visuals::light() };
+        visuals.panel_fill = self.panel_fill;
+        visuals.window_fill = self.panel_fill;
+        visuals.override_text_color = Some(self.text_color);
+        visuals.widgets.inactive.bg_fill = self.widget_fill;
+        visuals.widgets.inactive.weak_bg_fill = self.widget_fill;
+        visuals.selection.bg_fill = self.accent;
+        visuals.hyperlink_color = self.accent;
+        visuals
+    }
+}
+
+/// Variants rendered side by side in the Variant Preview tab
+#[derive(Component, Clone, Debug)]
+pub struct PreviewVariants {
+    pub variants: Vec<PreviewVariant>,
+    pub custom: CustomPreviewVisuals,
+}
+
+impl Default for PreviewVariants {
+    fn default() -> Self {
+        Self {
+            variants: vec![
+                PreviewVariant { theme: PreviewTheme::Dark, pixels_per_point: 1.0, font_scale: 1.0 },
+                PreviewVariant { theme: PreviewTheme::Light, pixels_per_point: 1.0, font_scale: 1.0 },
+                PreviewVariant { theme: PreviewTheme::Dark, pixels_per_point: 1.0, font_scale: 1.5 },
+            ],
+            custom: CustomPreviewVisuals::default(),
+        }
+    }
+}
+
+impl PreviewVariants {
+    /// Style a variant is drawn with; only `Project` changes more than the visuals of `base`
+    pub fn style_for(&self, theme: PreviewTheme, project_theme: &ThemeDesign, base: &egui::Style) -> egui::Style {
+        let visuals = match theme {
+            PreviewTheme::Dark => egui::Visuals::dark(),
+            PreviewTheme::Light => egui::Visuals::light(),
+            PreviewTheme::Custom => self.custom.visuals(),
+            PreviewTheme::Project => return project_theme.style(),
+        };
+        egui::Style { visuals, ..base.clone() }
+    }
+}
@@ -0,0 +1,170 @@
+use bevy_ecs::prelude::*;
+use crate::codegen::{is_rust_ident, pascal_ident, snake_ident};
+use crate::codegen_thread::{UiElementSnapshot, UiElementType};
+use super::ui::UiRadioButton;
+
+/// Radio buttons sharing a `group_id`. The generated code turns a group into an enum
+/// with one variant per member and an `App` field holding the selected variant.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RadioGroup {
+    pub id: String,
+    /// Members in their order within the group
+    pub members: Vec<RadioGroupMember>,
+    /// Generated enum, unique among the groups collected together
+    pub enum_name: String,
+    /// `App` field holding the selected variant, unique among the groups collected together
+    pub field: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RadioGroupMember {
+    pub entity: Entity,
+    pub label: String,
+    /// Variant name as entered, empty when derived from the label
+    pub variant: String,
+    /// Selected in the designer; the selected member is the enum's default
+    pub selected: bool,
+    pub order: usize,
+}
+
+impl RadioGroupMember {
+    pub fn new(entity: Entity, radio: &UiRadioButton) -> Self {
+        Self {
+            entity,
+            label: radio.label.clone(),
+            variant: radio.variant.clone(),
+            selected: radio.selected,
+            order: radio.order,
+        }
+    }
+
+    pub fn variant_name(&self) -> String {
+        radio_variant_name(&self.variant, &self.label)
+    }
+}
+
+impl RadioGroup {
+    /// Group radio buttons by `group_id`. Groups are sorted by id, members by their order.
+    /// Groups whose ids give the same enum or field name, like "size" and "Size", are numbered.
+    pub fn collect(buttons: impl IntoIterator<Item = (String, RadioGroupMember)>) -> Vec<RadioGroup> {
+        let mut groups: Vec<RadioGroup> = Vec::new();
+        for (id, member) in buttons {
+            match groups.iter_mut().find(|group| group.id == id) {
+                Some(group) => group.members.push(member),
+                None => groups.push(RadioGroup {
+                    enum_name: radio_group_enum(&id),
+                    field: radio_group_field(&id),
+                    id,
+                    members: vec![member],
+                }),
+            }
+        }
+        for group in &mut groups {
+            group.members.sort_by_key(|member| (member.order, member.entity));
+        }
+        groups.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut enums: Vec<String> = Vec::new();
+        let mut fields: Vec<String> = Vec::new();
+        for group in &mut groups {
+            group.enum_name = numbered(&group.enum_name, "", &enums);
+            group.field = numbered(&group.field, "_", &fields);
+            enums.push(group.enum_name.clone());
+            fields.push(group.field.clone());
+        }
+        groups
+    }
+
+    /// Index of the default member: the first selected one, or the first member
+    pub fn default_index(&self) -> usize {
+        self.members.iter().position(|member| member.selected).unwrap_or(0)
+    }
+
+    /// Variant identifiers of the members, made unique by numbering repeated names
+    pub fn variant_idents(&self) -> Vec<String> {
+        let mut idents: Vec<String> = Vec::new();
+        for member in &self.members {
+            let ident = numbered(&member.variant_name(), "", &idents);
+            idents.push(ident);
+        }
+        idents
+    }
+}
+
+/// Radio groups of all radio buttons in the world
+pub fn radio_groups(world: &mut World) -> Vec<RadioGroup> {
+    let mut query = world.query::<(Entity, &UiRadioButton)>();
+    RadioGroup::collect(
+        query
+            .iter(world)
+            .map(|(entity, radio)| (radio.group_id.clone(), RadioGroupMember::new(entity, radio))),
+    )
+}
+
+/// Types a radio group's enum must not shadow: those of the std prelude and the generated
+/// code's imports, and the types generated for the app and for other widgets
+const RESERVED_TYPES: &[&str] = &[
+    // std prelude
+    "Option", "Some", "None", "Result", "Ok", "Err", "String", "ToString", "Vec", "Box",
+    "Clone", "Copy", "Default", "Debug", "Hash", "PartialEq", "Eq", "PartialOrd", "Ord",
+    "Send", "Sync", "Sized", "Unpin", "Drop", "Fn", "FnMut", "FnOnce", "AsRef", "AsMut",
+    "From", "Into", "TryFrom", "TryInto", "ToOwned", "Iterator", "IntoIterator",
+    "DoubleEndedIterator", "ExactSizeIterator", "Extend", "FromIterator",
+    // imports of a docked app
+    "DockArea", "DockState", "NodeIndex", "Style", "TabViewer",
+    // generated types
+    "App", "AppTab", "AppTabViewer", "MainWindow", "MenuHandler", "TreeNode", "LedStatus", "CodeHighlighter",
+];
+
+/// Group id and group member of a snapshotted radio button
+pub fn snapshot_radio_member(element: &UiElementSnapshot) -> Option<(String, RadioGroupMember)> {
+    let UiElementType::RadioButton { label, selected, group_id, variant, order, .. } = &element.element_type else {
+        return None;
+    };
+    Some((group_id.clone(), RadioGroupMember {
+        entity: element.entity_id,
+        label: label.clone(),
+        variant: variant.clone(),
+        selected: *selected,
+        order: *order,
+    }))
+}
+
+/// Name of the enum generated for a radio group, before numbering against other groups
+pub fn radio_group_enum(group_id: &str) -> String {
+    let name = pascal_ident(group_id);
+    // Tables generate `<Name>Row` structs and components `<Name>State` structs
+    if RESERVED_TYPES.contains(&name.as_str()) || name.ends_with("Row") || name.ends_with("State") {
+        format!("{}Choice", name)
+    } else {
+        name
+    }
+}
+
+/// Name of the `App` field holding a radio group's selection, before numbering against other groups
+pub fn radio_group_field(group_id: &str) -> String {
+    format!("{}_selection", snake_ident(group_id))
+}
+
+/// `name`, or the first of `name2`, `name3`, ... (with `separator` before the number) not in `taken`
+fn numbered(name: &str, separator: &str, taken: &[String]) -> String {
+    let mut numbered = name.to_string();
+    let mut suffix = 2;
+    while taken.contains(&numbered) {
+        numbered = format!("{}{}{}", name, separator, suffix);
+        suffix += 1;
+    }
+    numbered
+}
+
+/// Variant identifier for an entered variant name, falling back to the label
+pub fn radio_variant_name(variant: &str, label: &str) -> String {
+    let variant = variant.trim();
+    if variant.is_empty() {
+        pascal_ident(label)
+    } else if is_rust_ident(variant) {
+        variant.to_string()
+    } else {
+        pascal_ident(variant)
+    }
+}
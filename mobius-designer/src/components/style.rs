@@ -0,0 +1,306 @@
+use bevy_ecs::prelude::*;
+use egui::{Color32, Vec2};
+use serde::{Deserialize, Serialize};
+
+/// Font size new elements are created with; sizes equal to it are not emitted by codegen
+pub const DEFAULT_FONT_SIZE: f32 = 14.0;
+
+/// Outline drawn around an element
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StrokeStyle {
+    pub width: f32,
+    pub color: Color32,
+}
+
+impl StrokeStyle {
+    pub fn stroke(&self) -> egui::Stroke {
+        egui::Stroke::new(self.width, self.color)
+    }
+}
+
+/// Visual style of an element. Every property is optional; unset properties
+/// come from the referenced named style, or keep egui's defaults for the widget.
+#[derive(Component, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct UiElementStyle {
+    /// Name of the `StyleSheet` style this element uses; the other properties override it
+    #[serde(default)]
+    pub class: Option<String>,
+    pub fill: Option<Color32>,
+    pub text_color: Option<Color32>,
+    pub stroke: Option<StrokeStyle>,
+    pub corner_radius: Option<f32>,
+    /// Inner padding; button padding for buttons, frame margin for other elements
+    pub padding: Option<Vec2>,
+    pub bold: Option<bool>,
+    pub italic: Option<bool>,
+    pub tooltip: Option<String>,
+}
+
+impl std::hash::Hash for UiElementStyle {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.class.hash(state);
+        self.fill.map(|color| color.to_array()).hash(state);
+        self.text_color.map(|color| color.to_array()).hash(state);
+        self.stroke.map(|stroke| (stroke.width.to_bits(), stroke.color.to_array())).hash(state);
+        self.corner_radius.map(f32::to_bits).hash(state);
+        self.padding.map(|padding| (padding.x.to_bits(), padding.y.to_bits())).hash(state);
+        self.bold.hash(state);
+        self.italic.hash(state);
+        self.tooltip.hash(state);
+    }
+}
+
+impl UiElementStyle {
+    /// Whether any visual property is set, ignoring `class`
+    pub fn has_properties(&self) -> bool {
+        self.fill.is_some()
+            || self.text_color.is_some()
+            || self.stroke.is_some()
+            || self.corner_radius.is_some()
+            || self.padding.is_some()
+            || self.bold.is_some()
+            || self.italic.is_some()
+            || self.tooltip.is_some()
+    }
+
+    /// This style's properties with the unset ones taken from `base`
+    pub fn over(&self, base: &UiElementStyle) -> UiElementStyle {
+        UiElementStyle {
+            class: self.class.clone(),
+            fill: self.fill.or(base.fill),
+            text_color: self.text_color.or(base.text_color),
+            stroke: self.stroke.or(base.stroke),
+            corner_radius: self.corner_radius.or(base.corner_radius),
+            padding: self.padding.or(base.padding),
+            bold: self.bold.or(base.bold),
+            italic: self.italic.or(base.italic),
+            tooltip: self.tooltip.clone().or_else(|| base.tooltip.clone()),
+        }
+    }
+
+    pub fn is_bold(&self) -> bool {
+        self.bold.unwrap_or(false)
+    }
+
+    pub fn is_italic(&self) -> bool {
+        self.italic.unwrap_or(false)
+    }
+
+    /// Tooltip text, `None` when unset or empty
+    pub fn tooltip_text(&self) -> Option<&str> {
+        self.tooltip.as_deref().filter(|text| !text.is_empty())
+    }
+
+    /// Whether non-button elements need a surrounding `Frame`
+    pub fn has_frame(&self) -> bool {
+        self.fill.is_some() || self.stroke.is_some() || self.corner_radius.is_some() || self.padding.is_some()
+    }
+
+    /// Label text with the size, color and emphasis of this style
+    pub fn rich_text(&self, text: &str, font_size: f32) -> egui::RichText {
+        let mut rich_text = egui::RichText::new(text);
+        if font_size > 0.0 {
+            rich_text = rich_text.size(font_size);
+        }
+        if let Some(color) = self.text_color {
+            rich_text = rich_text.color(color);
+        }
+        if self.is_bold() {
+            rich_text = rich_text.strong();
+        }
+        if self.is_italic() {
+            rich_text = rich_text.italics();
+        }
+        rich_text
+    }
+
+    /// Frame drawn around non-button elements
+    pub fn frame(&self) -> egui::Frame {
+        self.apply_to_frame(egui::Frame::new())
+    }
+
+    /// Group box frame with this style's overrides
+    pub fn group_frame(&self, style: &egui::Style) -> egui::Frame {
+        self.apply_to_frame(egui::Frame::group(style))
+    }
+
+    fn apply_to_frame(&self, mut frame: egui::Frame) -> egui::Frame {
+        if let Some(fill) = self.fill {
+            frame = frame.fill(fill);
+        }
+        if let Some(stroke) = self.stroke {
+            frame = frame.stroke(stroke.stroke());
+        }
+        if let Some(radius) = self.corner_radius {
+            frame = frame.corner_radius(radius);
+        }
+        if let Some(padding) = self.padding {
+            frame = frame.inner_margin(egui::Margin::symmetric(padding.x as i8, padding.y as i8));
+        }
+        frame
+    }
+
+    /// Apply fill, stroke and corner radius to a button
+    pub fn style_button<'a>(&self, mut button: egui::Button<'a>) -> egui::Button<'a> {
+        if let Some(fill) = self.fill {
+            button = button.fill(fill);
+        }
+        if let Some(stroke) = self.stroke {
+            button = button.stroke(stroke.stroke());
+        }
+        if let Some(radius) = self.corner_radius {
+            button = button.corner_radius(radius);
+        }
+        button
+    }
+
+    /// Show `add_contents` inside this style's frame when it has one
+    pub fn show_framed<R>(&self, ui: &mut egui::Ui, add_contents: impl FnOnce(&mut egui::Ui) -> R) -> egui::InnerResponse<R> {
+        if self.has_frame() {
+            self.frame().show(ui, add_contents)
+        } else {
+            ui.scope(add_contents)
+        }
+    }
+}
+
+/// A reusable style elements refer to by name
+#[derive(Clone, Debug, PartialEq, Hash, Serialize, Deserialize)]
+pub struct NamedStyle {
+    pub name: String,
+    /// Style this one inherits unset properties from
+    pub parent: Option<String>,
+    /// Properties set by this style; `class` is unused here
+    pub style: UiElementStyle,
+}
+
+impl NamedStyle {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into(), parent: None, style: UiElementStyle::default() }
+    }
+}
+
+/// Project-level named styles shared across elements
+#[derive(Component, Clone, Debug, PartialEq, Hash, Serialize, Deserialize)]
+pub struct StyleSheet {
+    pub styles: Vec<NamedStyle>,
+}
+
+impl Default for StyleSheet {
+    fn default() -> Self {
+        let mut primary = NamedStyle::new("primary-button");
+        primary.style.fill = Some(Color32::from_rgb(40, 100, 200));
+        primary.style.text_color = Some(Color32::WHITE);
+        primary.style.corner_radius = Some(4.0);
+        let mut danger = NamedStyle::new("danger");
+        danger.parent = Some("primary-button".to_string());
+        danger.style.fill = Some(Color32::from_rgb(190, 40, 40));
+        Self { styles: vec![primary, danger] }
+    }
+}
+
+impl StyleSheet {
+    pub fn get(&self, name: &str) -> Option<&NamedStyle> {
+        self.styles.iter().find(|style| style.name == name)
+    }
+
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut NamedStyle> {
+        self.styles.iter_mut().find(|style| style.name == name)
+    }
+
+    /// Whether following parents from `start` reaches `target`
+    fn reaches(&self, start: &str, target: &str) -> bool {
+        let mut visited = Vec::new();
+        let mut next = self.get(start);
+        while let Some(style) = next {
+            if style.name == target {
+                return true;
+            }
+            if visited.contains(&style.name.as_str()) {
+                return false;
+            }
+            visited.push(style.name.as_str());
+            next = style.parent.as_deref().and_then(|parent| self.get(parent));
+        }
+        false
+    }
+
+    /// The parent `name` inherits from, `None` when it is missing or part of an inheritance cycle
+    pub fn effective_parent(&self, name: &str) -> Option<&NamedStyle> {
+        let parent = self.get(self.get(name)?.parent.as_deref()?)?;
+        (!self.reaches(&parent.name, name)).then_some(parent)
+    }
+
+    /// Whether the parent of `name` is ignored because it leads back to `name`
+    pub fn has_cycle(&self, name: &str) -> bool {
+        self.get(name)
+            .and_then(|style| style.parent.as_deref())
+            .is_some_and(|parent| self.reaches(parent, name))
+    }
+
+    /// Whether making `parent` the parent of `name` would create an inheritance cycle
+    pub fn would_cycle(&self, name: &str, parent: &str) -> bool {
+        self.reaches(parent, name)
+    }
+
+    /// `name` followed by the styles it inherits from
+    pub fn ancestry(&self, name: &str) -> Vec<&NamedStyle> {
+        let mut chain: Vec<&NamedStyle> = self.get(name).into_iter().collect();
+        while let Some(parent) = chain.last().and_then(|style| self.effective_parent(&style.name)) {
+            chain.push(parent);
+        }
+        chain
+    }
+
+    /// All properties of the named style, including inherited ones
+    pub fn resolve_named(&self, name: &str) -> UiElementStyle {
+        self.ancestry(name)
+            .iter()
+            .rev()
+            .fold(UiElementStyle::default(), |resolved, style| style.style.over(&resolved))
+    }
+
+    /// The style an element is drawn with: its overrides on top of its named style
+    pub fn resolve(&self, style: Option<&UiElementStyle>) -> UiElementStyle {
+        match style {
+            Some(style) => match style.class.as_deref() {
+                Some(class) => style.over(&self.resolve_named(class)),
+                None => style.clone(),
+            },
+            None => UiElementStyle::default(),
+        }
+    }
+
+    /// A name not used by any style yet, based on `base`
+    pub fn unique_name(&self, base: &str) -> String {
+        if self.get(base).is_none() {
+            return base.to_string();
+        }
+        (2..).map(|n| format!("{}-{}", base, n)).find(|name| self.get(name).is_none()).unwrap()
+    }
+
+    /// Rename a style and every parent reference to it
+    pub fn rename(&mut self, old: &str, new: &str) {
+        for style in &mut self.styles {
+            if style.name == old {
+                style.name = new.to_string();
+            }
+            if style.parent.as_deref() == Some(old) {
+                style.parent = Some(new.to_string());
+            }
+        }
+    }
+
+    /// Remove a style; styles inheriting from it inherit from its parent instead
+    pub fn remove(&mut self, name: &str) {
+        let Some(index) = self.styles.iter().position(|style| style.name == name) else {
+            return;
+        };
+        let removed = self.styles.remove(index);
+        for style in &mut self.styles {
+            if style.parent.as_deref() == Some(name) {
+                style.parent = removed.parent.clone();
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use bevy_ecs::prelude::*;
+use bevy_ecs::reflect::ReflectComponent;
+use bevy_reflect::Reflect;
+use serde::{Deserialize, Serialize};
+use crate::codegen::{pascal_ident, snake_ident};
+use super::inspector::*;
+
+/// Text an instance shows instead of the master part's label
+#[derive(Clone, Debug, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct SymbolLabelOverride {
+    /// Element inside the master
+    #[serde(with = "entity_bits")]
+    pub part: Entity,
+    pub label: String,
+}
+
+/// Placement of a reusable component. The master holds the component's elements; every
+/// other instance of the same component shows copies of them, kept in sync with the master.
+#[derive(Component, Clone, Debug, Reflect, Serialize, Deserialize)]
+#[reflect(Component, @InspectorSection::new("◈ Component Properties", 0))]
+pub struct UiSymbolInstance {
+    /// Name of the instance; the `App` field holding its state is named after it
+    pub name: String,
+    /// Name of the component definition
+    #[reflect(@InspectorHidden)]
+    pub symbol: String,
+    /// Whether this instance holds the elements the component is defined by
+    #[reflect(@InspectorHidden)]
+    pub master: bool,
+    #[reflect(@InspectorHidden)]
+    pub overrides: Vec<SymbolLabelOverride>,
+}
+
+impl UiSymbolInstance {
+    pub fn new(name: &str, symbol: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            symbol: symbol.to_string(),
+            master: false,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Label override of a master part, if this instance has one
+    pub fn label_override(&self, part: Entity) -> Option<&str> {
+        self.overrides.iter().find(|o| o.part == part).map(|o| o.label.as_str())
+    }
+
+    /// Name of the generated function drawing the component
+    pub fn function(&self) -> String {
+        format!("{}_component", snake_ident(&self.symbol))
+    }
+
+    /// Name of the generated struct holding the state of one instance
+    pub fn state_type(&self) -> String {
+        format!("{}State", pascal_ident(&self.symbol))
+    }
+
+    /// Name of the `App` field holding the instance's state
+    pub fn state_field(&self) -> String {
+        snake_ident(&self.name)
+    }
+}
+
+/// Element an instance shows in place of a master part; rewritten from the part every frame
+#[derive(Component, Clone, Debug)]
+pub struct UiSymbolCopy {
+    pub instance: Entity,
+    pub part: Entity,
+}
+
+/// Entities are written as their bits so that element snapshots can carry overrides
+mod entity_bits {
+    use bevy_ecs::entity::Entity;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(entity: &Entity, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(entity.to_bits())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Entity, D::Error> {
+        Entity::try_from_bits(u64::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
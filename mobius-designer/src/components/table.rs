@@ -0,0 +1,206 @@
+use bevy_ecs::prelude::*;
+use bevy_ecs::reflect::ReflectComponent;
+use bevy_reflect::Reflect;
+use serde::{Deserialize, Serialize};
+use crate::codegen::{pascal_ident, snake_ident};
+use super::inspector::*;
+
+/// Width policy of a table column, as in `egui_extras::Column`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum ColumnWidth {
+    /// Fits the widest cell
+    #[default]
+    Auto,
+    /// Starts at the column's width
+    Initial,
+    /// Always the column's width
+    Exact,
+    /// Takes the width left over by the other columns
+    Remainder,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum ColumnAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// Type of a column's field in the generated row struct
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum ColumnType {
+    #[default]
+    Text,
+    Integer,
+    Float,
+    Bool,
+}
+
+impl ColumnType {
+    pub fn rust_type(&self) -> &'static str {
+        match self {
+            ColumnType::Text => "String",
+            ColumnType::Integer => "i64",
+            ColumnType::Float => "f64",
+            ColumnType::Bool => "bool",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct TableColumn {
+    pub header: String,
+    pub value_type: ColumnType,
+    pub width_policy: ColumnWidth,
+    /// Width of `Initial` and `Exact` columns
+    pub width: f32,
+    pub align: ColumnAlign,
+    pub resizable: bool,
+    /// Clicking the header sorts the rows by this column
+    pub sortable: bool,
+}
+
+impl TableColumn {
+    pub fn new(header: &str, value_type: ColumnType) -> Self {
+        Self {
+            header: header.to_string(),
+            value_type,
+            width_policy: ColumnWidth::Auto,
+            width: 100.0,
+            align: ColumnAlign::Left,
+            resizable: true,
+            sortable: false,
+        }
+    }
+
+    /// Placeholder value of the column in sample row `row`
+    pub fn sample_value(&self, row: usize) -> CellValue {
+        let number = row + 1;
+        match self.value_type {
+            ColumnType::Text if self.header.trim().is_empty() => CellValue::Text(format!("Item {}", number)),
+            ColumnType::Text => CellValue::Text(format!("{} {}", self.header.trim(), number)),
+            ColumnType::Integer => CellValue::Integer(number as i64),
+            ColumnType::Float => CellValue::Float(number as f64 * 1.5),
+            ColumnType::Bool => CellValue::Bool(row.is_multiple_of(2)),
+        }
+    }
+}
+
+/// Value of one table cell
+#[derive(Clone, Debug, PartialEq)]
+pub enum CellValue {
+    Text(String),
+    Integer(i64),
+    Float(f64),
+    Bool(bool),
+}
+
+impl CellValue {
+    /// Text shown in the cell, the same as the generated code shows
+    pub fn display(&self) -> String {
+        match self {
+            CellValue::Text(text) => text.clone(),
+            CellValue::Integer(value) => value.to_string(),
+            CellValue::Float(value) => value.to_string(),
+            CellValue::Bool(value) => if *value { "✔" } else { "" }.to_string(),
+        }
+    }
+
+    /// Rust expression of the value
+    pub fn code(&self) -> String {
+        match self {
+            CellValue::Text(text) => format!("\"{}\".to_string()", text.escape_default()),
+            CellValue::Integer(value) => value.to_string(),
+            CellValue::Float(value) => format!("{:?}", value),
+            CellValue::Bool(value) => value.to_string(),
+        }
+    }
+
+    pub fn compare(&self, other: &CellValue) -> std::cmp::Ordering {
+        match (self, other) {
+            (CellValue::Text(a), CellValue::Text(b)) => a.cmp(b),
+            (CellValue::Integer(a), CellValue::Integer(b)) => a.cmp(b),
+            (CellValue::Float(a), CellValue::Float(b)) => a.total_cmp(b),
+            (CellValue::Bool(a), CellValue::Bool(b)) => a.cmp(b),
+            _ => std::cmp::Ordering::Equal,
+        }
+    }
+}
+
+/// Table of rows with typed columns. The generated code gets a row struct and a `Vec` of rows in the `App`.
+#[derive(Component, Clone, Debug, Reflect, Serialize, Deserialize)]
+#[reflect(Component, @InspectorSection::new("▦ Table Properties", 0))]
+pub struct UiTable {
+    /// Name of the table; the row struct and `App` fields are named after it
+    pub name: String,
+    #[reflect(@InspectorHidden)]
+    pub columns: Vec<TableColumn>,
+    pub striped: bool,
+    /// Rows of placeholder data shown on the canvas and generated as initial rows
+    #[reflect(@InspectorRange::new(0.0, 100.0))]
+    pub sample_rows: usize,
+    #[reflect(@InspectorRange::new(12.0, 60.0), @InspectorUnit("px"))]
+    pub row_height: f32,
+}
+
+impl UiTable {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            columns: vec![
+                TableColumn::new("Name", ColumnType::Text),
+                TableColumn { width_policy: ColumnWidth::Remainder, align: ColumnAlign::Right, ..TableColumn::new("Value", ColumnType::Integer) },
+            ],
+            striped: true,
+            sample_rows: 5,
+            row_height: 20.0,
+        }
+    }
+
+    /// Name of the generated row struct
+    pub fn row_struct(&self) -> String {
+        format!("{}Row", pascal_ident(&self.name))
+    }
+
+    /// Name of the `App` field holding the rows
+    pub fn rows_field(&self) -> String {
+        format!("{}_rows", snake_ident(&self.name))
+    }
+
+    /// Name of the `App` field holding the sort column and direction
+    pub fn sort_field(&self) -> String {
+        format!("{}_sort", snake_ident(&self.name))
+    }
+
+    pub fn is_sortable(&self) -> bool {
+        self.columns.iter().any(|column| column.sortable)
+    }
+
+    /// Field name of each column in the row struct, unique within the table
+    pub fn column_fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = Vec::new();
+        for (index, column) in self.columns.iter().enumerate() {
+            let mut field = if column.header.trim().is_empty() {
+                format!("column_{}", index + 1)
+            } else {
+                snake_ident(&column.header)
+            };
+            let base = field.clone();
+            let mut suffix = 2;
+            while fields.contains(&field) {
+                field = format!("{}_{}", base, suffix);
+                suffix += 1;
+            }
+            fields.push(field);
+        }
+        fields
+    }
+
+    /// Placeholder rows, one value per column
+    pub fn sample_data(&self) -> Vec<Vec<CellValue>> {
+        (0..self.sample_rows)
+            .map(|row| self.columns.iter().map(|column| column.sample_value(row)).collect())
+            .collect()
+    }
+}
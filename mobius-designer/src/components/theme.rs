@@ -0,0 +1,188 @@
+use bevy_ecs::prelude::*;
+use egui::{Color32, Vec2};
+use serde::{Deserialize, Serialize};
+
+/// Font sizes of the built-in text styles
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ThemeTextSizes {
+    pub small: f32,
+    pub body: f32,
+    pub button: f32,
+    pub heading: f32,
+    pub monospace: f32,
+}
+
+/// Global look of the generated application: palette, spacing, rounding and text styles
+#[derive(Component, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ThemeDesign {
+    /// Start from egui's dark visuals instead of the light ones
+    pub dark_base: bool,
+    pub panel_fill: Color32,
+    pub window_fill: Color32,
+    /// Background of text edits and other sunken areas
+    pub extreme_bg_color: Color32,
+    /// Background of striped rows
+    pub faint_bg_color: Color32,
+    /// Color of all text; `None` keeps the per-widget colors of the base visuals
+    pub text_color: Option<Color32>,
+    pub widget_fill: Color32,
+    pub hovered_fill: Color32,
+    pub active_fill: Color32,
+    pub selection: Color32,
+    pub hyperlink: Color32,
+    pub warn: Color32,
+    pub error: Color32,
+    pub item_spacing: Vec2,
+    pub button_padding: Vec2,
+    pub window_margin: f32,
+    pub indent: f32,
+    pub widget_corner_radius: f32,
+    pub window_corner_radius: f32,
+    pub text_sizes: ThemeTextSizes,
+}
+
+impl Default for ThemeDesign {
+    fn default() -> Self {
+        Self::from_visuals(egui::Visuals::dark())
+    }
+}
+
+impl std::hash::Hash for ThemeDesign {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.dark_base.hash(state);
+        for color in [
+            self.panel_fill,
+            self.window_fill,
+            self.extreme_bg_color,
+            self.faint_bg_color,
+            self.widget_fill,
+            self.hovered_fill,
+            self.active_fill,
+            self.selection,
+            self.hyperlink,
+            self.warn,
+            self.error,
+        ] {
+            color.to_array().hash(state);
+        }
+        self.text_color.map(|color| color.to_array()).hash(state);
+        for value in [
+            self.item_spacing.x,
+            self.item_spacing.y,
+            self.button_padding.x,
+            self.button_padding.y,
+            self.window_margin,
+            self.indent,
+            self.widget_corner_radius,
+            self.window_corner_radius,
+            self.text_sizes.small,
+            self.text_sizes.body,
+            self.text_sizes.button,
+            self.text_sizes.heading,
+            self.text_sizes.monospace,
+        ] {
+            value.to_bits().hash(state);
+        }
+    }
+}
+
+impl ThemeDesign {
+    /// A theme matching egui's default style with the given visuals
+    pub fn from_visuals(visuals: egui::Visuals) -> Self {
+        let style = egui::Style { visuals, ..Default::default() };
+        let size = |text_style: egui::TextStyle| style.text_styles.get(&text_style).map(|font| font.size).unwrap_or(14.0);
+        let visuals = &style.visuals;
+        Self {
+            dark_base: visuals.dark_mode,
+            panel_fill: visuals.panel_fill,
+            window_fill: visuals.window_fill,
+            extreme_bg_color: visuals.extreme_bg_color,
+            faint_bg_color: visuals.faint_bg_color,
+            text_color: visuals.override_text_color,
+            widget_fill: visuals.widgets.inactive.weak_bg_fill,
+            hovered_fill: visuals.widgets.hovered.weak_bg_fill,
+            active_fill: visuals.widgets.active.weak_bg_fill,
+            selection: visuals.selection.bg_fill,
+            hyperlink: visuals.hyperlink_color,
+            warn: visuals.warn_fg_color,
+            error: visuals.error_fg_color,
+            item_spacing: style.spacing.item_spacing,
+            button_padding: style.spacing.button_padding,
+            window_margin: style.spacing.window_margin.left as f32,
+            indent: style.spacing.indent,
+            widget_corner_radius: visuals.widgets.inactive.corner_radius.nw as f32,
+            window_corner_radius: visuals.window_corner_radius.nw as f32,
+            text_sizes: ThemeTextSizes {
+                small: size(egui::TextStyle::Small),
+                body: size(egui::TextStyle::Body),
+                button: size(egui::TextStyle::Button),
+                heading: size(egui::TextStyle::Heading),
+                monospace: size(egui::TextStyle::Monospace),
+            },
+        }
+    }
+
+    /// The egui style this theme produces
+    pub fn style(&self) -> egui::Style {
+        let mut style = egui::Style::default();
+        self.apply(&mut style);
+        style
+    }
+
+    /// Overwrite `style` with this theme, the same way the generated `apply_theme` does
+    pub fn apply(&self, style: &mut egui::Style) {
+        style.visuals = if self.dark_base { egui::Visuals::dark() } else { egui::Visuals::light() };
+        let visuals = &mut style.visuals;
+        visuals.panel_fill = self.panel_fill;
+        visuals.window_fill = self.window_fill;
+        visuals.extreme_bg_color = self.extreme_bg_color;
+        visuals.faint_bg_color = self.faint_bg_color;
+        visuals.override_text_color = self.text_color;
+        visuals.widgets.inactive.bg_fill = self.widget_fill;
+        visuals.widgets.inactive.weak_bg_fill = self.widget_fill;
+        visuals.widgets.hovered.bg_fill = self.hovered_fill;
+        visuals.widgets.hovered.weak_bg_fill = self.hovered_fill;
+        visuals.widgets.active.bg_fill = self.active_fill;
+        visuals.widgets.active.weak_bg_fill = self.active_fill;
+        visuals.selection.bg_fill = self.selection;
+        visuals.hyperlink_color = self.hyperlink;
+        visuals.warn_fg_color = self.warn;
+        visuals.error_fg_color = self.error;
+        let corner_radius = egui::CornerRadius::same(self.widget_corner_radius as u8);
+        for widget in [
+            &mut visuals.widgets.noninteractive,
+            &mut visuals.widgets.inactive,
+            &mut visuals.widgets.hovered,
+            &mut visuals.widgets.active,
+            &mut visuals.widgets.open,
+        ] {
+            widget.corner_radius = corner_radius;
+        }
+        visuals.window_corner_radius = egui::CornerRadius::same(self.window_corner_radius as u8);
+        visuals.menu_corner_radius = corner_radius;
+
+        style.spacing.item_spacing = self.item_spacing;
+        style.spacing.button_padding = self.button_padding;
+        style.spacing.window_margin = egui::Margin::same(self.window_margin as i8);
+        style.spacing.indent = self.indent;
+
+        for (text_style, size) in self.text_style_sizes() {
+            let family = if text_style == egui::TextStyle::Monospace {
+                egui::FontFamily::Monospace
+            } else {
+                egui::FontFamily::Proportional
+            };
+            style.text_styles.insert(text_style, egui::FontId::new(size, family));
+        }
+    }
+
+    pub fn text_style_sizes(&self) -> [(egui::TextStyle, f32); 5] {
+        [
+            (egui::TextStyle::Small, self.text_sizes.small),
+            (egui::TextStyle::Body, self.text_sizes.body),
+            (egui::TextStyle::Button, self.text_sizes.button),
+            (egui::TextStyle::Heading, self.text_sizes.heading),
+            (egui::TextStyle::Monospace, self.text_sizes.monospace),
+        ]
+    }
+}
@@ -0,0 +1,86 @@
+use bevy_ecs::prelude::*;
+use bevy_ecs::reflect::ReflectComponent;
+use bevy_reflect::Reflect;
+use serde::{Deserialize, Serialize};
+use crate::codegen::snake_ident;
+use super::inspector::*;
+use super::style::DEFAULT_FONT_SIZE;
+
+/// Node of a tree view's sample hierarchy. Nodes are kept flat in display order;
+/// a node's children follow it one level deeper.
+#[derive(Clone, Debug, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct TreeSampleNode {
+    pub label: String,
+    /// Nesting level, 0 for the roots
+    pub depth: usize,
+    /// Whether the node starts expanded; also its state on the design canvas
+    pub expanded: bool,
+}
+
+impl TreeSampleNode {
+    pub fn new(label: &str, depth: usize) -> Self {
+        Self { label: label.to_string(), depth, expanded: true }
+    }
+}
+
+/// Hierarchy of nodes drawn as nested collapsing headers. The generated `App` keeps the
+/// nodes as `TreeNode`s and the path of the selected node.
+#[derive(Component, Clone, Debug, Reflect, Serialize, Deserialize)]
+#[reflect(Component, @InspectorSection::new("🌳 Tree View Properties", 0))]
+pub struct UiTreeView {
+    /// Name of the tree; the `App` fields are named after it
+    pub name: String,
+    pub enabled: bool,
+    #[reflect(@InspectorRange::new(8.0, 48.0), @InspectorUnit("pt"))]
+    pub font_size: f32,
+    /// Sample hierarchy the app starts with; edited in the Inspector
+    #[reflect(@InspectorHidden)]
+    pub nodes: Vec<TreeSampleNode>,
+}
+
+impl UiTreeView {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            enabled: true,
+            font_size: DEFAULT_FONT_SIZE,
+            nodes: vec![
+                TreeSampleNode::new("Project", 0),
+                TreeSampleNode::new("src", 1),
+                TreeSampleNode::new("main.rs", 2),
+                TreeSampleNode::new("lib.rs", 2),
+                TreeSampleNode::new("Cargo.toml", 1),
+                TreeSampleNode::new("README.md", 1),
+            ],
+        }
+    }
+
+    /// Index after the last descendant of the node at `index`
+    pub fn subtree_end(&self, index: usize) -> usize {
+        let depth = self.nodes[index].depth;
+        self.nodes[index + 1..].iter()
+            .position(|node| node.depth <= depth)
+            .map_or(self.nodes.len(), |offset| index + 1 + offset)
+    }
+
+    /// Indices of the direct children of the nodes in `start..end`
+    pub fn level(&self, start: usize, end: usize) -> Vec<usize> {
+        let mut indices = Vec::new();
+        let mut index = start;
+        while index < end {
+            indices.push(index);
+            index = self.subtree_end(index);
+        }
+        indices
+    }
+
+    /// Name of the `App` field holding the nodes
+    pub fn nodes_field(&self) -> String {
+        format!("{}_nodes", snake_ident(&self.name))
+    }
+
+    /// Name of the `App` field holding the path of the selected node
+    pub fn selected_field(&self) -> String {
+        format!("{}_selected", snake_ident(&self.name))
+    }
+}
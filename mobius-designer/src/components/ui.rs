@@ -1,43 +1,147 @@
 use bevy_ecs::prelude::*;
+use bevy_ecs::reflect::ReflectComponent;
+use bevy_reflect::Reflect;
+use super::inspector::*;
+use super::radio_group::radio_variant_name;
 
-#[derive(Component, Clone)]
+#[derive(Component, Clone, Reflect)]
+#[reflect(Component, @InspectorSection::new("🔘 Button Properties", 0))]
 pub struct UiButton {
     pub label: String,
+    #[reflect(@InspectorHidden)]
     pub clicked: bool,
     pub enabled: bool,
+    #[reflect(ignore)]
     pub click_time: Option<std::time::Instant>,
+    #[reflect(@InspectorRange::new(8.0, 48.0), @InspectorUnit("pt"))]
     pub font_size: f32,
 }
 
-#[derive(Component, Clone)]
+#[derive(Component, Clone, Reflect)]
+#[reflect(Component, @InspectorSection::new("📝 Text Input Properties", 0))]
 pub struct UiTextInput {
     pub label: String,
     pub value: String,
     pub enabled: bool,
+    #[reflect(@InspectorRange::new(8.0, 48.0), @InspectorUnit("pt"))]
     pub font_size: f32,
+    pub multiline: bool,
+    /// Visible rows of a multiline input
+    #[reflect(@InspectorRange::new(1.0, 50.0))]
+    pub rows: usize,
+    /// Mask the text like a password field
+    pub password: bool,
+    /// Placeholder shown while the input is empty
+    pub hint: String,
+    /// Maximum number of characters, 0 for no limit
+    #[reflect(@InspectorRange::new(0.0, 10000.0))]
+    pub max_length: usize,
+    pub validation: TextValidation,
+    /// Regular expression the whole value must match for `TextValidation::Regex`
+    pub pattern: String,
 }
 
-#[derive(Component, Clone)]
+impl UiTextInput {
+    /// Text input with the default mode: single line, no hint, limit or validation
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            value: String::new(),
+            enabled: true,
+            font_size: 14.0,
+            multiline: false,
+            rows: 4,
+            password: false,
+            hint: String::new(),
+            max_length: 0,
+            validation: TextValidation::None,
+            pattern: String::new(),
+        }
+    }
+
+    /// Why `value` fails the input's validation rule, `None` when it is valid
+    pub fn validation_error(&self, value: &str) -> Option<String> {
+        self.validation.check(value, &self.pattern).err()
+    }
+}
+
+/// Rule a text input's value must satisfy
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum TextValidation {
+    #[default]
+    None,
+    /// Not empty or whitespace only
+    Required,
+    /// Parses as a whole number
+    Integer,
+    /// Parses as a decimal number
+    Float,
+    /// Matches the input's `pattern`
+    Regex,
+}
+
+impl TextValidation {
+    pub fn check(&self, value: &str, pattern: &str) -> Result<(), String> {
+        match self {
+            TextValidation::None => Ok(()),
+            TextValidation::Required if value.trim().is_empty() => Err("Required".to_string()),
+            TextValidation::Integer if value.trim().parse::<i64>().is_err() => Err("Must be a whole number".to_string()),
+            TextValidation::Float if value.trim().parse::<f64>().is_err() => Err("Must be a number".to_string()),
+            TextValidation::Regex => match anchored_regex(pattern) {
+                Ok(regex) if regex.is_match(value) => Ok(()),
+                Ok(_) => Err(format!("Must match {}", pattern)),
+                Err(error) => Err(format!("Invalid pattern: {}", error)),
+            },
+            _ => Ok(()),
+        }
+    }
+}
+
+/// `pattern` matching the whole value rather than a part of it
+pub fn anchored_regex(pattern: &str) -> Result<regex::Regex, regex::Error> {
+    regex::Regex::new(&format!("^(?:{})$", pattern))
+}
+
+#[derive(Component, Clone, Reflect)]
+#[reflect(Component, @InspectorSection::new("☑️ Checkbox Properties", 0))]
 pub struct UiCheckbox {
     pub label: String,
     pub checked: bool,
     pub enabled: bool,
+    #[reflect(@InspectorRange::new(8.0, 48.0), @InspectorUnit("pt"))]
     pub font_size: f32,
 }
 
-#[derive(Component, Clone)]
+#[derive(Component, Clone, Reflect)]
+#[reflect(Component, @InspectorSection::new("🔘 Radio Button Properties", 0))]
 pub struct UiRadioButton {
     pub label: String,
     pub selected: bool,
     pub enabled: bool,
+    #[reflect(@InspectorRange::new(8.0, 48.0), @InspectorUnit("pt"))]
     pub font_size: f32,
     pub group_id: String,
+    /// Name of the button's variant in the generated group enum; empty to derive it from the label
+    pub variant: String,
+    /// Position of the button within its radio group
+    #[reflect(@InspectorHidden)]
+    pub order: usize,
+}
+
+impl UiRadioButton {
+    /// Identifier of the button's enum variant
+    pub fn variant_name(&self) -> String {
+        radio_variant_name(&self.variant, &self.label)
+    }
 }
 
-#[derive(Component, Clone)]
+#[derive(Component, Clone, Reflect)]
+#[reflect(Component, @InspectorSection::new("📦 Group Box Properties", 0))]
 pub struct UiGroupBox {
     pub label: String,
     pub enabled: bool,
+    #[reflect(@InspectorRange::new(8.0, 48.0), @InspectorUnit("pt"))]
     pub font_size: f32,
+    #[reflect(@InspectorHidden)]
     pub contained_widgets: Vec<Entity>,
 }
\ No newline at end of file
@@ -17,6 +17,13 @@ pub enum TabKind {
     Controls,
     Inspector,
     Preview,
+    DockLayout,
+    MenuBar,
+    VariantPreview,
+    Styles,
+    Theme,
+    RadioGroups,
+    Symbols,
 }
 
 pub struct MobiusTabViewer {
@@ -8,6 +8,8 @@ pub mod codegen;
 pub mod syntax_highlighting;
 pub mod events;
 pub mod codegen_thread;
+pub mod widgets;
+pub mod templates;
 
 // Re-export commonly used items
 pub use components::*;
@@ -15,8 +17,9 @@ pub use systems::*;
 pub use resources::*;
 pub use bundles::*;
 pub use integration::*;
+pub use widgets::*;
 // Don't re-export utils::* to avoid ambiguous glob reexport with grid module
-pub use utils::{get_grid_status, clear_all_selections, reset_button_clicks, add_designer_log, snap_to_grid, clear_all_ui_elements};
+pub use utils::{get_grid_status, active_page_id, is_on_page, clear_all_selections, reset_button_clicks, add_designer_log, snap_to_grid, clear_all_ui_elements};
 
 // Re-export external dependencies for convenience
 pub use bevy_ecs;
@@ -40,6 +43,13 @@ fn create_default_dock_layout() -> egui_dock::DockState<Tab> {
         Tab { name: "Settings".to_string(), kind: TabKind::Settings, id: 3 },
         Tab { name: "Event Logger".to_string(), kind: TabKind::EventLogger, id: 4 },
         Tab { name: "Preview".to_string(), kind: TabKind::Preview, id: 5 },
+        Tab { name: "Dock Layout".to_string(), kind: TabKind::DockLayout, id: 6 },
+        Tab { name: "Menu Bar".to_string(), kind: TabKind::MenuBar, id: 7 },
+        Tab { name: "Variant Preview".to_string(), kind: TabKind::VariantPreview, id: 8 },
+        Tab { name: "Styles".to_string(), kind: TabKind::Styles, id: 9 },
+        Tab { name: "Theme".to_string(), kind: TabKind::Theme, id: 10 },
+        Tab { name: "Radio Groups".to_string(), kind: TabKind::RadioGroups, id: 11 },
+        Tab { name: "Components".to_string(), kind: TabKind::Symbols, id: 12 },
     ]);
     
     dock_state
@@ -76,6 +86,42 @@ fn load_dock_layout() -> egui_dock::DockState<Tab> {
     create_default_dock_layout()
 }
 
+/// Get the path for storing the project theme
+fn get_theme_path() -> PathBuf {
+    let mut path = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    path.push("mobius_designer_theme.json");
+    path
+}
+
+/// Save the project theme to file
+fn save_theme(world: &mut World) -> Result<PathBuf, String> {
+    let theme = world.query::<&ThemeDesign>().iter(world).next().cloned().unwrap_or_default();
+    let serialized = serde_json::to_string_pretty(&theme).map_err(|e| e.to_string())?;
+    let path = get_theme_path();
+    std::fs::write(&path, serialized).map_err(|e| e.to_string())?;
+    Ok(path)
+}
+
+/// Load the project theme from file, or return the default theme if there is none
+fn load_theme() -> ThemeDesign {
+    std::fs::read_to_string(get_theme_path())
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Type registry of the designer components shown in the Inspector
+pub fn create_inspector_registry() -> bevy_ecs::reflect::AppTypeRegistry {
+    let registry = bevy_ecs::reflect::AppTypeRegistry::default();
+    {
+        let mut registry = registry.write();
+        WidgetRegistry::default().register_types(&mut registry);
+        registry.register::<UiElementPosition>();
+        registry.register::<UiElementSize>();
+    }
+    registry
+}
+
 /// Initialize a new ECS world with default designer components
 pub fn create_designer_world() -> World {
     let mut world = World::new();
@@ -85,7 +131,25 @@ pub fn create_designer_world() -> World {
     
     // Add distribution settings as a resource
     world.insert_resource(DistributionSettings::new());
-    
+
+    // Widget types that can be placed on the canvas, and the components the Inspector edits through reflection
+    world.insert_resource(WidgetRegistry::default());
+    world.insert_resource(create_inspector_registry());
+
+    // Design pages and the dock layout of the generated application
+    world.spawn(DesignPages::default());
+    world.spawn(DockLayoutDesign::default());
+    world.spawn(MenuBarDesign::default());
+
+    // Named styles elements can share
+    world.spawn(StyleSheet::default());
+
+    // Global theme of the generated application
+    world.spawn(load_theme());
+
+    // Themes and scales the design is previewed under
+    world.spawn(PreviewVariants::default());
+
     // Add default panels from mobius_ecs
     world.spawn(mobius_ecs::MainWorkArea {
         content: "Welcome to Mobius Designer!".to_string(),
@@ -136,7 +200,9 @@ pub fn add_ui_button(
         size: UiElementSize { width: 100.0, height: 30.0 },
         tab: UiElementTab { tab_kind, position: 0 },
         selected: UiElementSelected::default(),
-        container: UiElementContainer { parent_group: None },
+        container: UiElementContainer { parent_group: None, slot: 0 },
+        page: UiElementPage::default(),
+        style: UiElementStyle::default(),
     }).id()
 }
 
@@ -149,17 +215,14 @@ pub fn add_ui_text_input(
     tab_kind: TabKind,
 ) -> Entity {
     world.spawn(UiTextInputBundle {
-        text_input: UiTextInput {
-            label,
-            value: String::new(),
-            enabled: true,
-            font_size: 14.0,
-        },
+        text_input: UiTextInput::new(label),
         position: UiElementPosition { x, y },
         size: UiElementSize { width: 200.0, height: 50.0 },
         tab: UiElementTab { tab_kind, position: 0 },
         selected: UiElementSelected::default(),
-        container: UiElementContainer { parent_group: None },
+        container: UiElementContainer { parent_group: None, slot: 0 },
+        page: UiElementPage::default(),
+        style: UiElementStyle::default(),
     }).id()
 }
 
@@ -182,7 +245,9 @@ pub fn add_ui_checkbox(
         size: UiElementSize { width: 150.0, height: 25.0 },
         tab: UiElementTab { tab_kind, position: 0 },
         selected: UiElementSelected::default(),
-        container: UiElementContainer { parent_group: None },
+        container: UiElementContainer { parent_group: None, slot: 0 },
+        page: UiElementPage::default(),
+        style: UiElementStyle::default(),
     }).id()
 }
 
@@ -195,6 +260,7 @@ pub fn add_ui_radio_button(
     y: f32,
     tab_kind: TabKind,
 ) -> Entity {
+    let order = world.query::<&UiRadioButton>().iter(world).filter(|radio| radio.group_id == group_id).count();
     world.spawn(UiRadioButtonBundle {
         radio_button: UiRadioButton {
             label,
@@ -202,12 +268,16 @@ pub fn add_ui_radio_button(
             enabled: true,
             font_size: 14.0,
             group_id,
+            variant: String::new(),
+            order,
         },
         position: UiElementPosition { x, y },
         size: UiElementSize { width: 150.0, height: 25.0 },
         tab: UiElementTab { tab_kind, position: 0 },
         selected: UiElementSelected::default(),
-        container: UiElementContainer { parent_group: None },
+        container: UiElementContainer { parent_group: None, slot: 0 },
+        page: UiElementPage::default(),
+        style: UiElementStyle::default(),
     }).id()
 }
 
@@ -232,7 +302,9 @@ pub fn add_ui_group_box(
         size: UiElementSize { width, height },
         tab: UiElementTab { tab_kind, position: 0 },
         selected: UiElementSelected::default(),
-        container: UiElementContainer { parent_group: None },
+        container: UiElementContainer { parent_group: None, slot: 0 },
+        page: UiElementPage::default(),
+        style: UiElementStyle::default(),
     }).id()
 }
 
@@ -245,6 +317,9 @@ pub struct DesignerApp {
     pub tab_viewer: MobiusTabViewer,
     pub codegen_state: events::CodeGenState,
     pub world_snapshot: egui_mobius::types::Value<Option<crate::codegen_thread::WorldSnapshot>>,
+    pub template_gallery: templates::TemplateGallery,
+    /// Whether the template gallery is shown; it welcomes the user at startup
+    pub show_template_gallery: bool,
 }
 
 impl DesignerApp {
@@ -273,6 +348,8 @@ impl DesignerApp {
             tab_viewer: MobiusTabViewer::new(),
             codegen_state: events::CodeGenState::new(signal_to_codegen, slot_for_responses),
             world_snapshot: world_snapshot.clone(),
+            template_gallery: templates::TemplateGallery::load(),
+            show_template_gallery: true,
         };
         
         // Start background timer thread for code generation
@@ -311,185 +388,85 @@ impl DesignerApp {
         add_ui_group_box(&mut self.world, "Settings".to_string(), 300.0, 50.0, 200.0, 150.0, TabKind::MainWork);
     }
     
-    fn get_current_tab_kind(&mut self) -> Option<TabKind> {
-        if let Some(tab) = self.dock_state.find_active_focused() {
-            Some(tab.1.kind.clone())
-        } else {
-            Some(TabKind::MainWork) // Default fallback
-        }
-    }
-    
-    fn add_button_to_current_tab(&mut self) {
-        if let Some(current_tab_kind) = self.get_current_tab_kind() {
-            let button_count = self.world.query::<&UiButton>().iter(&self.world).count();
-            
-            // Generate a position with some offset to avoid overlap
-            let mut x_offset = (button_count % 5) as f32 * 120.0 + 20.0;
-            let mut y_offset = (button_count / 5) as f32 * 50.0 + 50.0;
-            
-            // Apply grid snapping to initial position
-            {
-                let mut grid_query = self.world.query::<&GridSettings>();
-                if let Some(grid_settings) = grid_query.iter(&self.world).next() {
-                    let snapped_pos = snap_to_grid(egui::Pos2::new(x_offset, y_offset), grid_settings.spacing_pixels);
-                    x_offset = snapped_pos.x;
-                    y_offset = snapped_pos.y;
+    /// Replace the design with a new one, empty or started from a gallery template.
+    /// Widget types registered on the app stay registered.
+    pub fn new_project(&mut self, template: Option<&str>) {
+        let widgets = current_widgets(&self.world);
+        let type_registry = self.world.resource::<bevy_ecs::reflect::AppTypeRegistry>().clone();
+        let mut world = create_designer_world();
+        world.insert_resource(widgets);
+        world.insert_resource(type_registry);
+
+        match template {
+            Some(name) => {
+                let unknown = templates::spawn_template_elements(&mut world, self.template_gallery.elements(name));
+                add_designer_log(&mut world, &format!("🆕 Started a new design from template '{}'", name));
+                if !unknown.is_empty() {
+                    add_designer_log(&mut world, &format!("⚠ Skipped elements of unknown kinds: {}", unknown.join(", ")));
                 }
             }
-    
-            self.world.spawn(UiButtonBundle {
-                button: UiButton {
-                    label: format!("Button {}", button_count + 1),
-                    clicked: false,
-                    enabled: true,
-                    click_time: None,
-                    font_size: 14.0,
-                },
-                tab: UiElementTab {
-                    tab_kind: current_tab_kind,
-                    position: 0,
-                },
-                position: UiElementPosition {
-                    x: x_offset,
-                    y: y_offset,
-                },
-                size: UiElementSize::default(),
-                selected: UiElementSelected::default(),
-                container: UiElementContainer { parent_group: None },
-            });
-    
-            add_designer_log(&mut self.world, &format!("Added Button {} to tab", button_count + 1));
+            None => add_designer_log(&mut world, "🆕 Started a blank design"),
         }
+
+        // Nothing may refer to entities of the replaced world
+        self.world = world;
+        self.selection_state = SelectionState::default();
+        self.tab_viewer.renaming_entity = None;
+        self.tab_viewer.resizing_entity = None;
+        self.tab_viewer.drag_selection = None;
+        self.tab_viewer.set_world(&mut self.world as *mut World);
     }
-    
-    fn add_text_input_to_current_tab(&mut self) {
-        if let Some(current_tab_kind) = self.get_current_tab_kind() {
-            let input_count = self.world.query::<&UiTextInput>().iter(&self.world).count();
-            
-            let mut x_offset = (input_count % 3) as f32 * 200.0 + 20.0;
-            let mut y_offset = (input_count / 3) as f32 * 50.0 + 150.0;
-            
-            {
-                let mut grid_query = self.world.query::<&GridSettings>();
-                if let Some(grid_settings) = grid_query.iter(&self.world).next() {
-                    let snapped_pos = snap_to_grid(egui::Pos2::new(x_offset, y_offset), grid_settings.spacing_pixels);
-                    x_offset = snapped_pos.x;
-                    y_offset = snapped_pos.y;
-                }
+
+    /// Add a designer tab next to the Controls tab unless a tab of that kind is already open
+    fn open_designer_tab(&mut self, name: &str, kind: TabKind, id: usize) {
+        let already_open = self.dock_state.iter_all_tabs().any(|(_surface_info, tab)| tab.kind == kind);
+
+        if !already_open {
+            let new_tab = Tab {
+                name: name.to_string(),
+                kind,
+                id,
+            };
+            // Try to find the first available leaf to add the tab
+            if let Some((surface_index, node_index, _tab_index)) = self.dock_state.find_tab(&Tab { name: "Controls".to_string(), kind: TabKind::Controls, id: 2 }) {
+                self.dock_state.set_focused_node_and_surface((surface_index, node_index));
+                self.dock_state.push_to_focused_leaf(new_tab);
             }
-    
-            self.world.spawn(UiTextInputBundle {
-                text_input: UiTextInput {
-                    label: format!("Input {}", input_count + 1),
-                    value: String::new(),
-                    enabled: true,
-                    font_size: 14.0,
-                },
-                tab: UiElementTab {
-                    tab_kind: current_tab_kind,
-                    position: 0,
-                },
-                position: UiElementPosition {
-                    x: x_offset,
-                    y: y_offset,
-                },
-                size: UiElementSize { width: 200.0, height: 30.0 },
-                selected: UiElementSelected::default(),
-                container: UiElementContainer { parent_group: None },
-            });
-    
-            add_designer_log(&mut self.world, &format!("Added Text Input {} to tab", input_count + 1));
+            add_designer_log(&mut self.world, &format!("Added {} tab", name));
+        } else {
+            add_designer_log(&mut self.world, &format!("{} tab already exists", name));
         }
     }
-    
-    fn add_checkbox_to_current_tab(&mut self) {
-        if let Some(current_tab_kind) = self.get_current_tab_kind() {
-            let checkbox_count = self.world.query::<&UiCheckbox>().iter(&self.world).count();
-            
-            let mut x_offset = (checkbox_count % 4) as f32 * 150.0 + 20.0;
-            let mut y_offset = (checkbox_count / 4) as f32 * 50.0 + 250.0;
-            
-            {
-                let mut grid_query = self.world.query::<&GridSettings>();
-                if let Some(grid_settings) = grid_query.iter(&self.world).next() {
-                    let snapped_pos = snap_to_grid(egui::Pos2::new(x_offset, y_offset), grid_settings.spacing_pixels);
-                    x_offset = snapped_pos.x;
-                    y_offset = snapped_pos.y;
-                }
-            }
-    
-            self.world.spawn(UiCheckboxBundle {
-                checkbox: UiCheckbox {
-                    label: format!("Checkbox {}", checkbox_count + 1),
-                    checked: false,
-                    enabled: true,
-                    font_size: 14.0,
-                },
-                tab: UiElementTab {
-                    tab_kind: current_tab_kind,
-                    position: 0,
-                },
-                position: UiElementPosition {
-                    x: x_offset,
-                    y: y_offset,
-                },
-                size: UiElementSize::default(),
-                selected: UiElementSelected::default(),
-                container: UiElementContainer { parent_group: None },
-            });
-    
-            add_designer_log(&mut self.world, &format!("Added Checkbox {} to tab", checkbox_count + 1));
+
+    fn get_current_tab_kind(&mut self) -> Option<TabKind> {
+        if let Some(tab) = self.dock_state.find_active_focused() {
+            Some(tab.1.kind.clone())
+        } else {
+            Some(TabKind::MainWork) // Default fallback
         }
     }
     
-    fn add_radio_button_to_current_tab(&mut self) {
-        if let Some(current_tab_kind) = self.get_current_tab_kind() {
-            let radio_count = self.world.query::<&UiRadioButton>().iter(&self.world).count();
-            
-            let mut x_offset = (radio_count % 4) as f32 * 150.0 + 20.0;
-            let mut y_offset = (radio_count / 4) as f32 * 50.0 + 350.0;
-            
-            {
-                let mut grid_query = self.world.query::<&GridSettings>();
-                if let Some(grid_settings) = grid_query.iter(&self.world).next() {
-                    let snapped_pos = snap_to_grid(egui::Pos2::new(x_offset, y_offset), grid_settings.spacing_pixels);
-                    x_offset = snapped_pos.x;
-                    y_offset = snapped_pos.y;
-                }
-            }
-    
-            self.world.spawn(UiRadioButtonBundle {
-                radio_button: UiRadioButton {
-                    label: format!("Radio {}", radio_count + 1),
-                    selected: false,
-                    enabled: true,
-                    font_size: 14.0,
-                    group_id: "default_group".to_string(),
-                },
-                tab: UiElementTab {
-                    tab_kind: current_tab_kind,
-                    position: 0,
-                },
-                position: UiElementPosition {
-                    x: x_offset,
-                    y: y_offset,
-                },
-                size: UiElementSize::default(),
-                selected: UiElementSelected::default(),
-                container: UiElementContainer { parent_group: None },
-            });
-    
-            add_designer_log(&mut self.world, &format!("Added Radio Button {} to tab", radio_count + 1));
-        }
+    /// Make a widget type available in the add menus, the Inspector and code generation
+    pub fn register_widget(&mut self, widget: impl DesignerWidget) {
+        register_widget(&mut self.world, widget);
     }
-    
-    fn add_group_box_to_current_tab(&mut self) {
+
+    fn add_widget_to_current_tab(&mut self, kind: &str) {
+        let widgets = current_widgets(&self.world);
+        let Some((band, widget)) = widgets.iter().enumerate().find(|(_, widget)| widget.kind() == kind) else {
+            return;
+        };
         if let Some(current_tab_kind) = self.get_current_tab_kind() {
-            let group_count = self.world.query::<&UiGroupBox>().iter(&self.world).count();
+            let page_id = active_page_id(&mut self.world);
+            let count = widget_elements(&self.world, widget.as_ref()).len();
             
-            let mut x_offset = (group_count % 3) as f32 * 250.0 + 50.0;
-            let mut y_offset = (group_count / 3) as f32 * 200.0 + 450.0;
+            // Generate a position with some offset to avoid overlap, one band of rows per widget type
+            let column_width = widget.default_size().width.max(100.0) + 20.0;
+            let columns = ((600.0 / column_width) as usize).max(1);
+            let mut x_offset = (count % columns) as f32 * column_width + 20.0;
+            let mut y_offset = (count / columns) as f32 * 50.0 + 50.0 + band as f32 * 100.0;
             
+            // Apply grid snapping to initial position
             {
                 let mut grid_query = self.world.query::<&GridSettings>();
                 if let Some(grid_settings) = grid_query.iter(&self.world).next() {
@@ -499,27 +476,9 @@ impl DesignerApp {
                 }
             }
     
-            self.world.spawn(UiGroupBoxBundle {
-                group_box: UiGroupBox {
-                    label: format!("Group {}", group_count + 1),
-                    enabled: true,
-                    font_size: 14.0,
-                    contained_widgets: Vec::new(),
-                },
-                tab: UiElementTab {
-                    tab_kind: current_tab_kind,
-                    position: 0,
-                },
-                position: UiElementPosition {
-                    x: x_offset,
-                    y: y_offset,
-                },
-                size: UiElementSize { width: 200.0, height: 150.0 },
-                selected: UiElementSelected::default(),
-                container: UiElementContainer { parent_group: None },
-            });
-    
-            add_designer_log(&mut self.world, &format!("Added Group Box {} to tab", group_count + 1));
+            let entity = spawn_widget(&mut self.world, widget.as_ref(), x_offset, y_offset, current_tab_kind, page_id);
+            let label = widget.label(self.world.entity(entity));
+            add_designer_log(&mut self.world, &format!("Added {} to tab", label));
         }
     }
 }
@@ -527,6 +486,9 @@ impl DesignerApp {
 impl Drop for DesignerApp {
     fn drop(&mut self) {
         save_dock_layout(&self.dock_state);
+        if let Err(e) = save_theme(&mut self.world) {
+            eprintln!("Failed to save theme: {}", e);
+        }
     }
 }
 
@@ -538,6 +500,9 @@ impl eframe::App for DesignerApp {
         
         // Reset button clicks after a delay (visual feedback)
         reset_button_clicks(&mut self.world);
+
+        // Instances of components follow their master's elements
+        crate::systems::sync_symbol_instances(&mut self.world);
         
         // Update world snapshot for codegen thread (only when it needs it)
         {
@@ -550,8 +515,8 @@ impl eframe::App for DesignerApp {
         egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
             egui::MenuBar::new().ui(ui, |ui| {
                 ui.menu_button("File", |ui| {
-                    if ui.button("🆕 New Project").clicked() {
-                        self.world.clear_all();
+                    if ui.button("🆕 New Project...").clicked() {
+                        self.show_template_gallery = true;
                         ui.close_kind(egui::UiKind::Menu);
                     }
                     ui.separator();
@@ -559,6 +524,10 @@ impl eframe::App for DesignerApp {
                         // Handle open
                     }
                     if ui.button("💾 Save Project").clicked() {
+                        match save_theme(&mut self.world) {
+                            Ok(path) => add_designer_log(&mut self.world, &format!("💾 Saved theme to {}", path.display())),
+                            Err(e) => add_designer_log(&mut self.world, &format!("❌ Failed to save theme: {}", e)),
+                        }
                         ui.close_kind(egui::UiKind::Menu);
                     }
                     ui.separator();
@@ -577,59 +546,46 @@ impl eframe::App for DesignerApp {
                 
                 ui.menu_button("View", |ui| {
                     if ui.button("🖥️ Add Preview Tab").clicked() {
-                        // Check if Preview tab already exists
-                        let mut has_preview = false;
-                        for (_surface_info, tab) in self.dock_state.iter_all_tabs() {
-                            if matches!(tab.kind, TabKind::Preview) {
-                                has_preview = true;
-                                break;
-                            }
-                        }
-                        
-                        if !has_preview {
-                            let new_tab = Tab { 
-                                name: "Preview".to_string(), 
-                                kind: TabKind::Preview, 
-                                id: 5 
-                            };
-                            // Try to find the first available leaf to add the tab
-                            if let Some((surface_index, node_index, _tab_index)) = self.dock_state.find_tab(&Tab { name: "Controls".to_string(), kind: TabKind::Controls, id: 2 }) {
-                                self.dock_state.set_focused_node_and_surface((surface_index, node_index));
-                                self.dock_state.push_to_focused_leaf(new_tab);
-                            }
-                            add_designer_log(&mut self.world, "Added Preview tab");
-                        } else {
-                            add_designer_log(&mut self.world, "Preview tab already exists");
-                        }
+                        self.open_designer_tab("Preview", TabKind::Preview, 5);
                         ui.close_kind(egui::UiKind::Menu);
                     }
-                });
-                
-                ui.menu_button("UI Elements", |ui| {
-                    if ui.button("➕ Add Button").clicked() {
-                        self.add_button_to_current_tab();
+                    if ui.button("🗂️ Add Dock Layout Tab").clicked() {
+                        self.open_designer_tab("Dock Layout", TabKind::DockLayout, 6);
                         ui.close_kind(egui::UiKind::Menu);
                     }
-                    
-                    if ui.button("📝 Add Text Input").clicked() {
-                        self.add_text_input_to_current_tab();
+                    if ui.button("☰ Add Menu Bar Tab").clicked() {
+                        self.open_designer_tab("Menu Bar", TabKind::MenuBar, 7);
                         ui.close_kind(egui::UiKind::Menu);
                     }
-                    
-                    if ui.button("☑️ Add Checkbox").clicked() {
-                        self.add_checkbox_to_current_tab();
+                    if ui.button("🎨 Add Variant Preview Tab").clicked() {
+                        self.open_designer_tab("Variant Preview", TabKind::VariantPreview, 8);
                         ui.close_kind(egui::UiKind::Menu);
                     }
-                    
-                    if ui.button("🔘 Add Radio Button").clicked() {
-                        self.add_radio_button_to_current_tab();
+                    if ui.button("🖌 Add Styles Tab").clicked() {
+                        self.open_designer_tab("Styles", TabKind::Styles, 9);
                         ui.close_kind(egui::UiKind::Menu);
                     }
-                    
-                    if ui.button("📦 Add Group Box").clicked() {
-                        self.add_group_box_to_current_tab();
+                    if ui.button("🌗 Add Theme Tab").clicked() {
+                        self.open_designer_tab("Theme", TabKind::Theme, 10);
+                        ui.close_kind(egui::UiKind::Menu);
+                    }
+                    if ui.button("🔘 Add Radio Groups Tab").clicked() {
+                        self.open_designer_tab("Radio Groups", TabKind::RadioGroups, 11);
+                        ui.close_kind(egui::UiKind::Menu);
+                    }
+                    if ui.button("◈ Add Components Tab").clicked() {
+                        self.open_designer_tab("Components", TabKind::Symbols, 12);
                         ui.close_kind(egui::UiKind::Menu);
                     }
+                });
+                
+                ui.menu_button("UI Elements", |ui| {
+                    for widget in current_widgets(&self.world).iter() {
+                        if ui.button(format!("{} Add {}", widget.icon(), widget.display_name())).clicked() {
+                            self.add_widget_to_current_tab(widget.kind());
+                            ui.close_kind(egui::UiKind::Menu);
+                        }
+                    }
                     
                     ui.separator();
                     if ui.button("🗑️ Clear All UI Elements").clicked() {
@@ -641,6 +597,15 @@ impl eframe::App for DesignerApp {
             });
         });
         
+        // Welcome screen and New Project dialog
+        if self.show_template_gallery {
+            match render_template_gallery(ctx, &mut self.template_gallery, &mut self.show_template_gallery) {
+                Some(GalleryChoice::Blank) => self.new_project(None),
+                Some(GalleryChoice::Template(name)) => self.new_project(Some(&name)),
+                None => {}
+            }
+        }
+
         // Main UI
         egui::CentralPanel::default().show(ctx, |_ui| {
             // Render the docking area
@@ -7,4 +7,29 @@ pub struct SelectionState {
     pub start_pos: Pos2,
     pub current_pos: Pos2,
     pub selected_entities: Vec<Entity>,
-}
\ No newline at end of file
+}
+/// Scratch copy of widget state used while the canvas runs as an interactive preview.
+/// The design components are never touched; the copy is dropped when the preview stops.
+#[derive(Resource, Default)]
+pub struct RunPreviewState {
+    pub text_values: std::collections::HashMap<Entity, String>,
+    pub checked: std::collections::HashMap<Entity, bool>,
+    /// Selected radio button of each radio group
+    pub radio_selection: std::collections::HashMap<String, Entity>,
+    pub button_clicks: std::collections::HashMap<Entity, u32>,
+    pub dates: std::collections::HashMap<Entity, chrono::NaiveDate>,
+    pub times: std::collections::HashMap<Entity, chrono::NaiveTime>,
+    pub colors: std::collections::HashMap<Entity, egui::Color32>,
+    /// Values the generated app sets from its own code, scrubbed in the run toolbar
+    pub progress: std::collections::HashMap<Entity, f32>,
+    pub spinning: std::collections::HashMap<Entity, bool>,
+    pub statuses: std::collections::HashMap<Entity, crate::components::LedStatus>,
+    /// Whether each collapsing header is open
+    pub open_sections: std::collections::HashMap<Entity, bool>,
+    /// Open tab of each tab strip
+    pub open_tabs: std::collections::HashMap<Entity, usize>,
+    /// Whether each tree view node, by tree and node index, is expanded
+    pub open_nodes: std::collections::HashMap<(Entity, usize), bool>,
+    /// Index of the selected node of each tree view
+    pub tree_selection: std::collections::HashMap<Entity, usize>,
+}
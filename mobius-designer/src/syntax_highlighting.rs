@@ -22,8 +22,19 @@ impl SyntaxHighlighter {
         Self::default()
     }
 
+    /// Highlighter loaded once and shared by everything drawing highlighted code every frame
+    pub fn shared() -> &'static SyntaxHighlighter {
+        static SHARED: std::sync::OnceLock<SyntaxHighlighter> = std::sync::OnceLock::new();
+        SHARED.get_or_init(SyntaxHighlighter::new)
+    }
+
     pub fn highlight_rust_code(&self, code: &str) -> Vec<(egui::Color32, String)> {
-        let syntax = self.syntax_set.find_syntax_by_extension("rs")
+        self.highlight(code, "rs")
+    }
+
+    /// Colored segments of `code` in the language of the file extension `extension`
+    pub fn highlight(&self, code: &str, extension: &str) -> Vec<(egui::Color32, String)> {
+        let syntax = self.syntax_set.find_syntax_by_extension(extension)
             .unwrap_or_else(|| self.syntax_set.find_syntax_plain_text());
         
         // Use a dark theme that works well with egui
@@ -33,8 +44,9 @@ impl SyntaxHighlighter {
         let mut highlighted_segments = Vec::new();
         
         for line in LinesWithEndings::from(code) {
+            // Unhighlighted rather than dropped, so editors laying out the text get all of it
             let ranges: Vec<(Style, &str)> = highlighter.highlight_line(line, &self.syntax_set)
-                .unwrap_or_default();
+                .unwrap_or_else(|_| vec![(Style { foreground: syntect::highlighting::Color::WHITE, ..Style::default() }, line)]);
             
             for (style, text) in ranges {
                 let color = egui::Color32::from_rgb(
@@ -49,6 +61,16 @@ impl SyntaxHighlighter {
         highlighted_segments
     }
 
+    /// Highlighted `code` in a monospace font, e.g. for a `TextEdit` layouter
+    pub fn layout_job(&self, code: &str, extension: &str, font_size: f32) -> egui::text::LayoutJob {
+        let font_id = egui::FontId::monospace(font_size);
+        let mut job = egui::text::LayoutJob::default();
+        for (color, text) in self.highlight(code, extension) {
+            job.append(&text, 0.0, egui::TextFormat::simple(font_id.clone(), color));
+        }
+        job
+    }
+
     pub fn render_highlighted_code(&self, ui: &mut egui::Ui, code: &str) {
         let highlighted = self.highlight_rust_code(code);
         
@@ -0,0 +1,267 @@
+use bevy_ecs::prelude::*;
+use egui::Ui;
+use crate::components::*;
+use crate::systems::page_bar::{add_design_page, remove_design_page};
+
+/// Editor for the dock layout of the generated application
+pub fn render_dock_layout_panel(ui: &mut Ui, world: &mut World) {
+    ui.heading("🗂️ Dock Layout");
+    ui.label("Arrange design pages into the tabs and splits of the generated app");
+    ui.separator();
+
+    let pages = {
+        let mut query = world.query::<&DesignPages>();
+        match query.iter(world).next() {
+            Some(pages) => pages.clone(),
+            None => {
+                ui.label("No design pages found");
+                return;
+            }
+        }
+    };
+    let Some(mut layout) = ({
+        let mut query = world.query::<&DockLayoutDesign>();
+        query.iter(world).next().cloned()
+    }) else {
+        ui.label("No dock layout found");
+        return;
+    };
+    let original_hash = layout_hash(&layout);
+
+    let mut renames = Vec::new();
+    let mut add_page = false;
+    let mut remove_page = None;
+
+    ui.checkbox(&mut layout.enabled, "Generate a dock layout")
+        .on_hover_text("When disabled the generated app shows the first page in a CentralPanel");
+    ui.add_space(8.0);
+
+    ui.group(|ui| {
+        ui.label("Pages");
+        for page in &pages.pages {
+            ui.horizontal(|ui| {
+                let mut name = page.name.clone();
+                if ui.text_edit_singleline(&mut name).changed() && !name.trim().is_empty() {
+                    renames.push((page.id, name));
+                }
+                let can_remove = pages.pages.len() > 1;
+                if ui.add_enabled(can_remove, egui::Button::new("🗑").small())
+                    .on_hover_text("Delete this page and its elements")
+                    .clicked()
+                {
+                    remove_page = Some(page.id);
+                }
+            });
+        }
+        if ui.button("➕ Add Page").clicked() {
+            add_page = true;
+        }
+    });
+
+    ui.add_space(8.0);
+
+    ui.add_enabled_ui(layout.enabled, |ui| {
+        ui.group(|ui| {
+            ui.label("Layout");
+            egui::ScrollArea::vertical()
+                .id_salt("dock_layout_tree")
+                .max_height(300.0)
+                .show(ui, |ui| {
+                    render_node_editor(ui, &mut layout.root, &pages, "root");
+                });
+        });
+
+        let placed = layout.root.page_ids();
+        let unplaced: Vec<&DesignPage> = pages.pages.iter()
+            .filter(|page| !placed.contains(&page.id))
+            .collect();
+        if !unplaced.is_empty() {
+            let names: Vec<&str> = unplaced.iter().map(|page| page.name.as_str()).collect();
+            ui.colored_label(
+                egui::Color32::from_rgb(255, 200, 0),
+                format!("⚠ Not placed in the layout: {}", names.join(", ")),
+            );
+        }
+
+        ui.add_space(8.0);
+        ui.label("Preview");
+        let (rect, _) = ui.allocate_exact_size(egui::vec2(ui.available_width().min(320.0), 180.0), egui::Sense::hover());
+        paint_layout_preview(ui.painter(), rect, &layout.root, &pages);
+    });
+
+    // Write back edits
+    if layout_hash(&layout) != original_hash {
+        let mut query = world.query::<&mut DockLayoutDesign>();
+        if let Some(mut stored) = query.iter_mut(world).next() {
+            *stored = layout;
+        }
+    }
+    if !renames.is_empty() {
+        let mut query = world.query::<&mut DesignPages>();
+        if let Some(mut stored) = query.iter_mut(world).next() {
+            for (page_id, name) in renames {
+                if let Some(page) = stored.pages.iter_mut().find(|page| page.id == page_id) {
+                    page.name = name;
+                }
+            }
+        }
+    }
+    if add_page {
+        add_design_page(world);
+    }
+    if let Some(page_id) = remove_page {
+        remove_design_page(world, page_id);
+    }
+}
+
+fn layout_hash(layout: &DockLayoutDesign) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    layout.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn render_node_editor(ui: &mut Ui, node: &mut DockLayoutNode, pages: &DesignPages, path: &str) {
+    let mut replace_with = None;
+
+    match node {
+        DockLayoutNode::Leaf { pages: tabs } => {
+            ui.group(|ui| {
+                ui.label("Tab group");
+                let mut move_left = None;
+                let mut move_right = None;
+                let mut remove = None;
+                let tab_count = tabs.len();
+                for (index, page_id) in tabs.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        ui.label(format!("📄 {}", pages.page_name(*page_id)));
+                        if ui.add_enabled(index > 0, egui::Button::new("◀").small()).clicked() {
+                            move_left = Some(index);
+                        }
+                        if ui.add_enabled(index + 1 < tab_count, egui::Button::new("▶").small()).clicked() {
+                            move_right = Some(index);
+                        }
+                        if ui.small_button("✖").on_hover_text("Remove from this tab group").clicked() {
+                            remove = Some(index);
+                        }
+                    });
+                }
+                if tabs.is_empty() {
+                    ui.weak("(empty - omitted from generated code)");
+                }
+                if let Some(index) = move_left {
+                    tabs.swap(index, index - 1);
+                }
+                if let Some(index) = move_right {
+                    tabs.swap(index, index + 1);
+                }
+                if let Some(index) = remove {
+                    tabs.remove(index);
+                }
+
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_id_salt(format!("dock_add_page_{}", path))
+                        .selected_text("Add page...")
+                        .show_ui(ui, |ui| {
+                            for page in &pages.pages {
+                                if !tabs.contains(&page.id) && ui.selectable_label(false, &page.name).clicked() {
+                                    tabs.push(page.id);
+                                }
+                            }
+                        });
+                    if ui.small_button("Split Right").clicked() {
+                        replace_with = Some(split_leaf(tabs, DockSplitDirection::Horizontal));
+                    }
+                    if ui.small_button("Split Below").clicked() {
+                        replace_with = Some(split_leaf(tabs, DockSplitDirection::Vertical));
+                    }
+                });
+            });
+        }
+        DockLayoutNode::Split { direction, fraction, first, second } => {
+            let mut merge = false;
+            ui.group(|ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Split");
+                    egui::ComboBox::from_id_salt(format!("dock_split_dir_{}", path))
+                        .selected_text(match direction {
+                            DockSplitDirection::Horizontal => "Left | Right",
+                            DockSplitDirection::Vertical => "Top / Bottom",
+                        })
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(direction, DockSplitDirection::Horizontal, "Left | Right");
+                            ui.selectable_value(direction, DockSplitDirection::Vertical, "Top / Bottom");
+                        });
+                    ui.add(egui::Slider::new(fraction, 0.1..=0.9).text("share"));
+                    if ui.small_button("Merge").on_hover_text("Collapse into a single tab group").clicked() {
+                        merge = true;
+                    }
+                });
+                ui.indent(format!("dock_split_{}", path), |ui| {
+                    render_node_editor(ui, first, pages, &format!("{}_0", path));
+                    render_node_editor(ui, second, pages, &format!("{}_1", path));
+                });
+            });
+            if merge {
+                node.merge();
+            }
+        }
+    }
+
+    if let Some(new_node) = replace_with {
+        *node = new_node;
+    }
+}
+
+/// Split a tab group in two; the last tab moves to the new group when there is more than one
+fn split_leaf(tabs: &mut Vec<usize>, direction: DockSplitDirection) -> DockLayoutNode {
+    let moved = if tabs.len() > 1 { tabs.pop().into_iter().collect() } else { Vec::new() };
+    DockLayoutNode::Split {
+        direction,
+        fraction: 0.5,
+        first: Box::new(DockLayoutNode::Leaf { pages: tabs.clone() }),
+        second: Box::new(DockLayoutNode::Leaf { pages: moved }),
+    }
+}
+
+fn paint_layout_preview(painter: &egui::Painter, rect: egui::Rect, node: &DockLayoutNode, pages: &DesignPages) {
+    match node {
+        DockLayoutNode::Leaf { pages: tabs } => {
+            let visuals = egui::Stroke::new(1.0, egui::Color32::from_gray(140));
+            painter.rect_filled(rect.shrink(2.0), 2.0, egui::Color32::from_gray(45));
+            painter.rect_stroke(rect.shrink(2.0), 2.0, visuals, egui::StrokeKind::Inside);
+            let label = if tabs.is_empty() {
+                "(empty)".to_string()
+            } else {
+                tabs.iter().map(|id| pages.page_name(*id)).collect::<Vec<_>>().join(" | ")
+            };
+            painter.text(
+                rect.left_top() + egui::vec2(6.0, 6.0),
+                egui::Align2::LEFT_TOP,
+                label,
+                egui::FontId::proportional(11.0),
+                egui::Color32::from_gray(220),
+            );
+        }
+        DockLayoutNode::Split { direction, fraction, first, second } => {
+            let (first_rect, second_rect) = match direction {
+                DockSplitDirection::Horizontal => {
+                    let x = rect.left() + rect.width() * *fraction;
+                    (
+                        egui::Rect::from_min_max(rect.min, egui::pos2(x, rect.bottom())),
+                        egui::Rect::from_min_max(egui::pos2(x, rect.top()), rect.max),
+                    )
+                }
+                DockSplitDirection::Vertical => {
+                    let y = rect.top() + rect.height() * *fraction;
+                    (
+                        egui::Rect::from_min_max(rect.min, egui::pos2(rect.right(), y)),
+                        egui::Rect::from_min_max(egui::pos2(rect.left(), y), rect.max),
+                    )
+                }
+            };
+            paint_layout_preview(painter, first_rect, first, pages);
+            paint_layout_preview(painter, second_rect, second, pages);
+        }
+    }
+}
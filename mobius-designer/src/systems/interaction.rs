@@ -59,15 +59,16 @@ fn draw_selection_box(ui: &mut egui::Ui, selection_state: &SelectionState) {
 }
 
 fn select_elements_in_rect(world: &mut World, selection_rect: Rect) {
-    let mut query = world.query::<(Entity, &UiElementPosition, &UiElementSize, &mut UiElementSelected)>();
+    let active_page = active_page_id(world);
+    let mut query = world.query_filtered::<(Entity, &UiElementPosition, &UiElementSize, &mut UiElementSelected, Option<&UiElementPage>), Without<UiSymbolCopy>>();
     
-    for (_entity, pos, size, mut selected) in query.iter_mut(world) {
+    for (_entity, pos, size, mut selected, page) in query.iter_mut(world) {
         let element_rect = Rect::from_min_size(
             Pos2::new(pos.x, pos.y),
             Vec2::new(size.width, size.height),
         );
         
-        if selection_rect.intersects(element_rect) {
+        if is_on_page(page, active_page) && selection_rect.intersects(element_rect) {
             selected.selected = true;
         }
     }
@@ -130,7 +131,7 @@ fn delete_selected_elements(world: &mut World) {
 
 fn select_all_elements(world: &mut World) {
     let mut count = 0;
-    let mut query = world.query::<&mut UiElementSelected>();
+    let mut query = world.query_filtered::<&mut UiElementSelected, Without<UiSymbolCopy>>();
     for mut selected in query.iter_mut(world) {
         selected.selected = true;
         count += 1;
@@ -0,0 +1,222 @@
+use bevy_ecs::prelude::*;
+use egui::Ui;
+use crate::components::*;
+
+/// Reordering and removal requested for an entry of a list while it was being drawn
+enum ListAction {
+    MoveUp(usize),
+    MoveDown(usize),
+    Remove(usize),
+}
+
+fn apply_list_action<T>(list: &mut Vec<T>, action: Option<ListAction>) {
+    match action {
+        Some(ListAction::MoveUp(index)) if index > 0 => list.swap(index, index - 1),
+        Some(ListAction::MoveDown(index)) if index + 1 < list.len() => list.swap(index, index + 1),
+        Some(ListAction::Remove(index)) if index < list.len() => {
+            list.remove(index);
+        }
+        _ => {}
+    }
+}
+
+fn list_controls(ui: &mut Ui, index: usize, len: usize, action: &mut Option<ListAction>) {
+    if ui.add_enabled(index > 0, egui::Button::new("⬆").small()).clicked() {
+        *action = Some(ListAction::MoveUp(index));
+    }
+    if ui.add_enabled(index + 1 < len, egui::Button::new("⬇").small()).clicked() {
+        *action = Some(ListAction::MoveDown(index));
+    }
+    if ui.small_button("🗑").clicked() {
+        *action = Some(ListAction::Remove(index));
+    }
+}
+
+/// Editor for the menu bar of the generated application
+pub fn render_menu_bar_panel(ui: &mut Ui, world: &mut World) {
+    ui.heading("☰ Menu Bar");
+    ui.label("Menus of the generated app. Each item calls a method of the generated MenuHandler trait.");
+    ui.separator();
+
+    let Some(mut menu_bar) = ({
+        let mut query = world.query::<&MenuBarDesign>();
+        query.iter(world).next().cloned()
+    }) else {
+        ui.label("No menu bar design found");
+        return;
+    };
+    let original = menu_bar.clone();
+
+    // Live preview of the designed menus
+    ui.label("Preview");
+    egui::Frame::group(ui.style()).show(ui, |ui| {
+        egui::MenuBar::new().ui(ui, |ui| {
+            for menu in &menu_bar.menus {
+                ui.menu_button(&menu.title, |ui| preview_entries(ui, &menu.entries));
+            }
+            if menu_bar.menus.is_empty() {
+                ui.weak("(no menus - the generated app has no menu bar)");
+            }
+        });
+    });
+    ui.add_space(8.0);
+
+    egui::ScrollArea::vertical()
+        .id_salt("menu_bar_editor")
+        .show(ui, |ui| {
+            let mut action = None;
+            let menu_count = menu_bar.menus.len();
+            for (index, menu) in menu_bar.menus.iter_mut().enumerate() {
+                ui.push_id(("menu", index), |ui| {
+                    ui.group(|ui| {
+                        ui.horizontal(|ui| {
+                            ui.label("Menu:");
+                            ui.text_edit_singleline(&mut menu.title);
+                            list_controls(ui, index, menu_count, &mut action);
+                        });
+                        ui.indent("entries", |ui| {
+                            render_entries_editor(ui, &mut menu.entries);
+                        });
+                    });
+                });
+            }
+            apply_list_action(&mut menu_bar.menus, action);
+
+            if ui.button("➕ Add Menu").clicked() {
+                menu_bar.menus.push(MenuDesign {
+                    title: format!("Menu {}", menu_bar.menus.len() + 1),
+                    entries: Vec::new(),
+                });
+            }
+
+            render_menu_warnings(ui, &menu_bar);
+        });
+
+    if menu_bar != original {
+        let mut query = world.query::<&mut MenuBarDesign>();
+        if let Some(mut stored) = query.iter_mut(world).next() {
+            *stored = menu_bar;
+        }
+    }
+}
+
+fn preview_entries(ui: &mut Ui, entries: &[MenuEntry]) {
+    for entry in entries {
+        match entry {
+            MenuEntry::Item(item) => {
+                let mut button = egui::Button::new(&item.label);
+                if item.checkable {
+                    button = button.selected(item.checked);
+                }
+                if let Some(shortcut) = item.keyboard_shortcut() {
+                    button = button.shortcut_text(ui.ctx().format_shortcut(&shortcut));
+                }
+                ui.add(button);
+            }
+            MenuEntry::Separator => {
+                ui.separator();
+            }
+            MenuEntry::Submenu { title, entries } => {
+                ui.menu_button(title, |ui| preview_entries(ui, entries));
+            }
+        }
+    }
+}
+
+fn render_entries_editor(ui: &mut Ui, entries: &mut Vec<MenuEntry>) {
+    let mut action = None;
+    let entry_count = entries.len();
+
+    for (index, entry) in entries.iter_mut().enumerate() {
+        ui.push_id(("entry", index), |ui| match entry {
+            MenuEntry::Item(item) => {
+                ui.horizontal(|ui| {
+                    ui.label("Item:");
+                    let derived_handler = format!("on_{}", crate::codegen::snake_ident(&item.label));
+                    if ui.add(egui::TextEdit::singleline(&mut item.label).desired_width(110.0)).changed()
+                        && item.handler == derived_handler
+                    {
+                        // Keep the handler name in sync until it has been edited by hand
+                        item.handler = format!("on_{}", crate::codegen::snake_ident(&item.label));
+                    }
+                    list_controls(ui, index, entry_count, &mut action);
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Handler:");
+                    ui.add(egui::TextEdit::singleline(&mut item.handler).desired_width(110.0));
+                    ui.label("Shortcut:");
+                    ui.add(egui::TextEdit::singleline(&mut item.shortcut)
+                        .desired_width(80.0)
+                        .hint_text("Ctrl+S"));
+                    if !item.shortcut.trim().is_empty() && item.keyboard_shortcut().is_none() {
+                        ui.colored_label(egui::Color32::from_rgb(255, 100, 100), "⚠")
+                            .on_hover_text("Shortcut not recognized; use e.g. Ctrl+Shift+S");
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut item.checkable, "Checkable");
+                    if item.checkable {
+                        ui.checkbox(&mut item.checked, "Checked initially");
+                    }
+                });
+                ui.add_space(4.0);
+            }
+            MenuEntry::Separator => {
+                ui.horizontal(|ui| {
+                    ui.weak("── separator ──");
+                    list_controls(ui, index, entry_count, &mut action);
+                });
+            }
+            MenuEntry::Submenu { title, entries } => {
+                ui.horizontal(|ui| {
+                    ui.label("Submenu:");
+                    ui.add(egui::TextEdit::singleline(title).desired_width(110.0));
+                    list_controls(ui, index, entry_count, &mut action);
+                });
+                ui.indent("submenu", |ui| {
+                    render_entries_editor(ui, entries);
+                });
+            }
+        });
+    }
+    apply_list_action(entries, action);
+
+    ui.horizontal(|ui| {
+        if ui.small_button("➕ Item").clicked() {
+            entries.push(MenuEntry::Item(MenuItemDesign::new(&format!("Item {}", entries.len() + 1))));
+        }
+        if ui.small_button("➖ Separator").clicked() {
+            entries.push(MenuEntry::Separator);
+        }
+        if ui.small_button("📂 Submenu").clicked() {
+            entries.push(MenuEntry::Submenu {
+                title: "Submenu".to_string(),
+                entries: Vec::new(),
+            });
+        }
+    });
+}
+
+/// Flag designs that would generate code which does not compile or behaves surprisingly
+fn render_menu_warnings(ui: &mut Ui, menu_bar: &MenuBarDesign) {
+    let mut warnings = Vec::new();
+    let mut shortcuts = std::collections::HashMap::new();
+
+    for item in menu_bar.items() {
+        if !crate::codegen::is_rust_ident(&item.handler) {
+            warnings.push(format!("'{}' has an invalid handler name '{}'", item.label, item.handler));
+        }
+        if let Some(shortcut) = item.keyboard_shortcut() {
+            if let Some(other) = shortcuts.insert(shortcut, item.label.clone()) {
+                warnings.push(format!("'{}' and '{}' share the shortcut {}", other, item.label, item.shortcut));
+            }
+        }
+    }
+
+    if !warnings.is_empty() {
+        ui.add_space(8.0);
+        for warning in warnings {
+            ui.colored_label(egui::Color32::from_rgb(255, 200, 0), format!("⚠ {}", warning));
+        }
+    }
+}
@@ -2,8 +2,30 @@ pub mod rendering;
 pub mod tabs;
 pub mod interaction;
 pub mod distribution;
+pub mod page_bar;
+pub mod dock_editor;
+pub mod menu_editor;
+pub mod run_preview;
+pub mod variant_preview;
+pub mod style_editor;
+pub mod theme_editor;
+pub mod reflect_inspector;
+pub mod radio_group_editor;
+pub mod symbol_editor;
+pub mod template_gallery;
 
 pub use rendering::*;
 pub use tabs::render_tab_content;
 pub use interaction::*;
-pub use distribution::*;
\ No newline at end of file
+pub use distribution::*;
+pub use page_bar::*;
+pub use dock_editor::*;
+pub use menu_editor::*;
+pub use run_preview::*;
+pub use variant_preview::*;
+pub use style_editor::*;
+pub use theme_editor::*;
+pub use reflect_inspector::*;
+pub use radio_group_editor::*;
+pub use symbol_editor::*;
+pub use template_gallery::*;
\ No newline at end of file
@@ -0,0 +1,118 @@
+use bevy_ecs::prelude::*;
+use egui::Ui;
+use crate::components::*;
+use crate::utils::*;
+
+/// Row of page selectors shown above the design canvas
+pub fn render_page_bar(ui: &mut Ui, world: &mut World) {
+    let (pages, active_page) = {
+        let mut query = world.query::<&DesignPages>();
+        match query.iter(world).next() {
+            Some(pages) => (pages.pages.clone(), pages.active_page),
+            None => return,
+        }
+    };
+
+    let mut switch_to = None;
+    let mut add_page = false;
+    ui.horizontal(|ui| {
+        ui.label("📄 Page:");
+        for page in &pages {
+            if ui.selectable_label(page.id == active_page, &page.name).clicked() && page.id != active_page {
+                switch_to = Some(page.id);
+            }
+        }
+        if ui.small_button("➕ Page").on_hover_text("Add a new design page").clicked() {
+            add_page = true;
+        }
+    });
+
+    if let Some(page_id) = switch_to {
+        set_active_page(world, page_id);
+    }
+    if add_page {
+        let page_id = add_design_page(world);
+        set_active_page(world, page_id);
+    }
+}
+
+/// Show a page on the canvas. Selections on the previous page are cleared
+/// so group operations never touch hidden elements.
+pub fn set_active_page(world: &mut World, page_id: usize) {
+    let name = {
+        let mut query = world.query::<&mut DesignPages>();
+        let Some(mut pages) = query.iter_mut(world).next() else {
+            return;
+        };
+        if pages.page(page_id).is_none() {
+            return;
+        }
+        pages.active_page = page_id;
+        pages.page_name(page_id)
+    };
+    clear_all_selections(world);
+    add_designer_log(world, &format!("Switched to page '{}'", name));
+}
+
+/// Add a page and place it in the first tab group of the dock layout
+pub fn add_design_page(world: &mut World) -> usize {
+    let (page_id, name) = {
+        let mut query = world.query::<&mut DesignPages>();
+        let Some(mut pages) = query.iter_mut(world).next() else {
+            return 0;
+        };
+        let name = format!("Page {}", pages.next_id + 1);
+        (pages.add_page(name.clone()), name)
+    };
+
+    let mut layout_query = world.query::<&mut DockLayoutDesign>();
+    if let Some(mut layout) = layout_query.iter_mut(world).next() {
+        add_page_to_first_leaf(&mut layout.root, page_id);
+    }
+
+    add_designer_log(world, &format!("Added page '{}'", name));
+    page_id
+}
+
+fn add_page_to_first_leaf(node: &mut DockLayoutNode, page_id: usize) {
+    match node {
+        DockLayoutNode::Leaf { pages } => pages.push(page_id),
+        DockLayoutNode::Split { first, .. } => add_page_to_first_leaf(first, page_id),
+    }
+}
+
+/// Remove a page together with the elements placed on it. The last page cannot be removed.
+pub fn remove_design_page(world: &mut World, page_id: usize) {
+    let name = {
+        let mut query = world.query::<&mut DesignPages>();
+        let Some(mut pages) = query.iter_mut(world).next() else {
+            return;
+        };
+        if pages.pages.len() <= 1 || pages.page(page_id).is_none() {
+            return;
+        }
+        let name = pages.page_name(page_id);
+        pages.pages.retain(|page| page.id != page_id);
+        if pages.active_page == page_id {
+            pages.active_page = pages.pages[0].id;
+        }
+        name
+    };
+
+    let mut element_query = world.query_filtered::<(Entity, Option<&UiElementPage>), With<UiElementPosition>>();
+    let entities: Vec<Entity> = element_query.iter(world)
+        .filter(|(_, page)| is_on_page(*page, page_id))
+        .map(|(entity, _)| entity)
+        .collect();
+    let removed = entities.len();
+    for entity in entities {
+        world.despawn(entity);
+    }
+
+    let mut layout_query = world.query::<&mut DockLayoutDesign>();
+    if let Some(mut layout) = layout_query.iter_mut(world).next() {
+        layout.root.remove_page(page_id);
+    }
+
+    add_designer_log(world, &format!("Removed page '{}' and {} element(s)", name, removed));
+}
@@ -0,0 +1,175 @@
+use bevy_ecs::prelude::*;
+use egui::{Color32, Ui};
+use crate::codegen::{is_rust_ident, pascal_ident};
+use crate::components::*;
+use crate::utils::*;
+
+/// Change to a radio group requested while the editor was drawn
+enum RadioGroupChange {
+    Rename { from: String, to: String },
+    Move { group: usize, from: usize, to: usize },
+    SetDefault { group: usize, member: usize },
+    SetVariant { entity: Entity, variant: String },
+}
+
+/// Editor for the radio groups: member order, default member and enum variant names
+pub fn render_radio_group_panel(ui: &mut Ui, world: &mut World) {
+    ui.heading("🔘 Radio Groups");
+    ui.label("Radio buttons with the same group form a group. Each group is generated as an enum with one variant per button.");
+    ui.separator();
+
+    let groups = radio_groups(world);
+    if groups.is_empty() {
+        ui.weak("No radio buttons yet. Add some from the UI Elements menu.");
+        return;
+    }
+
+    let mut changes = Vec::new();
+    egui::ScrollArea::vertical().show(ui, |ui| {
+        for (index, group) in groups.iter().enumerate() {
+            ui.push_id(&group.id, |ui| render_radio_group(ui, index, group, &mut changes));
+            ui.add_space(8.0);
+        }
+    });
+
+    for change in changes {
+        apply_radio_group_change(world, &groups, change);
+    }
+}
+
+fn render_radio_group(ui: &mut Ui, index: usize, group: &RadioGroup, changes: &mut Vec<RadioGroupChange>) {
+    egui::Frame::group(ui.style()).show(ui, |ui| {
+        ui.horizontal(|ui| {
+            ui.label("Group:");
+            // Renames are applied when editing ends so the group keeps its place while typing
+            let buffer_id = ui.id().with("rename");
+            let mut name = ui.data(|data| data.get_temp::<String>(buffer_id)).unwrap_or_else(|| group.id.clone());
+            let response = ui.add(egui::TextEdit::singleline(&mut name).desired_width(160.0));
+            if response.changed() {
+                ui.data_mut(|data| data.insert_temp(buffer_id, name.clone()));
+            }
+            if response.lost_focus() {
+                ui.data_mut(|data| data.remove::<String>(buffer_id));
+                let name = name.trim();
+                if !name.is_empty() && name != group.id {
+                    changes.push(RadioGroupChange::Rename { from: group.id.clone(), to: name.to_string() });
+                }
+            }
+            ui.weak(format!("{} buttons", group.members.len()));
+        });
+
+        let default_index = group.default_index();
+        let idents = group.variant_idents();
+        egui::Grid::new("radio_group_members")
+            .num_columns(5)
+            .spacing([8.0, 4.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("");
+                ui.label("Default");
+                ui.label("Button");
+                ui.label("Variant");
+                ui.end_row();
+
+                let len = group.members.len();
+                for (position, (member, ident)) in group.members.iter().zip(&idents).enumerate() {
+                    ui.horizontal(|ui| {
+                        if ui.add_enabled(position > 0, egui::Button::new("⬆").small()).clicked() {
+                            changes.push(RadioGroupChange::Move { group: index, from: position, to: position - 1 });
+                        }
+                        if ui.add_enabled(position + 1 < len, egui::Button::new("⬇").small()).clicked() {
+                            changes.push(RadioGroupChange::Move { group: index, from: position, to: position + 1 });
+                        }
+                    });
+                    if ui.radio(position == default_index, "").clicked() && position != default_index {
+                        changes.push(RadioGroupChange::SetDefault { group: index, member: position });
+                    }
+                    ui.label(&member.label);
+
+                    let mut variant = member.variant.clone();
+                    let response = ui.add(
+                        egui::TextEdit::singleline(&mut variant)
+                            .hint_text(pascal_ident(&member.label))
+                            .desired_width(140.0),
+                    );
+                    if response.changed() {
+                        changes.push(RadioGroupChange::SetVariant { entity: member.entity, variant });
+                    }
+
+                    let entered = member.variant.trim();
+                    if *ident != member.variant_name() {
+                        ui.colored_label(Color32::from_rgb(255, 150, 0), format!("⚠ duplicate, generated as {}", ident));
+                    } else if !entered.is_empty() && !is_rust_ident(entered) {
+                        ui.colored_label(Color32::from_rgb(255, 150, 0), format!("⚠ generated as {}", ident));
+                    } else {
+                        ui.label("");
+                    }
+                    ui.end_row();
+                }
+            });
+
+        ui.label(
+            egui::RichText::new(format!(
+                "enum {} {{ {} }}  →  app.{}",
+                group.enum_name,
+                idents.join(", "),
+                group.field
+            ))
+            .monospace()
+            .weak(),
+        );
+    });
+}
+
+fn apply_radio_group_change(world: &mut World, groups: &[RadioGroup], change: RadioGroupChange) {
+    match change {
+        RadioGroupChange::Rename { from, to } => {
+            // Buttons joining an existing group go after its members
+            let offset = groups.iter().find(|group| group.id == to).map_or(0, |group| group.members.len());
+            let mut query = world.query::<&mut UiRadioButton>();
+            for mut radio in query.iter_mut(world) {
+                if radio.group_id == from {
+                    radio.group_id = to.clone();
+                    radio.order += offset;
+                }
+            }
+            if offset > 0 {
+                add_designer_log(world, &format!("🔘 Merged radio group '{}' into '{}'", from, to));
+            } else {
+                add_designer_log(world, &format!("🔘 Renamed radio group '{}' to '{}'", from, to));
+            }
+        }
+        RadioGroupChange::Move { group, from, to } => {
+            let Some(group) = groups.get(group) else {
+                return;
+            };
+            let mut members: Vec<Entity> = group.members.iter().map(|member| member.entity).collect();
+            if from < members.len() && to < members.len() {
+                members.swap(from, to);
+            }
+            for (order, entity) in members.into_iter().enumerate() {
+                if let Some(mut radio) = world.get_mut::<UiRadioButton>(entity) {
+                    radio.order = order;
+                }
+            }
+        }
+        RadioGroupChange::SetDefault { group, member } => {
+            let Some(group) = groups.get(group) else {
+                return;
+            };
+            for (position, other) in group.members.iter().enumerate() {
+                if let Some(mut radio) = world.get_mut::<UiRadioButton>(other.entity) {
+                    radio.selected = position == member;
+                }
+            }
+            if let Some(member) = group.members.get(member) {
+                add_designer_log(world, &format!("🔘 '{}' is now the default of radio group '{}'", member.label, group.id));
+            }
+        }
+        RadioGroupChange::SetVariant { entity, variant } => {
+            if let Some(mut radio) = world.get_mut::<UiRadioButton>(entity) {
+                radio.variant = variant;
+            }
+        }
+    }
+}
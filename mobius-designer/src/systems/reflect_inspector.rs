@@ -0,0 +1,310 @@
+use bevy_ecs::prelude::*;
+use bevy_ecs::reflect::{AppTypeRegistry, ReflectComponent};
+use bevy_reflect::attributes::CustomAttributes;
+use bevy_reflect::TypeRegistry;
+use bevy_reflect::{DynamicEnum, DynamicStruct, DynamicVariant, EnumInfo, PartialReflect, ReflectMut, ReflectRef, StructInfo, VariantInfo};
+use egui::Ui;
+use crate::components::*;
+
+/// Editors for every reflected component of `entity` that has an `InspectorSection`,
+/// built from the field types and their Inspector attributes
+pub fn render_reflected_components(ui: &mut Ui, world: &mut World, entity: Entity) {
+    let Some(registry) = world.get_resource::<AppTypeRegistry>().cloned() else {
+        return;
+    };
+    let registry = registry.read();
+
+    for (section, reflect_component, info) in inspector_sections(&registry) {
+        let Some(mut value) = reflect_component
+            .reflect(world.entity(entity))
+            .map(|component| component.to_dynamic())
+        else {
+            continue;
+        };
+
+        ui.separator();
+        ui.label(section.title);
+        let changed = ui.push_id(info.type_path(), |ui| edit_struct(ui, value.as_mut(), info)).inner;
+        if changed {
+            reflect_component.apply(world.entity_mut(entity), value.as_ref());
+        }
+    }
+}
+
+/// Editors for a multi-selection. Components every entity has get their own section;
+/// fields with the same name and type in the other components (`label`, `font_size`, ...)
+/// are collected under "Common Properties". Fields whose values differ show a mixed
+/// placeholder, and an edited field is written to every entity.
+pub fn render_shared_reflected_components(ui: &mut Ui, world: &mut World, entities: &[Entity]) {
+    let Some(registry) = world.get_resource::<AppTypeRegistry>().cloned() else {
+        return;
+    };
+    let registry = registry.read();
+    let sections = inspector_sections(&registry);
+
+    let mut common: Option<Vec<SharedField>> = None;
+    for entity in entities {
+        let mut fields = Vec::new();
+        for (_, reflect_component, info) in &sections {
+            if entities.iter().all(|entity| reflect_component.contains(world.entity(*entity))) {
+                continue;
+            }
+            if !reflect_component.contains(world.entity(*entity)) {
+                continue;
+            }
+            for field in info.iter() {
+                if field.has_attribute::<InspectorHidden>() || fields.iter().any(|shared: &SharedField| shared.name == field.name()) {
+                    continue;
+                }
+                fields.push(SharedField {
+                    name: field.name(),
+                    type_id: field.type_id(),
+                    attributes: field.custom_attributes(),
+                    components: vec![(*entity, *reflect_component)],
+                });
+            }
+        }
+        common = Some(match common {
+            None => fields,
+            Some(previous) => previous
+                .into_iter()
+                .filter_map(|mut shared| {
+                    let field = fields.iter().find(|field| field.name == shared.name && field.type_id == shared.type_id)?;
+                    shared.components.extend(field.components.iter().copied());
+                    Some(shared)
+                })
+                .collect(),
+        });
+    }
+
+    for (section, reflect_component, info) in &sections {
+        if !entities.iter().all(|entity| reflect_component.contains(world.entity(*entity))) {
+            continue;
+        }
+        let fields: Vec<SharedField> = info
+            .iter()
+            .filter(|field| !field.has_attribute::<InspectorHidden>())
+            .map(|field| SharedField {
+                name: field.name(),
+                type_id: field.type_id(),
+                attributes: field.custom_attributes(),
+                components: entities.iter().map(|entity| (*entity, *reflect_component)).collect(),
+            })
+            .collect();
+
+        ui.separator();
+        ui.label(section.title);
+        ui.push_id(info.type_path(), |ui| edit_shared_fields(ui, world, &fields));
+    }
+
+    let common = common.unwrap_or_default();
+    if !common.is_empty() {
+        ui.separator();
+        ui.label("🧩 Common Properties");
+        ui.push_id("common_properties", |ui| edit_shared_fields(ui, world, &common));
+    }
+}
+
+/// A field edited on several entities at once, with the component holding it on each entity
+struct SharedField<'a> {
+    name: &'static str,
+    type_id: std::any::TypeId,
+    attributes: &'a CustomAttributes,
+    components: Vec<(Entity, &'a ReflectComponent)>,
+}
+
+fn edit_shared_fields(ui: &mut Ui, world: &mut World, fields: &[SharedField]) {
+    egui::Grid::new("shared_fields")
+        .num_columns(2)
+        .spacing([8.0, 4.0])
+        .show(ui, |ui| {
+            for field in fields {
+                let values: Vec<Box<dyn PartialReflect>> = field
+                    .components
+                    .iter()
+                    .filter_map(|(entity, reflect_component)| {
+                        let component = reflect_component.reflect(world.entity(*entity))?;
+                        let ReflectRef::Struct(fields) = component.reflect_ref() else {
+                            return None;
+                        };
+                        fields.field(field.name).map(|value| value.to_dynamic())
+                    })
+                    .collect();
+                let Some(mut value) = values.first().map(|value| value.to_dynamic()) else {
+                    continue;
+                };
+                let mixed = values
+                    .iter()
+                    .skip(1)
+                    .any(|other| value.reflect_partial_eq(other.as_ref()) != Some(true));
+
+                ui.label(format!("{}:", field_label(field.name)));
+                let changed = ui.push_id(field.name, |ui| edit_value(ui, value.as_mut(), field.attributes, mixed)).inner;
+                ui.end_row();
+
+                if changed {
+                    let mut patch = DynamicStruct::default();
+                    patch.insert_boxed(field.name, value);
+                    for (entity, reflect_component) in &field.components {
+                        reflect_component.apply(world.entity_mut(*entity), &patch);
+                    }
+                }
+            }
+        });
+}
+
+/// Reflected components with an `InspectorSection`, in section order
+fn inspector_sections(registry: &TypeRegistry) -> Vec<(&InspectorSection, &ReflectComponent, &StructInfo)> {
+    let mut sections: Vec<(&InspectorSection, &ReflectComponent, &StructInfo)> = registry
+        .iter()
+        .filter_map(|registration| {
+            let reflect_component = registration.data::<ReflectComponent>()?;
+            let info = registration.type_info().as_struct().ok()?;
+            let section = info.get_attribute::<InspectorSection>()?;
+            Some((section, reflect_component, info))
+        })
+        .collect();
+    sections.sort_by_key(|(section, _, info)| (section.order, info.type_path()));
+    sections
+}
+
+/// Editor grid for the visible fields of a reflected struct; returns whether anything changed
+fn edit_struct(ui: &mut Ui, value: &mut dyn PartialReflect, info: &StructInfo) -> bool {
+    let ReflectMut::Struct(fields) = value.reflect_mut() else {
+        return false;
+    };
+
+    let mut changed = false;
+    egui::Grid::new("reflected_fields")
+        .num_columns(2)
+        .spacing([8.0, 4.0])
+        .show(ui, |ui| {
+            for index in 0..fields.field_len() {
+                let Some(name) = fields.name_at(index).map(str::to_string) else {
+                    continue;
+                };
+                let Some(field_info) = info.field(&name) else {
+                    continue;
+                };
+                if field_info.has_attribute::<InspectorHidden>() {
+                    continue;
+                }
+                let Some(field) = fields.field_at_mut(index) else {
+                    continue;
+                };
+                ui.label(format!("{}:", field_label(&name)));
+                changed |= ui.push_id(&name, |ui| edit_value(ui, field, field_info.custom_attributes(), false)).inner;
+                ui.end_row();
+            }
+        });
+    changed
+}
+
+/// Editor chosen from the value's type; types without an editor are shown read-only.
+/// A `mixed` value stands for differing values and is shown as a placeholder until edited.
+fn edit_value(ui: &mut Ui, value: &mut dyn PartialReflect, attributes: &CustomAttributes, mixed: bool) -> bool {
+    if let Some(text) = value.try_downcast_mut::<String>() {
+        if mixed {
+            let mut edited = String::new();
+            let changed = ui.add(egui::TextEdit::singleline(&mut edited).hint_text(MIXED)).changed();
+            if changed {
+                *text = edited;
+            }
+            return changed;
+        }
+        return ui.text_edit_singleline(text).changed();
+    }
+    if let Some(flag) = value.try_downcast_mut::<bool>() {
+        return ui.add(egui::Checkbox::without_text(flag).indeterminate(mixed)).changed();
+    }
+    if let Some(number) = value.try_downcast_mut::<f32>() {
+        return edit_number(ui, number, attributes, mixed);
+    }
+    if let Some(number) = value.try_downcast_mut::<f64>() {
+        return edit_number(ui, number, attributes, mixed);
+    }
+    if let Some(number) = value.try_downcast_mut::<i32>() {
+        return edit_number(ui, number, attributes, mixed);
+    }
+    if let Some(number) = value.try_downcast_mut::<u32>() {
+        return edit_number(ui, number, attributes, mixed);
+    }
+    if let Some(number) = value.try_downcast_mut::<usize>() {
+        return edit_number(ui, number, attributes, mixed);
+    }
+
+    if let Some(info) = value.get_represented_type_info().and_then(|info| info.as_enum().ok()) {
+        if info.iter().all(|variant| matches!(variant, VariantInfo::Unit(_))) {
+            return edit_unit_enum(ui, value, info, mixed);
+        }
+    }
+    if let Some(info) = value.get_represented_type_info().and_then(|info| info.as_struct().ok()) {
+        return ui.vertical(|ui| {
+            if mixed {
+                ui.weak(MIXED);
+            }
+            edit_struct(ui, value, info)
+        }).inner;
+    }
+    ui.weak(if mixed { MIXED.to_string() } else { format!("{:?}", value) });
+    false
+}
+
+/// Combo box choosing a variant of an enum without fields
+fn edit_unit_enum(ui: &mut Ui, value: &mut dyn PartialReflect, info: &EnumInfo, mixed: bool) -> bool {
+    let ReflectRef::Enum(current) = value.reflect_ref() else {
+        return false;
+    };
+    let current = current.variant_name().to_string();
+
+    let mut chosen = None;
+    egui::ComboBox::from_id_salt("enum_variant")
+        .selected_text(if mixed { MIXED } else { current.as_str() })
+        .show_ui(ui, |ui| {
+            for variant in info.iter() {
+                if ui.selectable_label(!mixed && variant.name() == current, variant.name()).clicked() {
+                    chosen = Some(variant.name());
+                }
+            }
+        });
+    let Some(name) = chosen else {
+        return false;
+    };
+    value.apply(&DynamicEnum::new(name, DynamicVariant::Unit));
+    true
+}
+
+/// Placeholder for a field whose value differs between the selected elements
+pub(crate) const MIXED: &str = "— mixed —";
+
+fn edit_number<N: egui::emath::Numeric>(ui: &mut Ui, value: &mut N, attributes: &CustomAttributes, mixed: bool) -> bool {
+    // Values outside the range, like the 0 of an automatic size, are shown as they are
+    // rather than clamped, which would write the clamped value back just by showing it
+    let mut drag = egui::DragValue::new(value).clamp_existing_to_range(false);
+    if let Some(range) = attributes.get::<InspectorRange>() {
+        drag = drag.range(N::from_f64(range.min)..=N::from_f64(range.max));
+    }
+    if mixed {
+        drag = drag.custom_formatter(|_, _| MIXED.to_string());
+    } else if let Some(unit) = attributes.get::<InspectorUnit>() {
+        drag = drag.suffix(format!(" {}", unit.0));
+    }
+    let response = ui.add(drag);
+    // Only edits by dragging or typing count
+    response.changed() && (response.dragged() || response.has_focus() || response.lost_focus())
+}
+
+/// `font_size` -> `Font Size`
+fn field_label(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -4,6 +4,8 @@ use mobius_ecs::*;
 use crate::components::*;
 use crate::integration::*;
 use crate::utils::*;
+use crate::widgets::{assign_container, container_descendants, current_widgets, is_element_shown, DesignerWidget, WidgetEvents};
+use std::sync::Arc;
 // use crate::resources::{LengthUnit, Tool}; // Now handled in tabs.rs
 
 pub fn render_dynamic_ui_elements(
@@ -19,11 +21,14 @@ pub fn render_dynamic_ui_elements(
     let mut updates = Vec::new();
     let mut log_messages = Vec::new();
     
-    render_buttons(ui, world, grid_settings, &mut updates, &mut log_messages, renaming_entity, rename_buffer, resizing_entity);
-    render_text_inputs(ui, world, grid_settings, &mut updates, &mut log_messages, resizing_entity);
-    render_checkboxes(ui, world, grid_settings, &mut updates, &mut log_messages, resizing_entity);
-    render_radio_buttons(ui, world, grid_settings, &mut updates, &mut log_messages, resizing_entity);
-    render_group_boxes(ui, world, grid_settings, &mut updates, &mut log_messages, resizing_entity);
+    // Only elements on the active design page are shown on the canvas
+    let active_page = active_page_id(world);
+    
+    let mut editing = CanvasEditing { renaming_entity, rename_buffer, resizing_entity };
+    let mut events = WidgetEvents::new(&mut updates, &mut log_messages);
+    for widget in current_widgets(world).iter() {
+        render_widget_elements(ui, world, widget, grid_settings, active_page, &mut editing, &mut events);
+    }
     
     apply_updates(world, updates);
     
@@ -36,119 +41,102 @@ pub fn render_dynamic_ui_elements(
     draw_resize_handles(ui, world);
 }
 
-fn render_buttons(
+/// Element being renamed or resized on the canvas, kept by the tab viewer between frames
+struct CanvasEditing<'a> {
+    renaming_entity: &'a mut Option<Entity>,
+    rename_buffer: &'a mut String,
+    resizing_entity: &'a mut Option<Entity>,
+}
+
+/// Draw the elements of one widget type with the design-mode interactions every element shares:
+/// dragging, renaming, resizing and the context menu
+fn render_widget_elements(
     ui: &mut Ui,
     world: &mut World,
+    widget: &Arc<dyn DesignerWidget>,
     grid_settings: &GridSettings,
-    updates: &mut Vec<Box<dyn FnOnce(&mut World) + Send>>,
-    log_messages: &mut Vec<String>,
-    renaming_entity: &mut Option<Entity>,
-    rename_buffer: &mut String,
-    resizing_entity: &mut Option<Entity>,
+    active_page: usize,
+    editing: &mut CanvasEditing,
+    events: &mut WidgetEvents,
 ) {
-    let mut query = world.query::<(Entity, &UiButton, &UiElementPosition, &UiElementSize, &UiElementSelected)>();
-    let buttons: Vec<_> = query.iter(world).map(|(e, b, p, s, sel)| (e, b.clone(), p.clone(), s.clone(), sel.clone())).collect();
+    let style_sheet = current_style_sheet(world);
+    let widgets = current_widgets(world);
+    let mut query = world.query::<(Entity, &UiElementPosition, &UiElementSelected, Option<&UiElementPage>, Option<&UiElementStyle>)>();
+    let elements: Vec<_> = query.iter(world)
+        .filter(|(e, .., page, _)| is_on_page(*page, active_page) && widget.is_instance(world.entity(*e)))
+        // Children of collapsed containers and of their other tabs are hidden
+        .filter(|(e, ..)| is_element_shown(world, &widgets, *e, None))
+        .map(|(e, p, sel, _, style)| (e, p.clone(), sel.clone(), style_sheet.resolve(style)))
+        .collect();
     
-    for (entity, button, pos, size, selected) in buttons {
-        let area_id = Id::new(format!("button_area_{:?}", entity));
+    for (entity, pos, selected, style) in elements {
+        let element = world.entity(entity);
+        let label = widget.label(element);
+        // Copies shown by a component instance move with the instance and can't be edited themselves
+        let copy_of = world.get::<UiSymbolCopy>(entity).map(|copy| copy.instance);
+        let drag_target = copy_of.unwrap_or(entity);
+        let area_id = Id::new(format!("{}_area_{:?}", widget.kind(), entity));
+        // Containers stay behind the elements placed on them
         let area_response = Area::new(area_id)
-            .order(Order::Middle)
+            .order(if widget.is_container() { Order::Background } else { Order::Middle })
             .fixed_pos(Pos2::new(pos.x, pos.y))
             .show(ui.ctx(), |ui| {
                 // Always show edit frame in design mode
                 create_edit_frame(ui, selected.selected);
                 
-                let button_response = if *renaming_entity == Some(entity) {
+                if *editing.renaming_entity == Some(entity) {
                     // Show text input for renaming
-                    let response = ui.text_edit_singleline(rename_buffer);
+                    let response = ui.text_edit_singleline(editing.rename_buffer);
                     if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
                         // Finish renaming
-                        let entity_copy = entity;
-                        let new_label = rename_buffer.clone();
-                        log_messages.push(format!("Button renamed to '{}'", new_label));
-                        updates.push(Box::new(move |world: &mut World| {
-                            if let Some(mut btn) = world.get_mut::<UiButton>(entity_copy) {
-                                btn.label = new_label;
+                        let widget = widget.clone();
+                        let new_label = editing.rename_buffer.clone();
+                        events.log(format!("{} renamed to '{}'", widget.display_name(), new_label));
+                        events.update(move |world: &mut World| {
+                            if let Ok(mut element) = world.get_entity_mut(entity) {
+                                widget.set_label(&mut element, new_label);
                             }
-                        }));
-                        *renaming_entity = None;
-                        rename_buffer.clear();
+                        });
+                        *editing.renaming_entity = None;
+                        editing.rename_buffer.clear();
                     } else if response.lost_focus() {
                         // Cancel renaming
-                        *renaming_entity = None;
-                        rename_buffer.clear();
+                        *editing.renaming_entity = None;
+                        editing.rename_buffer.clear();
                     }
                     if response.gained_focus() {
                         response.request_focus();
                     }
                     response
                 } else {
-                    // Show normal button
-                    let button_text = if button.font_size > 0.0 {
-                        egui::RichText::new(&button.label).size(button.font_size)
-                    } else {
-                        egui::RichText::new(&button.label)
-                    };
-                    
-                    let btn_response = if size.width > 0.0 || size.height > 0.0 {
-                        // Manual button rendering with exact size
-                        let actual_width = if size.width > 0.0 { size.width } else { 80.0 };
-                        let actual_height = if size.height > 0.0 { size.height } else { 25.0 };
-                        
-                        let desired_size = Vec2::new(actual_width, actual_height);
-                        let (rect, response) = ui.allocate_exact_size(desired_size, egui::Sense::click());
-                        
-                        if ui.is_rect_visible(rect) {
-                            let visuals = ui.style().interact(&response);
-                            
-                            // Draw button background
-                            ui.painter().rect_filled(rect, visuals.corner_radius, visuals.bg_fill);
-                            ui.painter().rect_stroke(rect, visuals.corner_radius, visuals.bg_stroke, egui::StrokeKind::Outside);
-                            
-                            // Draw text centered using galley for proper layout
-                            let text_color = if button.enabled { visuals.text_color() } else { ui.style().visuals.weak_text_color() };
-                            let font_id = egui::FontId::proportional(button.font_size);
-                            let galley = ui.fonts(|f| f.layout_no_wrap(button.label.clone(), font_id, text_color));
-                            let text_pos = rect.center() - galley.size() * 0.5;
-                            ui.painter().galley(text_pos, galley, text_color);
-                        }
-                        
-                        response
+                    let response = if widget.draws_own_frame() {
+                        widget.render_design(ui, element, &style, events)
                     } else {
-                        ui.add_enabled_ui(button.enabled, |ui| {
-                            ui.button(button_text)
-                        }).inner
+                        style.show_framed(ui, |ui| widget.render_design(ui, element, &style, events)).inner
                     };
-                    
-                    if button.clicked {
-                        ui.colored_label(Color32::GREEN, "✓");
-                    }
-                    
-                    btn_response
-                };
-                
-                button_response
+                    with_tooltip(response, &style)
+                }
             });
         
-        // Always enabled in design mode
-        if true {
-            // Handle dragging directly on the button response
-            let drag_response = area_response.inner.interact(egui::Sense::drag());
-            if drag_response.dragged() {
-                let delta = drag_response.drag_delta();
-                let snap_enabled = grid_settings.snap_enabled;
-                let spacing = grid_settings.spacing_pixels;
-                
-                // If this element is selected, move all selected elements
-                if selected.selected {
-                    updates.push(Box::new(move |world: &mut World| {
-                        move_selected_elements(world, delta, snap_enabled, spacing);
-                    }));
-                } else {
-                    // Move only this element
-                    let entity_copy = entity;
-                    updates.push(Box::new(move |world: &mut World| {
-                        if let Some(mut pos) = world.get_mut::<UiElementPosition>(entity_copy) {
+        // Handle dragging directly on the element response
+        let drag_response = area_response.inner.interact(egui::Sense::drag());
+        if drag_response.dragged() {
+            let delta = drag_response.drag_delta();
+            let snap_enabled = grid_settings.snap_enabled;
+            let spacing = grid_settings.spacing_pixels;
+            
+            // If this element is selected, move all selected elements
+            if selected.selected {
+                events.update(move |world: &mut World| {
+                    move_selected_elements(world, delta, snap_enabled, spacing);
+                });
+            } else {
+                // Move only this element, along with anything placed in it
+                events.update(move |world: &mut World| {
+                    let mut moved = container_descendants(world, drag_target);
+                    moved.push(drag_target);
+                    for moved_entity in moved {
+                        if let Some(mut pos) = world.get_mut::<UiElementPosition>(moved_entity) {
                             let new_pos = Pos2::new(pos.x + delta.x, pos.y + delta.y);
                             let final_pos = if snap_enabled {
                                 snap_to_grid(new_pos, spacing)
@@ -158,382 +146,59 @@ fn render_buttons(
                             pos.x = final_pos.x;
                             pos.y = final_pos.y;
                         }
-                    }));
-                }
-            }
-            if drag_response.hovered() {
-                ui.ctx().set_cursor_icon(egui::CursorIcon::Grab);
-            }
-            
-            // Right-click context menu  
-            area_response.inner.context_menu(|ui| {
-                ui.label(format!("Button: {}", button.label));
-                ui.separator();
-                
-                if ui.button("✏️ Rename").clicked() {
-                    // Start rename mode
-                    *renaming_entity = Some(entity);
-                    *rename_buffer = button.label.clone();
-                    ui.close_kind(egui::UiKind::Menu);
-                }
-                
-                let resize_text = if *resizing_entity == Some(entity) { "🔒 Stop Resize" } else { "📏 Resize" };
-                if ui.button(resize_text).clicked() {
-                    *resizing_entity = if *resizing_entity == Some(entity) { None } else { Some(entity) };
-                    ui.close_kind(egui::UiKind::Menu);
-                }
-                
-                if ui.button("🗑️ Delete").clicked() {
-                    let entity_copy = entity;
-                    let button_label = button.label.clone();
-                    log_messages.push(format!("Button '{}' deleted", button_label));
-                    updates.push(Box::new(move |world: &mut World| {
-                        world.despawn(entity_copy);
-                    }));
-                    ui.close_kind(egui::UiKind::Menu);
-                }
-            });
-            
-            // Show resize handles if this entity is being resized
-            if *resizing_entity == Some(entity) {
-                draw_resize_handles_for_entity(ui, area_response.inner.rect, updates, entity);
-            }
-        } else if area_response.inner.clicked() {
-            // Always enabled in design mode
-        if true {
-                // In edit mode, clicking selects/deselects the element
-                let entity_copy = entity;
-                updates.push(Box::new(move |world: &mut World| {
-                    if let Some(mut selected) = world.get_mut::<UiElementSelected>(entity_copy) {
-                        // Toggle selection, or if Ctrl is held, add to selection
-                        selected.selected = !selected.selected;
-                    }
-                }));
-            } else if !button.clicked {
-                // In view mode, clicking activates the button
-                let entity_copy = entity;
-                let button_label = button.label.clone();
-                log_messages.push(format!("Button '{}' clicked", button_label));
-                updates.push(Box::new(move |world: &mut World| {
-                    if let Some(mut button) = world.get_mut::<UiButton>(entity_copy) {
-                        button.clicked = true;
-                        button.click_time = Some(std::time::Instant::now());
                     }
-                }));
+                });
             }
         }
-    }
-}
-
-fn render_text_inputs(
-    ui: &mut Ui,
-    world: &mut World,
-    grid_settings: &GridSettings,
-    updates: &mut Vec<Box<dyn FnOnce(&mut World) + Send>>,
-    log_messages: &mut Vec<String>,
-    _resizing_entity: &mut Option<Entity>,
-) {
-    let mut query = world.query::<(Entity, &UiTextInput, &UiElementPosition, &UiElementSize, &UiElementSelected)>();
-    let inputs: Vec<_> = query.iter(world).map(|(e, t, p, s, sel)| (e, t.clone(), p.clone(), s.clone(), sel.clone())).collect();
-    
-    for (entity, text_input, pos, size, selected) in inputs {
-        let area_id = Id::new(format!("text_input_area_{:?}", entity));
-        let area_response = Area::new(area_id)
-            .order(Order::Middle)
-            .fixed_pos(Pos2::new(pos.x, pos.y))
-            .show(ui.ctx(), |ui| {
-                // Always show edit frame in design mode
-                create_edit_frame(ui, selected.selected);
-                
-                let response = ui.vertical(|ui| {
-                    let label_text = if text_input.font_size > 0.0 {
-                        egui::RichText::new(&text_input.label).size(text_input.font_size)
-                    } else {
-                        egui::RichText::new(&text_input.label)
-                    };
-                    ui.label(label_text);
-                    
-                    let mut value = text_input.value.clone();
-                    let text_edit = egui::TextEdit::singleline(&mut value)
-                        .desired_width(size.width.max(100.0));
-                    
-                    let response = ui.add_enabled_ui(text_input.enabled, |ui| {
-                        ui.add(text_edit)
-                    }).inner;
-                    
-                    if response.changed() {
-                        let entity_copy = entity;
-                        let input_label = text_input.label.clone();
-                        let new_value = value.clone();
-                        log_messages.push(format!("Text input '{}' changed to '{}'", input_label, new_value));
-                        updates.push(Box::new(move |world: &mut World| {
-                            if let Some(mut input) = world.get_mut::<UiTextInput>(entity_copy) {
-                                input.value = value;
-                            }
-                        }));
+        if drag_response.drag_stopped() {
+            // Dropping an element on a container puts it in the container
+            let selected_drag = selected.selected;
+            events.update(move |world: &mut World| {
+                let dropped = if selected_drag { selected_entities(world) } else { vec![drag_target] };
+                for dropped_entity in dropped {
+                    if assign_container(world, dropped_entity) {
+                        log_container_change(world, dropped_entity);
                     }
-                    
-                    response
-                }).inner;
-                
-                response
-            });
-        
-        // Always enabled in design mode
-        if true {
-            // Handle dragging directly on the element response
-            let drag_response = area_response.inner.interact(egui::Sense::drag());
-            if drag_response.dragged() {
-                let delta = drag_response.drag_delta();
-                let snap_enabled = grid_settings.snap_enabled;
-                let spacing = grid_settings.spacing_pixels;
-                
-                // If this element is selected, move all selected elements
-                if selected.selected {
-                    updates.push(Box::new(move |world: &mut World| {
-                        move_selected_elements(world, delta, snap_enabled, spacing);
-                    }));
-                } else {
-                    // Move only this element
-                    let entity_copy = entity;
-                    updates.push(Box::new(move |world: &mut World| {
-                        if let Some(mut pos) = world.get_mut::<UiElementPosition>(entity_copy) {
-                            let new_pos = Pos2::new(pos.x + delta.x, pos.y + delta.y);
-                            pos.x = new_pos.x;
-                            pos.y = new_pos.y;
-                        }
-                    }));
                 }
-            }
-            if drag_response.hovered() {
-                ui.ctx().set_cursor_icon(egui::CursorIcon::Grab);
-            }
-        }
-    }
-}
-
-fn render_checkboxes(
-    ui: &mut Ui,
-    world: &mut World,
-    grid_settings: &GridSettings,
-    updates: &mut Vec<Box<dyn FnOnce(&mut World) + Send>>,
-    log_messages: &mut Vec<String>,
-    _resizing_entity: &mut Option<Entity>,
-) {
-    let mut query = world.query::<(Entity, &UiCheckbox, &UiElementPosition, &UiElementSize, &UiElementSelected)>();
-    let checkboxes: Vec<_> = query.iter(world).map(|(e, c, p, s, sel)| (e, c.clone(), p.clone(), s.clone(), sel.clone())).collect();
-    
-    for (entity, checkbox, pos, _size, selected) in checkboxes {
-        let area_id = Id::new(format!("checkbox_area_{:?}", entity));
-        let area_response = Area::new(area_id)
-            .order(Order::Middle)
-            .fixed_pos(Pos2::new(pos.x, pos.y))
-            .show(ui.ctx(), |ui| {
-                // Always show edit frame in design mode
-                create_edit_frame(ui, selected.selected);
-                
-                let mut checked = checkbox.checked;
-                let label_text = if checkbox.font_size > 0.0 {
-                    egui::RichText::new(&checkbox.label).size(checkbox.font_size)
-                } else {
-                    egui::RichText::new(&checkbox.label)
-                };
-                
-                let response = ui.add_enabled_ui(checkbox.enabled, |ui| {
-                    ui.checkbox(&mut checked, label_text)
-                }).inner;
-                
-                if response.changed() {
-                    let entity_copy = entity;
-                    let checkbox_label = checkbox.label.clone();
-                    log_messages.push(format!("Checkbox '{}' {}", checkbox_label, if checked { "checked" } else { "unchecked" }));
-                    updates.push(Box::new(move |world: &mut World| {
-                        if let Some(mut cb) = world.get_mut::<UiCheckbox>(entity_copy) {
-                            cb.checked = checked;
-                        }
-                    }));
-                }
-                
-                response
             });
-        
-        // Always enabled in design mode
-        if true {
-            // Handle dragging directly on the element response
-            let drag_response = area_response.inner.interact(egui::Sense::drag());
-            if drag_response.dragged() {
-                let delta = drag_response.drag_delta();
-                let snap_enabled = grid_settings.snap_enabled;
-                let spacing = grid_settings.spacing_pixels;
-                
-                // If this element is selected, move all selected elements
-                if selected.selected {
-                    updates.push(Box::new(move |world: &mut World| {
-                        move_selected_elements(world, delta, snap_enabled, spacing);
-                    }));
-                } else {
-                    // Move only this element
-                    let entity_copy = entity;
-                    updates.push(Box::new(move |world: &mut World| {
-                        if let Some(mut pos) = world.get_mut::<UiElementPosition>(entity_copy) {
-                            let new_pos = Pos2::new(pos.x + delta.x, pos.y + delta.y);
-                            pos.x = new_pos.x;
-                            pos.y = new_pos.y;
-                        }
-                    }));
-                }
-            }
-            if drag_response.hovered() {
-                ui.ctx().set_cursor_icon(egui::CursorIcon::Grab);
-            }
         }
-    }
-}
-
-fn render_radio_buttons(
-    ui: &mut Ui,
-    world: &mut World,
-    grid_settings: &GridSettings,
-    updates: &mut Vec<Box<dyn FnOnce(&mut World) + Send>>,
-    log_messages: &mut Vec<String>,
-    _resizing_entity: &mut Option<Entity>,
-) {
-    let mut query = world.query::<(Entity, &UiRadioButton, &UiElementPosition, &UiElementSize, &UiElementSelected)>();
-    let radio_buttons: Vec<_> = query.iter(world).map(|(e, r, p, s, sel)| (e, r.clone(), p.clone(), s.clone(), sel.clone())).collect();
-    
-    for (entity, radio_button, pos, _size, selected) in radio_buttons {
-        let area_id = Id::new(format!("radio_area_{:?}", entity));
-        let area_response = Area::new(area_id)
-            .order(Order::Middle)
-            .fixed_pos(Pos2::new(pos.x, pos.y))
-            .show(ui.ctx(), |ui| {
-                // Always show edit frame in design mode
-                create_edit_frame(ui, selected.selected);
-                
-                let label_text = if radio_button.font_size > 0.0 {
-                    egui::RichText::new(&radio_button.label).size(radio_button.font_size)
-                } else {
-                    egui::RichText::new(&radio_button.label)
-                };
-                
-                let response = ui.add_enabled_ui(radio_button.enabled, |ui| {
-                    ui.radio_value(&mut true, radio_button.selected, label_text)
-                }).inner;
-                
-                if response.clicked() && !radio_button.selected {
-                    let group_id = radio_button.group_id.clone();
-                    let entity_copy = entity;
-                    let radio_label = radio_button.label.clone();
-                    log_messages.push(format!("Radio button '{}' selected", radio_label));
-                    updates.push(Box::new(move |world: &mut World| {
-                        let mut radio_query = world.query::<(Entity, &mut UiRadioButton)>();
-                        for (e, mut rb) in radio_query.iter_mut(world) {
-                            if rb.group_id == group_id {
-                                rb.selected = e == entity_copy;
-                            }
-                        }
-                    }));
-                }
-                
-                response
-            });
-        
-        // Always enabled in design mode
-        if true {
-            // Handle dragging directly on the element response
-            let drag_response = area_response.inner.interact(egui::Sense::drag());
-            if drag_response.dragged() {
-                let delta = drag_response.drag_delta();
-                let snap_enabled = grid_settings.snap_enabled;
-                let spacing = grid_settings.spacing_pixels;
-                
-                // If this element is selected, move all selected elements
-                if selected.selected {
-                    updates.push(Box::new(move |world: &mut World| {
-                        move_selected_elements(world, delta, snap_enabled, spacing);
-                    }));
-                } else {
-                    // Move only this element
-                    let entity_copy = entity;
-                    updates.push(Box::new(move |world: &mut World| {
-                        if let Some(mut pos) = world.get_mut::<UiElementPosition>(entity_copy) {
-                            let new_pos = Pos2::new(pos.x + delta.x, pos.y + delta.y);
-                            pos.x = new_pos.x;
-                            pos.y = new_pos.y;
-                        }
-                    }));
-                }
-            }
-            if drag_response.hovered() {
-                ui.ctx().set_cursor_icon(egui::CursorIcon::Grab);
-            }
+        if drag_response.hovered() {
+            ui.ctx().set_cursor_icon(egui::CursorIcon::Grab);
         }
-    }
-}
-
-fn render_group_boxes(
-    ui: &mut Ui,
-    world: &mut World,
-    grid_settings: &GridSettings,
-    updates: &mut Vec<Box<dyn FnOnce(&mut World) + Send>>,
-    _log_messages: &mut Vec<String>,
-    _resizing_entity: &mut Option<Entity>,
-) {
-    let mut query = world.query::<(Entity, &UiGroupBox, &UiElementPosition, &UiElementSize, &UiElementSelected)>();
-    let group_boxes: Vec<_> = query.iter(world).map(|(e, g, p, s, sel)| (e, g.clone(), p.clone(), s.clone(), sel.clone())).collect();
-    
-    for (entity, group_box, pos, size, selected) in group_boxes {
-        let area_id = Id::new(format!("groupbox_area_{:?}", entity));
-        let area_response = Area::new(area_id)
-            .order(Order::Middle)
-            .fixed_pos(Pos2::new(pos.x, pos.y))
-            .show(ui.ctx(), |ui| {
-                // Always show edit frame in design mode
-                create_edit_frame(ui, selected.selected);
-                
-                let title_text = if group_box.font_size > 0.0 {
-                    egui::RichText::new(&group_box.label).size(group_box.font_size)
-                } else {
-                    egui::RichText::new(&group_box.label)
-                };
-                
-                let response = ui.group(|ui| {
-                    ui.set_min_size(Vec2::new(size.width.max(100.0), size.height.max(50.0)));
-                    ui.label(title_text)
-                }).response;
-                
-                response
-            });
         
-        // Always enabled in design mode
-        if true {
-            // Handle dragging directly on the element response
-            let drag_response = area_response.inner.interact(egui::Sense::drag());
-            if drag_response.dragged() {
-                let delta = drag_response.drag_delta();
-                let snap_enabled = grid_settings.snap_enabled;
-                let spacing = grid_settings.spacing_pixels;
-                
-                // If this element is selected, move all selected elements
-                if selected.selected {
-                    updates.push(Box::new(move |world: &mut World| {
-                        move_selected_elements(world, delta, snap_enabled, spacing);
-                    }));
-                } else {
-                    // Move only this element
-                    let entity_copy = entity;
-                    updates.push(Box::new(move |world: &mut World| {
-                        if let Some(mut pos) = world.get_mut::<UiElementPosition>(entity_copy) {
-                            let new_pos = Pos2::new(pos.x + delta.x, pos.y + delta.y);
-                            pos.x = new_pos.x;
-                            pos.y = new_pos.y;
-                        }
-                    }));
-                }
+        // Right-click context menu
+        if copy_of.is_some() {
+            continue;
+        }
+        area_response.inner.context_menu(|ui| {
+            ui.label(format!("{}: {}", widget.display_name(), label));
+            ui.separator();
+            
+            if ui.button("✏️ Rename").clicked() {
+                // Start rename mode
+                *editing.renaming_entity = Some(entity);
+                *editing.rename_buffer = label.clone();
+                ui.close_kind(egui::UiKind::Menu);
             }
-            if drag_response.hovered() {
-                ui.ctx().set_cursor_icon(egui::CursorIcon::Grab);
+            
+            let resize_text = if *editing.resizing_entity == Some(entity) { "🔒 Stop Resize" } else { "📏 Resize" };
+            if ui.button(resize_text).clicked() {
+                *editing.resizing_entity = if *editing.resizing_entity == Some(entity) { None } else { Some(entity) };
+                ui.close_kind(egui::UiKind::Menu);
             }
+            
+            if ui.button("🗑️ Delete").clicked() {
+                events.log(format!("{} '{}' deleted", widget.display_name(), label));
+                events.update(move |world: &mut World| {
+                    world.despawn(entity);
+                });
+                ui.close_kind(egui::UiKind::Menu);
+            }
+        });
+        
+        // Show resize handles if this entity is being resized
+        if *editing.resizing_entity == Some(entity) {
+            draw_resize_handles_for_entity(ui, area_response.inner.rect, events, entity);
         }
     }
 }
@@ -701,6 +366,35 @@ pub fn render_tab_content_old(ui: &mut Ui, world: &mut World, tab: &Tab) {
         TabKind::Preview => {
             ui.label("Preview");
         }
+        TabKind::DockLayout => {
+            ui.label("Dock Layout");
+        }
+        TabKind::MenuBar => {
+            ui.label("Menu Bar");
+        }
+        TabKind::VariantPreview => {
+            ui.label("Variant Preview");
+        }
+        TabKind::Styles => {
+            ui.label("Styles");
+        }
+        TabKind::Theme => {
+            ui.label("Theme");
+        }
+        TabKind::RadioGroups => {
+            ui.label("Radio Groups");
+        }
+        TabKind::Symbols => {
+            ui.label("Components");
+        }
+    }
+}
+
+/// Attach the style's tooltip to a response
+fn with_tooltip(response: Response, style: &UiElementStyle) -> Response {
+    match style.tooltip_text() {
+        Some(tooltip) => response.on_hover_text(tooltip),
+        None => response,
     }
 }
 
@@ -719,7 +413,7 @@ fn apply_updates(world: &mut World, updates: Vec<Box<dyn FnOnce(&mut World) + Se
 }
 
 // Helper function to draw resize handles for a specific entity
-fn draw_resize_handles_for_entity(ui: &mut egui::Ui, rect: egui::Rect, updates: &mut Vec<Box<dyn FnOnce(&mut World) + Send>>, entity: Entity) {
+fn draw_resize_handles_for_entity(ui: &mut egui::Ui, rect: egui::Rect, events: &mut WidgetEvents, entity: Entity) {
     let handle_size = 10.0;
     let bottom_right = rect.right_bottom() - egui::vec2(handle_size / 2.0, handle_size / 2.0);
     let handle_rect = egui::Rect::from_center_size(bottom_right, egui::vec2(handle_size, handle_size));
@@ -740,12 +434,12 @@ fn draw_resize_handles_for_entity(ui: &mut egui::Ui, rect: egui::Rect, updates:
         );
         
         let entity_copy = entity;
-        updates.push(Box::new(move |world: &mut World| {
+        events.update(move |world: &mut World| {
             if let Some(mut size) = world.get_mut::<UiElementSize>(entity_copy) {
                 size.width = clamped_size.x;
                 size.height = clamped_size.y;
             }
-        }));
+        });
     }
     
     if handle_response.hovered() {
@@ -762,11 +456,37 @@ fn draw_resize_handles_for_entity(ui: &mut egui::Ui, rect: egui::Rect, updates:
     );
 }
 
-// Move all selected elements by the given delta
+fn selected_entities(world: &mut World) -> Vec<Entity> {
+    let mut query = world.query::<(Entity, &UiElementSelected)>();
+    query.iter(world).filter(|(_, selected)| selected.selected).map(|(entity, _)| entity).collect()
+}
+
+fn log_container_change(world: &mut World, entity: Entity) {
+    let widgets = current_widgets(world);
+    let describe = |world: &World, entity: Entity| {
+        widgets.widget_of(world.entity(entity))
+            .map(|widget| format!("{} '{}'", widget.display_name(), widget.label(world.entity(entity))))
+            .unwrap_or_default()
+    };
+    let message = match world.get::<UiElementContainer>(entity).and_then(|container| container.parent_group) {
+        Some(parent) => format!("{} placed in {}", describe(world, entity), describe(world, parent)),
+        None => format!("{} taken out of its container", describe(world, entity)),
+    };
+    add_designer_log(world, &message);
+}
+
+// Move all selected elements by the given delta, along with the elements placed in them
 fn move_selected_elements(world: &mut World, delta: egui::Vec2, snap_enabled: bool, spacing: f32) {
-    let mut query = world.query::<(&mut UiElementPosition, &UiElementSelected)>();
-    for (mut pos, selected) in query.iter_mut(world) {
-        if selected.selected {
+    let mut moved = selected_entities(world);
+    for entity in moved.clone() {
+        for descendant in container_descendants(world, entity) {
+            if !moved.contains(&descendant) {
+                moved.push(descendant);
+            }
+        }
+    }
+    for entity in moved {
+        if let Some(mut pos) = world.get_mut::<UiElementPosition>(entity) {
             let new_pos = Pos2::new(pos.x + delta.x, pos.y + delta.y);
             let final_pos = if snap_enabled {
                 snap_to_grid(new_pos, spacing)
@@ -0,0 +1,190 @@
+use bevy_ecs::prelude::*;
+use egui::{Area, Id, Order, Pos2, Ui};
+use crate::components::*;
+use crate::resources::RunPreviewState;
+use crate::utils::*;
+use crate::widgets::{current_widgets, is_element_shown};
+
+/// Whether the canvas is currently running as an interactive preview
+pub fn is_run_preview_active(world: &World) -> bool {
+    world.contains_resource::<RunPreviewState>()
+}
+
+/// Enter run mode with a scratch copy of the current widget state
+pub fn start_run_preview(world: &mut World) {
+    let mut state = RunPreviewState::default();
+
+    let mut text_query = world.query::<(Entity, &UiTextInput)>();
+    for (entity, input) in text_query.iter(world) {
+        state.text_values.insert(entity, input.value.clone());
+    }
+    let mut checkbox_query = world.query::<(Entity, &UiCheckbox)>();
+    for (entity, checkbox) in checkbox_query.iter(world) {
+        state.checked.insert(entity, checkbox.checked);
+    }
+    let mut date_query = world.query::<(Entity, &UiDatePicker)>();
+    for (entity, picker) in date_query.iter(world) {
+        state.dates.insert(entity, picker.date);
+    }
+    let mut time_query = world.query::<(Entity, &UiTimeInput)>();
+    for (entity, input) in time_query.iter(world) {
+        state.times.insert(entity, input.time);
+    }
+    let mut color_query = world.query::<(Entity, &UiColorPicker)>();
+    for (entity, picker) in color_query.iter(world) {
+        state.colors.insert(entity, picker.color);
+    }
+    let mut progress_query = world.query::<(Entity, &UiProgressBar)>();
+    for (entity, bar) in progress_query.iter(world) {
+        state.progress.insert(entity, bar.value);
+    }
+    let mut spinner_query = world.query::<(Entity, &UiSpinner)>();
+    for (entity, spinner) in spinner_query.iter(world) {
+        state.spinning.insert(entity, spinner.active);
+    }
+    let mut led_query = world.query::<(Entity, &UiStatusLed)>();
+    for (entity, led) in led_query.iter(world) {
+        state.statuses.insert(entity, led.status);
+    }
+    // Containers start as the generated app's do
+    let mut header_query = world.query::<(Entity, &UiCollapsingHeader)>();
+    for (entity, header) in header_query.iter(world) {
+        state.open_sections.insert(entity, header.default_open);
+    }
+    let mut tab_query = world.query::<(Entity, &UiTabStrip)>();
+    for (entity, strip) in tab_query.iter(world) {
+        state.open_tabs.insert(entity, strip.default_tab);
+    }
+    let mut tree_query = world.query::<(Entity, &UiTreeView)>();
+    for (entity, tree) in tree_query.iter(world) {
+        for (index, node) in tree.nodes.iter().enumerate() {
+            state.open_nodes.insert((entity, index), node.expanded);
+        }
+    }
+    // Groups start at their default member, like the generated enum
+    for group in radio_groups(world) {
+        if let Some(member) = group.members.get(group.default_index()) {
+            state.radio_selection.insert(group.id.clone(), member.entity);
+        }
+    }
+
+    clear_all_selections(world);
+    world.insert_resource(state);
+    add_designer_log(world, "▶ Run preview started");
+}
+
+/// Leave run mode, discarding everything changed while running
+pub fn stop_run_preview(world: &mut World) {
+    if world.remove_resource::<RunPreviewState>().is_some() {
+        add_designer_log(world, "■ Run preview stopped, preview changes discarded");
+    }
+}
+
+/// Toolbar above the canvas switching between design and run mode (also F5)
+pub fn render_run_toolbar(ui: &mut Ui, world: &mut World) {
+    let running = is_run_preview_active(world);
+    let mut toggle = ui.input(|i| i.key_pressed(egui::Key::F5));
+
+    ui.horizontal(|ui| {
+        if running {
+            ui.colored_label(egui::Color32::from_rgb(100, 180, 255), "▶ Run Preview - Widgets are live; changes are discarded on Stop");
+            if ui.button("■ Stop").on_hover_text("Return to design mode (F5)").clicked() {
+                toggle = true;
+            }
+        } else {
+            ui.colored_label(egui::Color32::from_rgb(0, 255, 0), "✏️ Design Mode - Drag elements to move them");
+            if ui.button("▶ Run").on_hover_text("Try the design interactively (F5)").clicked() {
+                toggle = true;
+            }
+        }
+    });
+
+    if toggle {
+        if running {
+            stop_run_preview(world);
+        } else {
+            start_run_preview(world);
+        }
+    } else if running {
+        render_run_controls(ui, world);
+    }
+}
+
+/// Row of controls for the values the generated app sets from code, e.g. a slider per progress bar
+fn render_run_controls(ui: &mut Ui, world: &mut World) {
+    let active_page = active_page_id(world);
+    let widgets = current_widgets(world);
+    let mut query = world.query::<(Entity, &UiElementPosition, Option<&UiElementPage>)>();
+    let mut elements: Vec<_> = query.iter(world)
+        .filter(|(_, _, page)| is_on_page(*page, active_page))
+        .filter_map(|(entity, pos, _)| Some((widgets.widget_of(world.entity(entity))?.clone(), entity, pos.y, pos.x)))
+        .collect();
+    if elements.is_empty() {
+        return;
+    }
+    // Same order as the elements appear on the page
+    elements.sort_by(|a, b| a.2.total_cmp(&b.2).then(a.3.total_cmp(&b.3)));
+
+    let Some(mut state) = world.remove_resource::<RunPreviewState>() else {
+        return;
+    };
+    ui.horizontal_wrapped(|ui| {
+        for (widget, entity, ..) in elements {
+            widget.render_run_controls(ui, world.entity(entity), &mut state);
+        }
+    });
+    world.insert_resource(state);
+}
+
+/// Render the active page with working widgets backed by the scratch state
+pub fn render_run_preview_elements(ui: &mut Ui, world: &mut World) {
+    let active_page = active_page_id(world);
+    let Some(mut state) = world.remove_resource::<RunPreviewState>() else {
+        return;
+    };
+    let mut log_messages = Vec::new();
+
+    let style_sheet = current_style_sheet(world);
+    let widgets = current_widgets(world);
+    let mut query = world.query::<(Entity, &UiElementPosition, Option<&UiElementPage>, Option<&UiElementStyle>)>();
+    let mut elements: Vec<_> = query.iter(world)
+        .filter(|(.., page, _)| is_on_page(*page, active_page))
+        .filter(|(entity, ..)| is_element_shown(world, &widgets, *entity, Some(&state)))
+        .filter_map(|(entity, pos, _, style)| {
+            let widget = widgets.widget_of(world.entity(entity))?.clone();
+            Some((widget, entity, pos.clone(), style_sheet.resolve(style)))
+        })
+        .collect();
+
+    // Containers first so widgets placed on them stay clickable
+    elements.sort_by_key(|(widget, ..)| !widget.is_container());
+
+    for (widget, entity, pos, style) in elements {
+        let element = world.entity(entity);
+        let container = widget.is_container();
+        Area::new(Id::new(("run", widget.kind(), entity)))
+            .order(if container { Order::Background } else { Order::Middle })
+            .fixed_pos(Pos2::new(pos.x, pos.y))
+            .interactable(!container)
+            .show(ui.ctx(), |ui| {
+                let response = if widget.draws_own_frame() {
+                    widget.render_run(ui, element, &style, &mut state, &mut log_messages)
+                } else {
+                    style.show_framed(ui, |ui| widget.render_run(ui, element, &style, &mut state, &mut log_messages)).inner
+                };
+                with_tooltip(response, &style);
+            });
+    }
+
+    world.insert_resource(state);
+    for message in log_messages {
+        add_designer_log(world, &message);
+    }
+}
+
+fn with_tooltip(response: egui::Response, style: &UiElementStyle) -> egui::Response {
+    match style.tooltip_text() {
+        Some(tooltip) => response.on_hover_text(tooltip),
+        None => response,
+    }
+}
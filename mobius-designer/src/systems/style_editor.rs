@@ -0,0 +1,468 @@
+use bevy_ecs::prelude::*;
+use egui::{Color32, Ui, Vec2};
+use crate::components::*;
+use crate::utils::*;
+use super::reflect_inspector::MIXED;
+
+/// Inspector section editing the `UiElementStyle` of a single element
+pub fn render_style_properties(ui: &mut Ui, world: &mut World, entity: Entity) {
+    let Some(mut style) = world.get::<UiElementStyle>(entity).cloned() else {
+        return;
+    };
+    let original = style.clone();
+    let mut style_sheet = current_style_sheet(world);
+    let original_sheet = style_sheet.clone();
+
+    ui.separator();
+    ui.horizontal(|ui| {
+        ui.label("🎨 Style:");
+        if ui.add_enabled(style != UiElementStyle::default(), egui::Button::new("Reset").small()).clicked() {
+            style = UiElementStyle::default();
+        }
+    });
+
+    ui.horizontal(|ui| {
+        ui.label("Named style:");
+        egui::ComboBox::from_id_salt(("style_class", entity))
+            .selected_text(style.class.as_deref().unwrap_or("(none)"))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut style.class, None, "(none)");
+                for named in &style_sheet.styles {
+                    ui.selectable_value(&mut style.class, Some(named.name.clone()), &named.name);
+                }
+            });
+        if let Some(class) = &style.class {
+            if style_sheet.get(class).is_none() {
+                ui.colored_label(Color32::from_rgb(255, 150, 0), "⚠ missing");
+            }
+        }
+    });
+
+    let inherited = style.class.as_deref().map(|class| style_sheet.resolve_named(class)).unwrap_or_default();
+    if style.class.is_some() {
+        ui.weak("Checked properties override the named style");
+    }
+    style_property_grid(ui, ("style_properties", entity), &mut style, &inherited, &MixedProperties::default());
+
+    ui.horizontal(|ui| {
+        if ui.add_enabled(style.has_properties(), egui::Button::new("💾 Save as Named Style"))
+            .on_hover_text("Move these properties into a new named style used by this element")
+            .clicked()
+        {
+            let mut named = NamedStyle::new(style_sheet.unique_name("style"));
+            named.parent = style.class.take();
+            named.style = UiElementStyle { class: None, ..style.clone() };
+            style = UiElementStyle { class: Some(named.name.clone()), ..Default::default() };
+            style_sheet.styles.push(named);
+        }
+        if style.class.is_some()
+            && style.has_properties()
+            && ui.button("Clear Overrides").clicked()
+        {
+            style = UiElementStyle { class: style.class.clone(), ..Default::default() };
+        }
+    });
+
+    if style_sheet != original_sheet {
+        store_style_sheet(world, style_sheet);
+    }
+    if style != original {
+        if let Some(mut current) = world.get_mut::<UiElementStyle>(entity) {
+            *current = style;
+        }
+    }
+}
+
+/// Inspector section editing the styles of a multi-selection. Properties whose values
+/// differ are marked as mixed; a property that is edited is set on every selected element.
+pub fn render_group_style_properties(ui: &mut Ui, world: &mut World, entities: &[Entity]) {
+    let (entities, styles): (Vec<Entity>, Vec<UiElementStyle>) = entities
+        .iter()
+        .filter_map(|entity| world.get::<UiElementStyle>(*entity).map(|style| (*entity, style.clone())))
+        .unzip();
+    let Some(original) = styles.first().cloned() else {
+        return;
+    };
+    let mixed = MixedProperties::of(&styles);
+    let mixed_class = differs(&styles, |style| style.class.clone());
+    let mut style = original.clone();
+    let style_sheet = current_style_sheet(world);
+    let mut chosen_class: Option<Option<String>> = None;
+
+    ui.separator();
+    ui.horizontal(|ui| {
+        ui.label("🎨 Style:");
+        if ui.button("Reset All").on_hover_text("Clear the style of every selected element").clicked() {
+            for entity in &entities {
+                if let Some(mut current) = world.get_mut::<UiElementStyle>(*entity) {
+                    *current = UiElementStyle::default();
+                }
+            }
+        }
+    });
+
+    ui.horizontal(|ui| {
+        ui.label("Named style:");
+        let selected_text = if mixed_class { MIXED } else { style.class.as_deref().unwrap_or("(none)") };
+        egui::ComboBox::from_id_salt("group_style_class")
+            .selected_text(selected_text)
+            .show_ui(ui, |ui| {
+                if ui.selectable_label(!mixed_class && style.class.is_none(), "(none)").clicked() {
+                    chosen_class = Some(None);
+                }
+                for named in &style_sheet.styles {
+                    let selected = !mixed_class && style.class.as_deref() == Some(named.name.as_str());
+                    if ui.selectable_label(selected, &named.name).clicked() {
+                        chosen_class = Some(Some(named.name.clone()));
+                    }
+                }
+            });
+    });
+    if let Some(class) = chosen_class.clone() {
+        style.class = class;
+    }
+
+    let inherited = if mixed_class {
+        UiElementStyle::default()
+    } else {
+        style.class.as_deref().map(|class| style_sheet.resolve_named(class)).unwrap_or_default()
+    };
+    style_property_grid(ui, "group_style_properties", &mut style, &inherited, &mixed);
+
+    if style != original || chosen_class.is_some() {
+        for entity in &entities {
+            if let Some(mut current) = world.get_mut::<UiElementStyle>(*entity) {
+                apply_changed_properties(&mut current, &original, &style);
+                if let Some(class) = &chosen_class {
+                    current.class = class.clone();
+                }
+            }
+        }
+    }
+}
+
+/// Style properties whose values differ between several elements
+#[derive(Default)]
+struct MixedProperties {
+    fill: bool,
+    text_color: bool,
+    stroke: bool,
+    corner_radius: bool,
+    padding: bool,
+    bold: bool,
+    italic: bool,
+    tooltip: bool,
+}
+
+impl MixedProperties {
+    fn of(styles: &[UiElementStyle]) -> Self {
+        Self {
+            fill: differs(styles, |style| style.fill),
+            text_color: differs(styles, |style| style.text_color),
+            stroke: differs(styles, |style| style.stroke),
+            corner_radius: differs(styles, |style| style.corner_radius),
+            padding: differs(styles, |style| style.padding),
+            bold: differs(styles, |style| style.bold),
+            italic: differs(styles, |style| style.italic),
+            tooltip: differs(styles, |style| style.tooltip.clone()),
+        }
+    }
+}
+
+fn differs<T: PartialEq>(styles: &[UiElementStyle], property: impl Fn(&UiElementStyle) -> T) -> bool {
+    styles.windows(2).any(|pair| property(&pair[0]) != property(&pair[1]))
+}
+
+/// Copy the properties that differ between `before` and `after` into `target`
+fn apply_changed_properties(target: &mut UiElementStyle, before: &UiElementStyle, after: &UiElementStyle) {
+    if after.class != before.class {
+        target.class = after.class.clone();
+    }
+    if after.fill != before.fill {
+        target.fill = after.fill;
+    }
+    if after.text_color != before.text_color {
+        target.text_color = after.text_color;
+    }
+    if after.stroke != before.stroke {
+        target.stroke = after.stroke;
+    }
+    if after.corner_radius != before.corner_radius {
+        target.corner_radius = after.corner_radius;
+    }
+    if after.padding != before.padding {
+        target.padding = after.padding;
+    }
+    if after.bold != before.bold {
+        target.bold = after.bold;
+    }
+    if after.italic != before.italic {
+        target.italic = after.italic;
+    }
+    if after.tooltip != before.tooltip {
+        target.tooltip = after.tooltip.clone();
+    }
+}
+
+/// Editor for the project's named styles
+pub fn render_style_sheet_panel(ui: &mut Ui, world: &mut World) {
+    ui.heading("🎨 Styles");
+    ui.label("Named styles shared by elements. Changes apply to every element using the style.");
+    ui.separator();
+
+    let mut style_sheet = current_style_sheet(world);
+    let original = style_sheet.clone();
+    let selected_id = egui::Id::new("style_sheet_selected");
+    let mut selected: Option<String> = ui.data(|data| data.get_temp(selected_id));
+    if selected.as_deref().is_none_or(|name| style_sheet.get(name).is_none()) {
+        selected = style_sheet.styles.first().map(|style| style.name.clone());
+    }
+
+    let mut usage = std::collections::HashMap::<String, usize>::new();
+    let mut query = world.query::<&UiElementStyle>();
+    for style in query.iter(world) {
+        if let Some(class) = &style.class {
+            *usage.entry(class.clone()).or_default() += 1;
+        }
+    }
+
+    let mut renamed: Option<(String, String)> = None;
+    let mut removed: Option<String> = None;
+
+    ui.horizontal(|ui| {
+        if ui.button("➕ New Style").clicked() {
+            let name = style_sheet.unique_name("style");
+            style_sheet.styles.push(NamedStyle::new(name.clone()));
+            selected = Some(name);
+        }
+        if let Some(name) = selected.clone() {
+            if ui.button("📄 Duplicate").clicked() {
+                if let Some(mut copy) = style_sheet.get(&name).cloned() {
+                    copy.name = style_sheet.unique_name(&name);
+                    selected = Some(copy.name.clone());
+                    style_sheet.styles.push(copy);
+                }
+            }
+            if ui.button("🗑 Delete").clicked() {
+                style_sheet.remove(&name);
+                removed = Some(name);
+                selected = style_sheet.styles.first().map(|style| style.name.clone());
+            }
+        }
+    });
+    ui.add_space(4.0);
+
+    ui.columns(2, |columns| {
+        egui::ScrollArea::vertical()
+            .id_salt("style_sheet_list")
+            .show(&mut columns[0], |ui| {
+                for named in &style_sheet.styles {
+                    let depth = style_sheet.ancestry(&named.name).len() - 1;
+                    let count = usage.get(&named.name).copied().unwrap_or(0);
+                    let label = format!("{}{}  ({} uses)", "   ".repeat(depth), named.name, count);
+                    if ui.selectable_label(selected.as_deref() == Some(named.name.as_str()), label).clicked() {
+                        selected = Some(named.name.clone());
+                    }
+                }
+                if style_sheet.styles.is_empty() {
+                    ui.weak("(no named styles)");
+                }
+            });
+
+        let ui = &mut columns[1];
+        let Some(name) = selected.clone() else {
+            return;
+        };
+        let candidates: Vec<String> = style_sheet
+            .styles
+            .iter()
+            .map(|style| style.name.clone())
+            .filter(|candidate| *candidate != name && !style_sheet.would_cycle(&name, candidate))
+            .collect();
+        let inherited = style_sheet
+            .effective_parent(&name)
+            .map(|parent| style_sheet.resolve_named(&parent.name))
+            .unwrap_or_default();
+        let resolved = style_sheet.resolve_named(&name);
+        let Some(named) = style_sheet.get(&name).cloned() else {
+            return;
+        };
+        let mut edited = named.clone();
+
+        ui.horizontal(|ui| {
+            ui.label("Name:");
+            ui.text_edit_singleline(&mut edited.name);
+        });
+        ui.horizontal(|ui| {
+            ui.label("Inherits:");
+            egui::ComboBox::from_id_salt("style_sheet_parent")
+                .selected_text(edited.parent.as_deref().unwrap_or("(none)"))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut edited.parent, None, "(none)");
+                    for candidate in &candidates {
+                        ui.selectable_value(&mut edited.parent, Some(candidate.clone()), candidate);
+                    }
+                });
+        });
+        if style_sheet.has_cycle(&name) {
+            ui.colored_label(Color32::from_rgb(255, 150, 0), "⚠ Inheritance cycle; the parent is ignored");
+        }
+        style_property_grid(ui, "style_sheet_properties", &mut edited.style, &inherited, &MixedProperties::default());
+
+        ui.add_space(8.0);
+        ui.label("Sample:");
+        resolved.show_framed(ui, |ui| {
+            ui.label(resolved.rich_text("Label text", DEFAULT_FONT_SIZE));
+        });
+        if let Some(padding) = resolved.padding {
+            ui.spacing_mut().button_padding = padding;
+        }
+        ui.add(resolved.style_button(egui::Button::new(resolved.rich_text("Button", DEFAULT_FONT_SIZE))));
+
+        let new_name = edited.name.trim().to_string();
+        if new_name != named.name {
+            if new_name.is_empty() || style_sheet.get(&new_name).is_some() {
+                edited.name = named.name.clone();
+            } else {
+                renamed = Some((named.name.clone(), new_name.clone()));
+                edited.name = new_name;
+            }
+        }
+        if edited != named {
+            if let Some(target) = style_sheet.get_mut(&name) {
+                *target = NamedStyle { name: named.name.clone(), ..edited };
+            }
+            if let Some((old, new)) = &renamed {
+                style_sheet.rename(old, new);
+                selected = Some(new.clone());
+            }
+        }
+    });
+
+    ui.data_mut(|data| data.insert_temp(selected_id, selected));
+
+    if style_sheet != original {
+        store_style_sheet(world, style_sheet);
+    }
+    if renamed.is_some() || removed.is_some() {
+        let mut query = world.query::<&mut UiElementStyle>();
+        for mut style in query.iter_mut(world) {
+            if let (Some((old, new)), Some(class)) = (&renamed, &style.class) {
+                if class == old {
+                    style.class = Some(new.clone());
+                }
+            }
+            if removed.is_some() && style.class == removed {
+                style.class = None;
+            }
+        }
+    }
+    if let Some((old, new)) = renamed {
+        add_designer_log(world, &format!("🎨 Renamed style '{}' to '{}'", old, new));
+    }
+    if let Some(name) = removed {
+        add_designer_log(world, &format!("🎨 Deleted style '{}'", name));
+    }
+}
+
+fn store_style_sheet(world: &mut World, style_sheet: StyleSheet) {
+    let mut query = world.query::<&mut StyleSheet>();
+    if let Some(mut current) = query.iter_mut(world).next() {
+        *current = style_sheet;
+    } else {
+        world.spawn(style_sheet);
+    }
+}
+
+/// Checkbox and editor per style property; unchecked properties show the `inherited` value.
+/// Properties flagged in `mixed` get an indeterminate checkbox.
+fn style_property_grid(
+    ui: &mut Ui,
+    id_salt: impl std::hash::Hash,
+    style: &mut UiElementStyle,
+    inherited: &UiElementStyle,
+    mixed: &MixedProperties,
+) {
+    egui::Grid::new(id_salt)
+        .num_columns(2)
+        .spacing([8.0, 4.0])
+        .show(ui, |ui| {
+            optional_color(ui, "Fill", &mut style.fill, inherited.fill.unwrap_or(Color32::from_gray(60)), mixed.fill);
+            optional_color(ui, "Text Color", &mut style.text_color, inherited.text_color.unwrap_or(Color32::WHITE), mixed.text_color);
+
+            let mut has_stroke = style.stroke.is_some();
+            property_checkbox(ui, &mut has_stroke, "Border", mixed.stroke);
+            let mut stroke = style.stroke.or(inherited.stroke).unwrap_or(StrokeStyle { width: 1.0, color: Color32::GRAY });
+            ui.add_enabled_ui(has_stroke, |ui| {
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut stroke.width).range(0.0..=10.0).speed(0.1).suffix(" px"));
+                    egui::color_picker::color_edit_button_srgba(ui, &mut stroke.color, egui::color_picker::Alpha::OnlyBlend);
+                });
+            });
+            style.stroke = has_stroke.then_some(stroke);
+            ui.end_row();
+
+            let mut has_radius = style.corner_radius.is_some();
+            property_checkbox(ui, &mut has_radius, "Corner Radius", mixed.corner_radius);
+            let mut radius = style.corner_radius.or(inherited.corner_radius).unwrap_or(4.0);
+            ui.add_enabled(has_radius, egui::DragValue::new(&mut radius).range(0.0..=50.0).speed(0.2));
+            style.corner_radius = has_radius.then_some(radius);
+            ui.end_row();
+
+            let mut has_padding = style.padding.is_some();
+            property_checkbox(ui, &mut has_padding, "Padding", mixed.padding);
+            let mut padding = style.padding.or(inherited.padding).unwrap_or(Vec2::new(4.0, 2.0));
+            ui.add_enabled_ui(has_padding, |ui| {
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut padding.x).range(0.0..=100.0).prefix("x: "));
+                    ui.add(egui::DragValue::new(&mut padding.y).range(0.0..=100.0).prefix("y: "));
+                });
+            });
+            style.padding = has_padding.then_some(padding);
+            ui.end_row();
+
+            optional_flag(ui, "Bold", &mut style.bold, inherited.is_bold(), mixed.bold);
+            optional_flag(ui, "Italic", &mut style.italic, inherited.is_italic(), mixed.italic);
+
+            let mut has_tooltip = style.tooltip.is_some();
+            property_checkbox(ui, &mut has_tooltip, "Tooltip", mixed.tooltip);
+            let mut tooltip = style.tooltip.clone().or_else(|| inherited.tooltip.clone()).unwrap_or_default();
+            if mixed.tooltip {
+                let mut edited = String::new();
+                if ui.add_enabled(has_tooltip, egui::TextEdit::singleline(&mut edited).hint_text(MIXED)).changed() {
+                    tooltip = edited;
+                }
+            } else {
+                ui.add_enabled(has_tooltip, egui::TextEdit::singleline(&mut tooltip));
+            }
+            style.tooltip = has_tooltip.then_some(tooltip);
+            ui.end_row();
+        });
+}
+
+fn property_checkbox(ui: &mut Ui, enabled: &mut bool, label: &str, mixed: bool) {
+    let response = ui.add(egui::Checkbox::new(enabled, label).indeterminate(mixed));
+    if mixed {
+        response.on_hover_text("Mixed values; editing sets it on every selected element");
+    }
+}
+
+fn optional_color(ui: &mut Ui, label: &str, value: &mut Option<Color32>, default: Color32, mixed: bool) {
+    let mut enabled = value.is_some();
+    property_checkbox(ui, &mut enabled, label, mixed);
+    let mut color = value.unwrap_or(default);
+    ui.add_enabled_ui(enabled, |ui| {
+        egui::color_picker::color_edit_button_srgba(ui, &mut color, egui::color_picker::Alpha::OnlyBlend);
+    });
+    *value = enabled.then_some(color);
+    ui.end_row();
+}
+
+fn optional_flag(ui: &mut Ui, label: &str, value: &mut Option<bool>, default: bool, mixed: bool) {
+    let mut enabled = value.is_some();
+    property_checkbox(ui, &mut enabled, label, mixed);
+    let mut flag = value.unwrap_or(default);
+    ui.add_enabled(enabled, egui::Checkbox::without_text(&mut flag));
+    *value = enabled.then_some(flag);
+    ui.end_row();
+}
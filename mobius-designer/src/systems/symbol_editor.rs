@@ -0,0 +1,316 @@
+use std::collections::HashMap;
+use bevy_ecs::prelude::*;
+use bevy_ecs::reflect::{AppTypeRegistry, ReflectComponent};
+use bevy_reflect::{PartialReflect, ReflectMut};
+use egui::{Color32, Ui};
+use crate::components::*;
+use crate::utils::*;
+use crate::widgets::{container_descendants, current_widgets, element_parent, spawn_widget, symbol_master, SymbolInstanceWidget};
+
+/// Editor for the reusable components: create one from the selected elements, rename it and place instances
+pub fn render_symbol_panel(ui: &mut Ui, world: &mut World) {
+    ui.heading("◈ Components");
+    ui.label("A component is a named block of elements. Its master holds the elements; every instance shows them and can override their labels.");
+    ui.separator();
+
+    ui.horizontal(|ui| {
+        let buffer_id = ui.id().with("new_symbol_name");
+        let mut name = ui.data(|data| data.get_temp::<String>(buffer_id)).unwrap_or_default();
+        if ui.add(egui::TextEdit::singleline(&mut name).hint_text("Component name").desired_width(160.0)).changed() {
+            ui.data_mut(|data| data.insert_temp(buffer_id, name.clone()));
+        }
+        if ui.button("◈ Create from Selection").clicked() {
+            match create_symbol_from_selection(world, &name) {
+                Ok(_) => {
+                    add_designer_log(world, &format!("◈ Created component '{}'", name.trim()));
+                    ui.data_mut(|data| data.remove::<String>(buffer_id));
+                }
+                Err(e) => add_designer_log(world, &format!("❌ {}", e)),
+            }
+        }
+    });
+    ui.separator();
+
+    let mut query = world.query::<(Entity, &UiSymbolInstance)>();
+    let instances: Vec<(Entity, UiSymbolInstance)> = query.iter(world).map(|(entity, instance)| (entity, instance.clone())).collect();
+    let masters: Vec<&(Entity, UiSymbolInstance)> = instances.iter().filter(|(_, instance)| instance.master).collect();
+    if masters.is_empty() {
+        ui.weak("No components yet. Select elements on the canvas, name the component and create it.");
+        return;
+    }
+
+    let mut renamed = None;
+    let mut placed = None;
+    let mut selected = None;
+    egui::ScrollArea::vertical().show(ui, |ui| {
+        for (master, component) in masters {
+            ui.push_id(master, |ui| {
+                egui::Frame::group(ui.style()).show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        // Renames are applied when editing ends so the component keeps its place while typing
+                        let buffer_id = ui.id().with("rename");
+                        let mut name = ui.data(|data| data.get_temp::<String>(buffer_id)).unwrap_or_else(|| component.symbol.clone());
+                        let response = ui.add(egui::TextEdit::singleline(&mut name).desired_width(160.0));
+                        if response.changed() {
+                            ui.data_mut(|data| data.insert_temp(buffer_id, name.clone()));
+                        }
+                        if response.lost_focus() {
+                            ui.data_mut(|data| data.remove::<String>(buffer_id));
+                            let name = name.trim();
+                            if !name.is_empty() && name != component.symbol {
+                                renamed = Some((component.symbol.clone(), name.to_string()));
+                            }
+                        }
+                        let count = instances.iter().filter(|(_, other)| !other.master && other.symbol == component.symbol).count();
+                        ui.weak(format!("{} instances", count));
+                    });
+                    ui.horizontal(|ui| {
+                        if ui.button("➕ Place Instance").clicked() {
+                            placed = Some(component.symbol.clone());
+                        }
+                        if ui.button("🔍 Select Master").clicked() {
+                            selected = Some(*master);
+                        }
+                    });
+                });
+            });
+            ui.add_space(4.0);
+        }
+    });
+
+    if let Some((from, to)) = renamed {
+        if symbol_master(world, &to).is_some() {
+            add_designer_log(world, &format!("❌ A component named '{}' already exists", to));
+        } else {
+            let mut query = world.query::<&mut UiSymbolInstance>();
+            for mut instance in query.iter_mut(world) {
+                if instance.symbol == from {
+                    instance.symbol = to.clone();
+                }
+            }
+            add_designer_log(world, &format!("◈ Renamed component '{}' to '{}'", from, to));
+        }
+    }
+    if let Some(symbol) = placed {
+        place_symbol_instance(world, &symbol);
+    }
+    if let Some(master) = selected {
+        clear_all_selections(world);
+        if let Some(mut selection) = world.get_mut::<UiElementSelected>(master) {
+            selection.selected = true;
+        }
+    }
+    if instances.iter().any(|(_, instance)| !instance.master && symbol_master(world, &instance.symbol).is_none()) {
+        ui.colored_label(Color32::YELLOW, "⚠ Some instances belong to a component whose master was deleted");
+    }
+}
+
+/// Turn the selected elements into a new component: a master is placed around them and they move into it
+pub fn create_symbol_from_selection(world: &mut World, name: &str) -> Result<Entity, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Enter a name for the component".to_string());
+    }
+    if symbol_master(world, name).is_some() {
+        return Err(format!("A component named '{}' already exists", name));
+    }
+
+    let mut query = world.query::<(Entity, &UiElementSelected)>();
+    let selected: Vec<Entity> = query.iter(world).filter(|(_, selected)| selected.selected).map(|(entity, _)| entity).collect();
+    // Elements in a selected container go along with it
+    let parts: Vec<Entity> = selected.iter()
+        .copied()
+        .filter(|entity| element_parent(world, *entity).is_none_or(|(parent, _)| !selected.contains(&parent)))
+        .collect();
+    let Some(&first) = parts.first() else {
+        return Err("Select the elements the component is made of".to_string());
+    };
+
+    let mut elements = parts.clone();
+    for part in &parts {
+        elements.extend(container_descendants(world, *part));
+    }
+    if elements.iter().any(|entity| world.get::<UiSymbolInstance>(*entity).is_some() || world.get::<UiSymbolCopy>(*entity).is_some()) {
+        return Err("Components can't contain other components".to_string());
+    }
+    let page = world.get::<UiElementPage>(first).cloned().unwrap_or_default();
+    let tab = world.get::<UiElementTab>(first).map(|tab| tab.tab_kind.clone()).ok_or("The selected elements are not on a tab")?;
+    let same_place = parts.iter().all(|part| {
+        world.get::<UiElementPage>(*part).map(|p| p.page_id) == Some(page.page_id)
+            && world.get::<UiElementTab>(*part).map(|t| &t.tab_kind) == Some(&tab)
+    });
+    if !same_place {
+        return Err("The elements of a component must be on the same page".to_string());
+    }
+
+    let mut bounds = egui::Rect::NOTHING;
+    for entity in &elements {
+        if let (Some(pos), Some(size)) = (world.get::<UiElementPosition>(*entity), world.get::<UiElementSize>(*entity)) {
+            bounds = bounds.union(egui::Rect::from_min_size(egui::pos2(pos.x, pos.y), egui::vec2(size.width, size.height)));
+        }
+    }
+    let bounds = bounds.expand(8.0);
+
+    let master = spawn_widget(world, &SymbolInstanceWidget, bounds.min.x, bounds.min.y, tab, page.page_id);
+    world.entity_mut(master).insert((
+        UiSymbolInstance { master: true, ..UiSymbolInstance::new(name, name) },
+        UiElementSize { width: bounds.width(), height: bounds.height() },
+    ));
+    for part in parts {
+        world.entity_mut(part).insert(UiElementContainer { parent_group: Some(master), slot: 0 });
+    }
+    clear_all_selections(world);
+    if let Some(mut selection) = world.get_mut::<UiElementSelected>(master) {
+        selection.selected = true;
+    }
+    Ok(master)
+}
+
+/// Place a new instance of the component next to its master, or at the master's position when
+/// the master is on another page
+pub fn place_symbol_instance(world: &mut World, symbol: &str) -> Option<Entity> {
+    let master = symbol_master(world, symbol)?;
+    let pos = world.get::<UiElementPosition>(master)?.clone();
+    let size = world.get::<UiElementSize>(master)?.clone();
+    let tab = world.get::<UiElementTab>(master)?.tab_kind.clone();
+    let master_page = world.get::<UiElementPage>(master).map(|page| page.page_id).unwrap_or_default();
+    let page = active_page_id(world);
+    let (x, y) = if page == master_page { (pos.x + size.width + 20.0, pos.y) } else { (pos.x, pos.y) };
+
+    let mut query = world.query::<&UiSymbolInstance>();
+    let count = query.iter(world).filter(|instance| instance.symbol == symbol).count();
+    let instance = spawn_widget(world, &SymbolInstanceWidget, x, y, tab, page);
+    world.entity_mut(instance).insert((UiSymbolInstance::new(&format!("{} {}", symbol, count + 1), symbol), size));
+    sync_symbol_instances(world);
+    add_designer_log(world, &format!("◈ Placed an instance of '{}'", symbol));
+    Some(instance)
+}
+
+/// Bring every instance in line with its component's master: one copy per element of the master,
+/// with the element's properties and style, at its offset in the master, and with the
+/// instance's label overrides. Copies of deleted instances and of removed elements are deleted.
+pub fn sync_symbol_instances(world: &mut World) {
+    let mut query = world.query::<(Entity, &UiSymbolInstance)>();
+    let instances: Vec<(Entity, UiSymbolInstance)> = query.iter(world).map(|(entity, instance)| (entity, instance.clone())).collect();
+
+    let mut copy_query = world.query::<(Entity, &UiSymbolCopy)>();
+    let orphans: Vec<Entity> = copy_query.iter(world)
+        .filter(|(_, copy)| !instances.iter().any(|(entity, instance)| *entity == copy.instance && !instance.master))
+        .map(|(entity, _)| entity)
+        .collect();
+    for orphan in orphans {
+        world.despawn(orphan);
+    }
+
+    for (instance, component) in instances.iter().filter(|(_, instance)| !instance.master) {
+        let master = instances.iter().find(|(_, other)| other.master && other.symbol == component.symbol);
+        if let Some((master, _)) = master {
+            sync_instance(world, *master, *instance, component);
+        }
+    }
+}
+
+fn sync_instance(world: &mut World, master: Entity, instance: Entity, component: &UiSymbolInstance) {
+    let Some(registry) = world.get_resource::<AppTypeRegistry>().cloned() else {
+        return;
+    };
+    let widgets = current_widgets(world);
+    let (Some(master_pos), Some(instance_pos)) = (world.get::<UiElementPosition>(master).cloned(), world.get::<UiElementPosition>(instance).cloned()) else {
+        return;
+    };
+    let page = world.get::<UiElementPage>(instance).cloned().unwrap_or_default();
+    let Some(tab) = world.get::<UiElementTab>(instance).cloned() else {
+        return;
+    };
+    let parts = container_descendants(world, master);
+
+    let mut copy_query = world.query::<(Entity, &UiSymbolCopy)>();
+    let mut copies: HashMap<Entity, Entity> = copy_query.iter(world)
+        .filter(|(_, copy)| copy.instance == instance)
+        .map(|(entity, copy)| (copy.part, entity))
+        .collect();
+    copies.retain(|part, copy| {
+        let kept = parts.contains(part);
+        if !kept {
+            world.despawn(*copy);
+        }
+        kept
+    });
+    for part in &parts {
+        if !copies.contains_key(part) {
+            let copy = world.spawn((UiElementSelected::default(), UiSymbolCopy { instance, part: *part })).id();
+            copies.insert(*part, copy);
+        }
+    }
+
+    let registry = registry.read();
+    for part in parts {
+        let copy = copies[&part];
+        let widget = widgets.widget_of(world.entity(part)).cloned();
+        let relabel = widget.as_ref()
+            .zip(component.label_override(part))
+            .map(|(widget, label)| (widget.label(world.entity(part)), label));
+        // Widget properties through reflection, as the Inspector edits them
+        for registration in registry.iter() {
+            if registration.type_id() == std::any::TypeId::of::<UiElementPosition>() {
+                continue;
+            }
+            let Some(reflect_component) = registration.data::<ReflectComponent>() else {
+                continue;
+            };
+            let Some(value) = reflect_component.reflect(world.entity(part)) else {
+                continue;
+            };
+            let current = reflect_component.reflect(world.entity(copy));
+            if relabel.is_none() && current.and_then(|current| current.reflect_partial_eq(value.as_partial_reflect())) == Some(true) {
+                continue;
+            }
+            let mut value = value.to_dynamic();
+            if let Some((master_label, label)) = &relabel {
+                // The copy shows the instance's label, which is no difference to sync
+                override_label(value.as_mut(), master_label, label);
+                if current.and_then(|current| current.reflect_partial_eq(value.as_ref())) == Some(true) {
+                    continue;
+                }
+            }
+            reflect_component.insert(&mut world.entity_mut(copy), value.as_ref(), &registry);
+        }
+
+        let Some(part_pos) = world.get::<UiElementPosition>(part).cloned() else {
+            continue;
+        };
+        let (parent, slot) = match element_parent(world, part) {
+            Some((parent, slot)) if parent != master => (copies.get(&parent).copied().unwrap_or(instance), slot),
+            Some((_, slot)) => (instance, slot),
+            None => (instance, 0),
+        };
+        let style = world.get::<UiElementStyle>(part).cloned().unwrap_or_default();
+        let mut entity = world.entity_mut(copy);
+        entity.insert((
+            UiElementPosition { x: instance_pos.x + part_pos.x - master_pos.x, y: instance_pos.y + part_pos.y - master_pos.y },
+            UiElementContainer { parent_group: Some(parent), slot },
+            page.clone(),
+            tab.clone(),
+            style,
+        ));
+        if let (Some(widget), Some((_, label))) = (widget, relabel) {
+            if widget.label(entity.as_readonly()) != label {
+                widget.set_label(&mut entity, label.to_string());
+            }
+        }
+    }
+}
+
+/// Put an instance's label in place of the master's in the text fields of a reflected component
+fn override_label(value: &mut dyn PartialReflect, master_label: &str, label: &str) {
+    let ReflectMut::Struct(fields) = value.reflect_mut() else {
+        return;
+    };
+    for index in 0..fields.field_len() {
+        if let Some(field) = fields.field_at_mut(index).and_then(|field| field.try_downcast_mut::<String>()) {
+            if field == master_label {
+                *field = label.to_string();
+            }
+        }
+    }
+}
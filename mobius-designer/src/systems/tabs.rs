@@ -4,7 +4,6 @@ use crate::integration::*;
 use crate::components::*;
 use crate::resources::*;
 use crate::utils::*;
-use crate::bundles::*;
 use crate::systems::distribution::*;
 
 pub fn render_tab_content(ui: &mut Ui, world: &mut World, tab: &Tab, renaming_entity: &mut Option<Entity>, rename_buffer: &mut String, show_add_menu: &mut bool, add_menu_pos: &mut egui::Pos2, resizing_entity: &mut Option<Entity>, drag_selection: &mut Option<crate::integration::DragSelection>, codegen_state: Option<&mut crate::events::CodeGenState>, file_dialog: &mut egui_file_dialog::FileDialog) {
@@ -22,7 +21,14 @@ pub fn render_tab_content(ui: &mut Ui, world: &mut World, tab: &Tab, renaming_en
                 }
             };
             
-            ui.colored_label(egui::Color32::from_rgb(0, 255, 0), "✏️ Design Mode - Drag elements to move them");
+            crate::systems::render_run_toolbar(ui, world);
+            crate::systems::render_page_bar(ui, world);
+            
+            // In run mode widgets are live and the design cannot be edited
+            if crate::systems::is_run_preview_active(world) {
+                crate::systems::render_run_preview_elements(ui, world);
+                return;
+            }
             
             // First render UI elements
             crate::systems::render_dynamic_ui_elements(
@@ -115,29 +121,11 @@ pub fn render_tab_content(ui: &mut Ui, world: &mut World, tab: &Tab, renaming_en
                         ui.label("Add UI Element:");
                         ui.separator();
                         
-                        if ui.button("➕ Add Button").clicked() {
-                            add_ui_element_at_position_in_tab(world, "button", add_menu_pos.x, add_menu_pos.y, tab.kind.clone());
-                            *show_add_menu = false;
-                        }
-                        
-                        if ui.button("📝 Add Text Input").clicked() {
-                            add_ui_element_at_position_in_tab(world, "text_input", add_menu_pos.x, add_menu_pos.y, tab.kind.clone());
-                            *show_add_menu = false;
-                        }
-                        
-                        if ui.button("☑️ Add Checkbox").clicked() {
-                            add_ui_element_at_position_in_tab(world, "checkbox", add_menu_pos.x, add_menu_pos.y, tab.kind.clone());
-                            *show_add_menu = false;
-                        }
-                        
-                        if ui.button("🔘 Add Radio Button").clicked() {
-                            add_ui_element_at_position_in_tab(world, "radio_button", add_menu_pos.x, add_menu_pos.y, tab.kind.clone());
-                            *show_add_menu = false;
-                        }
-                        
-                        if ui.button("📦 Add Group Box").clicked() {
-                            add_ui_element_at_position_in_tab(world, "group_box", add_menu_pos.x, add_menu_pos.y, tab.kind.clone());
-                            *show_add_menu = false;
+                        for widget in crate::widgets::current_widgets(world).iter() {
+                            if ui.button(format!("{} Add {}", widget.icon(), widget.display_name())).clicked() {
+                                add_ui_element_at_position_in_tab(world, widget.kind(), add_menu_pos.x, add_menu_pos.y, tab.kind.clone());
+                                *show_add_menu = false;
+                            }
                         }
                         
                         // Close menu if clicked outside or escape pressed
@@ -169,6 +157,27 @@ pub fn render_tab_content(ui: &mut Ui, world: &mut World, tab: &Tab, renaming_en
         TabKind::Preview => {
             render_preview_panel(ui, world, codegen_state, file_dialog);
         }
+        TabKind::DockLayout => {
+            crate::systems::render_dock_layout_panel(ui, world);
+        }
+        TabKind::MenuBar => {
+            crate::systems::render_menu_bar_panel(ui, world);
+        }
+        TabKind::VariantPreview => {
+            crate::systems::render_variant_preview_panel(ui, world);
+        }
+        TabKind::Styles => {
+            crate::systems::render_style_sheet_panel(ui, world);
+        }
+        TabKind::Theme => {
+            crate::systems::render_theme_panel(ui, world);
+        }
+        TabKind::RadioGroups => {
+            crate::systems::render_radio_group_panel(ui, world);
+        }
+        TabKind::Symbols => {
+            crate::systems::render_symbol_panel(ui, world);
+        }
     }
 }
 
@@ -394,27 +403,13 @@ fn render_designer_controls_panel(ui: &mut Ui, world: &mut World) {
             // Get the current tab kind for adding elements
             let current_tab = TabKind::MainWork; // Default to MainWork tab
             
-            ui.horizontal(|ui| {
-                if ui.button("➕ Add Button").clicked() {
-                    add_ui_element_at_position_in_tab(world, "button", 100.0, 100.0, current_tab.clone());
-                }
-                if ui.button("📝 Add Text Input").clicked() {
-                    add_ui_element_at_position_in_tab(world, "text_input", 100.0, 100.0, current_tab.clone());
-                }
-            });
-            
-            ui.horizontal(|ui| {
-                if ui.button("☑️ Add Checkbox").clicked() {
-                    add_ui_element_at_position_in_tab(world, "checkbox", 100.0, 100.0, current_tab.clone());
-                }
-                if ui.button("🔘 Add Radio Button").clicked() {
-                    add_ui_element_at_position_in_tab(world, "radio_button", 100.0, 100.0, current_tab.clone());
+            ui.horizontal_wrapped(|ui| {
+                for widget in crate::widgets::current_widgets(world).iter() {
+                    if ui.button(format!("{} Add {}", widget.icon(), widget.display_name())).clicked() {
+                        add_ui_element_at_position_in_tab(world, widget.kind(), 100.0, 100.0, current_tab.clone());
+                    }
                 }
             });
-            
-            if ui.button("📦 Add Group Box").clicked() {
-                add_ui_element_at_position_in_tab(world, "group_box", 100.0, 100.0, current_tab);
-            }
         });
         
         ui.add_space(10.0);
@@ -440,7 +435,7 @@ fn render_designer_controls_panel(ui: &mut Ui, world: &mut World) {
                 }
                 
                 if ui.button("📋 Generate Panel Function").clicked() {
-                    let panel_code = crate::codegen::CodeGenerator::generate_panel_function(world, "Generated");
+                    let panel_code = crate::codegen::CodeGenerator::generate_panel_functions(world);
                     
                     // Save to clipboard
                     ui.ctx().copy_text(panel_code.clone());
@@ -449,7 +444,7 @@ fn render_designer_controls_panel(ui: &mut Ui, world: &mut World) {
                     if let Err(e) = std::fs::write("generated_panel.rs", &panel_code) {
                         add_designer_log(world, &format!("Failed to save panel code: {}", e));
                     } else {
-                        add_designer_log(world, "Generated panel function saved to generated_panel.rs and copied to clipboard");
+                        add_designer_log(world, "Generated panel functions saved to generated_panel.rs and copied to clipboard");
                     }
                 }
             });
@@ -497,99 +492,14 @@ fn render_inspector_panel(ui: &mut Ui, world: &mut World) {
                 ui.label(format!("Item {} of {}:", i + 1, selected_count));
             }
             
-            // Show editable properties based on component type
-            if let Some(mut button) = world.get_mut::<UiButton>(*entity) {
-                ui.label("🔘 Button Properties:");
-                ui.horizontal(|ui| {
-                    ui.label("Label:");
-                    ui.text_edit_singleline(&mut button.label);
-                });
-                ui.horizontal(|ui| {
-                    ui.label("Font Size:");
-                    ui.add(egui::DragValue::new(&mut button.font_size).range(8.0..=48.0));
-                });
-                ui.checkbox(&mut button.enabled, "Enabled");
-                
-            } else if let Some(mut text_input) = world.get_mut::<UiTextInput>(*entity) {
-                ui.label("📝 Text Input Properties:");
-                ui.horizontal(|ui| {
-                    ui.label("Label:");
-                    ui.text_edit_singleline(&mut text_input.label);
-                });
-                ui.horizontal(|ui| {
-                    ui.label("Value:");
-                    ui.text_edit_singleline(&mut text_input.value);
-                });
-                ui.horizontal(|ui| {
-                    ui.label("Font Size:");
-                    ui.add(egui::DragValue::new(&mut text_input.font_size).range(8.0..=48.0));
-                });
-                ui.checkbox(&mut text_input.enabled, "Enabled");
-                
-            } else if let Some(mut checkbox) = world.get_mut::<UiCheckbox>(*entity) {
-                ui.label("☑️ Checkbox Properties:");
-                ui.horizontal(|ui| {
-                    ui.label("Label:");
-                    ui.text_edit_singleline(&mut checkbox.label);
-                });
-                ui.horizontal(|ui| {
-                    ui.label("Font Size:");
-                    ui.add(egui::DragValue::new(&mut checkbox.font_size).range(8.0..=48.0));
-                });
-                ui.checkbox(&mut checkbox.checked, "Checked");
-                ui.checkbox(&mut checkbox.enabled, "Enabled");
-                
-            } else if let Some(mut radio_button) = world.get_mut::<UiRadioButton>(*entity) {
-                ui.label("🔘 Radio Button Properties:");
-                ui.horizontal(|ui| {
-                    ui.label("Label:");
-                    ui.text_edit_singleline(&mut radio_button.label);
-                });
-                ui.horizontal(|ui| {
-                    ui.label("Group ID:");
-                    ui.text_edit_singleline(&mut radio_button.group_id);
-                });
-                ui.horizontal(|ui| {
-                    ui.label("Font Size:");
-                    ui.add(egui::DragValue::new(&mut radio_button.font_size).range(8.0..=48.0));
-                });
-                ui.checkbox(&mut radio_button.selected, "Selected");
-                ui.checkbox(&mut radio_button.enabled, "Enabled");
-                
-            } else if let Some(mut group_box) = world.get_mut::<UiGroupBox>(*entity) {
-                ui.label("📦 Group Box Properties:");
-                ui.horizontal(|ui| {
-                    ui.label("Label:");
-                    ui.text_edit_singleline(&mut group_box.label);
-                });
-                ui.horizontal(|ui| {
-                    ui.label("Font Size:");
-                    ui.add(egui::DragValue::new(&mut group_box.font_size).range(8.0..=48.0));
-                });
-                ui.checkbox(&mut group_box.enabled, "Enabled");
-                ui.label(format!("Contains {} widgets", group_box.contained_widgets.len()));
-            }
-            
-            // Show and allow editing position and size
-            if let Some(mut pos) = world.get_mut::<UiElementPosition>(*entity) {
-                ui.separator();
-                ui.label("📍 Position & Size:");
-                ui.horizontal(|ui| {
-                    ui.label("X:");
-                    ui.add(egui::DragValue::new(&mut pos.x).range(0.0..=2000.0));
-                    ui.label("Y:");
-                    ui.add(egui::DragValue::new(&mut pos.y).range(0.0..=2000.0));
-                });
-            }
-            
-            if let Some(mut size) = world.get_mut::<UiElementSize>(*entity) {
-                ui.horizontal(|ui| {
-                    ui.label("Width:");
-                    ui.add(egui::DragValue::new(&mut size.width).range(10.0..=500.0));
-                    ui.label("Height:");
-                    ui.add(egui::DragValue::new(&mut size.height).range(10.0..=500.0));
-                });
+            // Editors for the reflected components of the element
+            crate::systems::render_reflected_components(ui, world, *entity);
+            let widget = crate::widgets::current_widgets(world).widget_of(world.entity(*entity)).cloned();
+            if let Some(widget) = widget {
+                widget.render_inspector(ui, world, *entity);
             }
+
+            crate::systems::render_style_properties(ui, world, *entity);
             
             // Show tab assignment
             if let Some(tab) = world.get::<UiElementTab>(*entity) {
@@ -848,12 +758,17 @@ fn render_highlighted_code_cached(ui: &mut egui::Ui, code: &str) {
 fn render_group_properties(ui: &mut Ui, world: &mut World, selected_entities: &[Entity]) {
     ui.separator();
     ui.label(format!("📦 Group Properties ({} items)", selected_entities.len()));
+    
+    // Properties the selected elements share; edits apply to the whole selection
+    crate::systems::render_shared_reflected_components(ui, world, selected_entities);
+    crate::systems::render_group_style_properties(ui, world, selected_entities);
     ui.separator();
     
     // Collect common properties from all selected entities
     let mut common_positions = Vec::new();
     let mut common_sizes = Vec::new();
     let mut common_enabled_states = Vec::new();
+    let widgets = crate::widgets::current_widgets(world);
     
     for entity in selected_entities {
         if let Some(pos) = world.get::<UiElementPosition>(*entity) {
@@ -863,20 +778,8 @@ fn render_group_properties(ui: &mut Ui, world: &mut World, selected_entities: &[
             common_sizes.push((*entity, size.width, size.height));
         }
         
-        // Check if entity has an enabled property
-        let enabled = if let Some(button) = world.get::<UiButton>(*entity) {
-            Some(button.enabled)
-        } else if let Some(text_input) = world.get::<UiTextInput>(*entity) {
-            Some(text_input.enabled)
-        } else if let Some(checkbox) = world.get::<UiCheckbox>(*entity) {
-            Some(checkbox.enabled)
-        } else if let Some(radio) = world.get::<UiRadioButton>(*entity) {
-            Some(radio.enabled)
-        } else if let Some(group_box) = world.get::<UiGroupBox>(*entity) {
-            Some(group_box.enabled)
-        } else {
-            None
-        };
+        // Every widget type has an enabled property
+        let enabled = widgets.widget_of(world.entity(*entity)).map(|widget| widget.enabled(world.entity(*entity)));
         
         if let Some(enabled) = enabled {
             common_enabled_states.push((*entity, enabled));
@@ -1008,36 +911,14 @@ fn render_group_properties(ui: &mut Ui, world: &mut World, selected_entities: &[
             ui.horizontal(|ui| {
                 if ui.button("✅ Enable All").clicked() {
                     for (entity, _) in &common_enabled_states {
-                        // Update the enabled state for each component type
-                        if let Some(mut button) = world.get_mut::<UiButton>(*entity) {
-                            button.enabled = true;
-                        } else if let Some(mut text_input) = world.get_mut::<UiTextInput>(*entity) {
-                            text_input.enabled = true;
-                        } else if let Some(mut checkbox) = world.get_mut::<UiCheckbox>(*entity) {
-                            checkbox.enabled = true;
-                        } else if let Some(mut radio) = world.get_mut::<UiRadioButton>(*entity) {
-                            radio.enabled = true;
-                        } else if let Some(mut group_box) = world.get_mut::<UiGroupBox>(*entity) {
-                            group_box.enabled = true;
-                        }
+                        set_element_enabled(world, &widgets, *entity, true);
                     }
                     add_designer_log(world, &format!("Enabled {} elements", common_enabled_states.len()));
                 }
                 
                 if ui.button("❌ Disable All").clicked() {
                     for (entity, _) in &common_enabled_states {
-                        // Update the enabled state for each component type
-                        if let Some(mut button) = world.get_mut::<UiButton>(*entity) {
-                            button.enabled = false;
-                        } else if let Some(mut text_input) = world.get_mut::<UiTextInput>(*entity) {
-                            text_input.enabled = false;
-                        } else if let Some(mut checkbox) = world.get_mut::<UiCheckbox>(*entity) {
-                            checkbox.enabled = false;
-                        } else if let Some(mut radio) = world.get_mut::<UiRadioButton>(*entity) {
-                            radio.enabled = false;
-                        } else if let Some(mut group_box) = world.get_mut::<UiGroupBox>(*entity) {
-                            group_box.enabled = false;
-                        }
+                        set_element_enabled(world, &widgets, *entity, false);
                     }
                     add_designer_log(world, &format!("Disabled {} elements", common_enabled_states.len()));
                 }
@@ -1045,18 +926,7 @@ fn render_group_properties(ui: &mut Ui, world: &mut World, selected_entities: &[
                 if ui.button("🔄 Toggle All").clicked() {
                     for (entity, current_enabled) in &common_enabled_states {
                         let new_enabled = !current_enabled;
-                        // Update the enabled state for each component type
-                        if let Some(mut button) = world.get_mut::<UiButton>(*entity) {
-                            button.enabled = new_enabled;
-                        } else if let Some(mut text_input) = world.get_mut::<UiTextInput>(*entity) {
-                            text_input.enabled = new_enabled;
-                        } else if let Some(mut checkbox) = world.get_mut::<UiCheckbox>(*entity) {
-                            checkbox.enabled = new_enabled;
-                        } else if let Some(mut radio) = world.get_mut::<UiRadioButton>(*entity) {
-                            radio.enabled = new_enabled;
-                        } else if let Some(mut group_box) = world.get_mut::<UiGroupBox>(*entity) {
-                            group_box.enabled = new_enabled;
-                        }
+                        set_element_enabled(world, &widgets, *entity, new_enabled);
                     }
                     add_designer_log(world, &format!("Toggled enabled state for {} elements", common_enabled_states.len()));
                 }
@@ -1079,114 +949,23 @@ fn render_group_properties(ui: &mut Ui, world: &mut World, selected_entities: &[
 }
 
 
-fn add_ui_element_at_position_in_tab(world: &mut World, element_type: &str, x: f32, y: f32, tab_kind: TabKind) {
-    match element_type {
-        "button" => {
-            let button_count = world.query::<&UiButton>().iter(world).count();
-            world.spawn(UiButtonBundle {
-                button: UiButton {
-                    label: format!("Button {}", button_count + 1),
-                    clicked: false,
-                    enabled: true,
-                    click_time: None,
-                    font_size: 14.0,
-                },
-                tab: UiElementTab {
-                    tab_kind,
-                    position: 0,
-                },
-                position: UiElementPosition { x, y },
-                size: UiElementSize::default(),
-                selected: UiElementSelected::default(),
-                container: UiElementContainer { parent_group: None },
-            });
-            add_designer_log(world, &format!("Added Button {} at ({:.0}, {:.0})", button_count + 1, x, y));
-        }
-        "text_input" => {
-            let input_count = world.query::<&UiTextInput>().iter(world).count();
-            world.spawn(UiTextInputBundle {
-                text_input: UiTextInput {
-                    label: format!("Input {}", input_count + 1),
-                    value: String::new(),
-                    enabled: true,
-                    font_size: 14.0,
-                },
-                tab: UiElementTab {
-                    tab_kind,
-                    position: 0,
-                },
-                position: UiElementPosition { x, y },
-                size: UiElementSize { width: 200.0, height: 30.0 },
-                selected: UiElementSelected::default(),
-                container: UiElementContainer { parent_group: None },
-            });
-            add_designer_log(world, &format!("Added Text Input {} at ({:.0}, {:.0})", input_count + 1, x, y));
-        }
-        "checkbox" => {
-            let checkbox_count = world.query::<&UiCheckbox>().iter(world).count();
-            world.spawn(UiCheckboxBundle {
-                checkbox: UiCheckbox {
-                    label: format!("Checkbox {}", checkbox_count + 1),
-                    checked: false,
-                    enabled: true,
-                    font_size: 14.0,
-                },
-                tab: UiElementTab {
-                    tab_kind,
-                    position: 0,
-                },
-                position: UiElementPosition { x, y },
-                size: UiElementSize::default(),
-                selected: UiElementSelected::default(),
-                container: UiElementContainer { parent_group: None },
-            });
-            add_designer_log(world, &format!("Added Checkbox {} at ({:.0}, {:.0})", checkbox_count + 1, x, y));
-        }
-        "radio_button" => {
-            let radio_count = world.query::<&UiRadioButton>().iter(world).count();
-            world.spawn(UiRadioButtonBundle {
-                radio_button: UiRadioButton {
-                    label: format!("Radio {}", radio_count + 1),
-                    selected: false,
-                    enabled: true,
-                    font_size: 14.0,
-                    group_id: "default_group".to_string(),
-                },
-                tab: UiElementTab {
-                    tab_kind,
-                    position: 0,
-                },
-                position: UiElementPosition { x, y },
-                size: UiElementSize::default(),
-                selected: UiElementSelected::default(),
-                container: UiElementContainer { parent_group: None },
-            });
-            add_designer_log(world, &format!("Added Radio Button {} at ({:.0}, {:.0})", radio_count + 1, x, y));
-        }
-        "group_box" => {
-            let group_count = world.query::<&UiGroupBox>().iter(world).count();
-            world.spawn(UiGroupBoxBundle {
-                group_box: UiGroupBox {
-                    label: format!("Group {}", group_count + 1),
-                    enabled: true,
-                    font_size: 14.0,
-                    contained_widgets: Vec::new(),
-                },
-                tab: UiElementTab {
-                    tab_kind,
-                    position: 0,
-                },
-                position: UiElementPosition { x, y },
-                size: UiElementSize { width: 200.0, height: 150.0 },
-                selected: UiElementSelected::default(),
-                container: UiElementContainer { parent_group: None },
-            });
-            add_designer_log(world, &format!("Added Group Box {} at ({:.0}, {:.0})", group_count + 1, x, y));
-        }
-        _ => {}
+fn set_element_enabled(world: &mut World, widgets: &crate::widgets::WidgetRegistry, entity: Entity, enabled: bool) {
+    let widget = widgets.widget_of(world.entity(entity));
+    if let Some(widget) = widget {
+        widget.set_enabled(&mut world.entity_mut(entity), enabled);
     }
 }
 
+fn add_ui_element_at_position_in_tab(world: &mut World, element_type: &str, x: f32, y: f32, tab_kind: TabKind) {
+    let page_id = active_page_id(world);
+    let Some(widget) = crate::widgets::current_widgets(world).get(element_type).cloned() else {
+        return;
+    };
+    let entity = crate::widgets::spawn_widget(world, widget.as_ref(), x, y, tab_kind, page_id);
+    let label = widget.label(world.entity(entity));
+    add_designer_log(world, &format!("Added {} at ({:.0}, {:.0})", label, x, y));
+}
+
 fn handle_drag_selection_in_work_area(work_area_response: egui::Response, world: &mut World, drag_selection: &mut Option<crate::integration::DragSelection>) {
     // Start drag selection on drag_started (more reliable than clicked)
     if work_area_response.drag_started() {
@@ -1260,15 +1039,16 @@ fn handle_drag_selection_in_work_area(work_area_response: egui::Response, world:
 }
 
 fn select_elements_in_rect(world: &mut World, rect: egui::Rect) {
-    let mut query = world.query::<(Entity, &UiElementPosition, &UiElementSize, &mut UiElementSelected)>();
-    for (_, pos, size, mut selected) in query.iter_mut(world) {
+    let active_page = active_page_id(world);
+    let mut query = world.query_filtered::<(Entity, &UiElementPosition, &UiElementSize, &mut UiElementSelected, Option<&UiElementPage>), Without<UiSymbolCopy>>();
+    for (_, pos, size, mut selected, page) in query.iter_mut(world) {
         let element_rect = egui::Rect::from_min_size(
             egui::Pos2::new(pos.x, pos.y),
             egui::Vec2::new(size.width, size.height)
         );
         
-        // Check if element is within selection rectangle
-        selected.selected = rect.intersects(element_rect);
+        // Check if element is within selection rectangle on the visible page
+        selected.selected = is_on_page(page, active_page) && rect.intersects(element_rect);
     }
 }
 
@@ -0,0 +1,71 @@
+use egui::{Color32, RichText};
+use crate::templates::{template_title, TemplateGallery};
+
+/// Design picked in the template gallery
+pub enum GalleryChoice {
+    Blank,
+    Template(String),
+}
+
+/// Welcome screen and File → New dialog: start a blank design or one of the gallery's templates
+pub fn render_template_gallery(ctx: &egui::Context, gallery: &mut TemplateGallery, open: &mut bool) -> Option<GalleryChoice> {
+    let mut choice = None;
+    let mut reload = false;
+    egui::Window::new("🆕 Start a New Design")
+        .open(open)
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .order(egui::Order::Foreground)
+        .show(ctx, |ui| {
+            ui.label("Pick a starter design. The current design is replaced.");
+            ui.add_space(6.0);
+            egui::ScrollArea::vertical().max_height(420.0).show(ui, |ui| {
+                egui::Grid::new("template_gallery").num_columns(3).spacing([8.0, 8.0]).show(ui, |ui| {
+                    let mut column = 0;
+                    let mut card = |ui: &mut egui::Ui, title: &str, description: &str, elements: usize| {
+                        let mut clicked = false;
+                        egui::Frame::group(ui.style()).show(ui, |ui| {
+                            ui.set_width(180.0);
+                            ui.set_min_height(90.0);
+                            ui.label(RichText::new(title).strong());
+                            ui.weak(description);
+                            ui.weak(format!("{} elements", elements));
+                            clicked = ui.button("Create").clicked();
+                        });
+                        column += 1;
+                        if column % 3 == 0 {
+                            ui.end_row();
+                        }
+                        clicked
+                    };
+                    if card(ui, "Blank Design", "An empty canvas", 0) {
+                        choice = Some(GalleryChoice::Blank);
+                    }
+                    for template in gallery.templates() {
+                        let elements = gallery.elements(&template.name).len();
+                        if card(ui, &template_title(&template), &template.description, elements) {
+                            choice = Some(GalleryChoice::Template(template.name.clone()));
+                        }
+                    }
+                });
+            });
+            for error in &gallery.errors {
+                ui.colored_label(Color32::YELLOW, format!("⚠ {}", error));
+            }
+            ui.separator();
+            ui.horizontal(|ui| {
+                ui.weak(format!("Template files: {}", crate::templates::get_templates_dir().display()));
+                if ui.button("🔄 Reload").clicked() {
+                    reload = true;
+                }
+            });
+        });
+    if reload {
+        *gallery = TemplateGallery::load();
+    }
+    if choice.is_some() {
+        *open = false;
+    }
+    choice
+}
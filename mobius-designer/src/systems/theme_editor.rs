@@ -0,0 +1,170 @@
+use bevy_ecs::prelude::*;
+use egui::{Color32, Ui, Vec2};
+use crate::components::*;
+
+/// Editor for the global theme of the generated application, with a live sample
+pub fn render_theme_panel(ui: &mut Ui, world: &mut World) {
+    ui.heading("🌗 Theme");
+    ui.label("Palette, spacing, rounding and text sizes applied by the generated apply_theme()");
+    ui.separator();
+
+    let Some(mut theme) = ({
+        let mut query = world.query::<&ThemeDesign>();
+        query.iter(world).next().cloned()
+    }) else {
+        ui.label("No theme found");
+        return;
+    };
+    let original = theme.clone();
+
+    ui.horizontal(|ui| {
+        ui.label("Reset to:");
+        if ui.button("🌙 egui Dark").clicked() {
+            theme = ThemeDesign::from_visuals(egui::Visuals::dark());
+        }
+        if ui.button("☀ egui Light").clicked() {
+            theme = ThemeDesign::from_visuals(egui::Visuals::light());
+        }
+    });
+    ui.add_space(4.0);
+
+    ui.columns(2, |columns| {
+        egui::ScrollArea::vertical()
+            .id_salt("theme_editor_scroll")
+            .show(&mut columns[0], |ui| render_theme_properties(ui, &mut theme));
+
+        let ui = &mut columns[1];
+        ui.label("Live preview:");
+        render_theme_sample(ui, &theme);
+    });
+
+    if theme != original {
+        let mut query = world.query::<&mut ThemeDesign>();
+        if let Some(mut stored) = query.iter_mut(world).next() {
+            *stored = theme;
+        }
+    }
+}
+
+fn render_theme_properties(ui: &mut Ui, theme: &mut ThemeDesign) {
+    egui::CollapsingHeader::new("Palette")
+        .id_salt("theme_palette")
+        .default_open(true)
+        .show(ui, |ui| {
+            ui.checkbox(&mut theme.dark_base, "Based on dark visuals");
+            egui::Grid::new("theme_palette_grid").num_columns(2).show(ui, |ui| {
+                for (label, color) in [
+                    ("Panel fill", &mut theme.panel_fill),
+                    ("Window fill", &mut theme.window_fill),
+                    ("Text edit background", &mut theme.extreme_bg_color),
+                    ("Stripe background", &mut theme.faint_bg_color),
+                    ("Widget fill", &mut theme.widget_fill),
+                    ("Hovered widget", &mut theme.hovered_fill),
+                    ("Active widget", &mut theme.active_fill),
+                    ("Selection", &mut theme.selection),
+                    ("Hyperlink", &mut theme.hyperlink),
+                    ("Warning", &mut theme.warn),
+                    ("Error", &mut theme.error),
+                ] {
+                    ui.label(label);
+                    egui::color_picker::color_edit_button_srgba(ui, color, egui::color_picker::Alpha::OnlyBlend);
+                    ui.end_row();
+                }
+
+                let mut override_text = theme.text_color.is_some();
+                ui.checkbox(&mut override_text, "Text color");
+                let mut text_color = theme.text_color.unwrap_or(if theme.dark_base { Color32::from_gray(220) } else { Color32::from_gray(30) });
+                ui.add_enabled_ui(override_text, |ui| {
+                    egui::color_picker::color_edit_button_srgba(ui, &mut text_color, egui::color_picker::Alpha::Opaque);
+                });
+                theme.text_color = override_text.then_some(text_color);
+                ui.end_row();
+            });
+        });
+
+    egui::CollapsingHeader::new("Spacing & Rounding")
+        .id_salt("theme_spacing")
+        .default_open(true)
+        .show(ui, |ui| {
+            egui::Grid::new("theme_spacing_grid").num_columns(2).show(ui, |ui| {
+                vec2_row(ui, "Item spacing", &mut theme.item_spacing);
+                vec2_row(ui, "Button padding", &mut theme.button_padding);
+                ui.label("Window margin");
+                ui.add(egui::DragValue::new(&mut theme.window_margin).range(0.0..=64.0));
+                ui.end_row();
+                ui.label("Indent");
+                ui.add(egui::DragValue::new(&mut theme.indent).range(0.0..=64.0));
+                ui.end_row();
+                ui.label("Widget corner radius");
+                ui.add(egui::DragValue::new(&mut theme.widget_corner_radius).range(0.0..=32.0));
+                ui.end_row();
+                ui.label("Window corner radius");
+                ui.add(egui::DragValue::new(&mut theme.window_corner_radius).range(0.0..=32.0));
+                ui.end_row();
+            });
+        });
+
+    egui::CollapsingHeader::new("Text Styles")
+        .id_salt("theme_text")
+        .default_open(true)
+        .show(ui, |ui| {
+            egui::Grid::new("theme_text_grid").num_columns(2).show(ui, |ui| {
+                let sizes = &mut theme.text_sizes;
+                for (label, size) in [
+                    ("Small", &mut sizes.small),
+                    ("Body", &mut sizes.body),
+                    ("Button", &mut sizes.button),
+                    ("Heading", &mut sizes.heading),
+                    ("Monospace", &mut sizes.monospace),
+                ] {
+                    ui.label(label);
+                    ui.add(egui::DragValue::new(size).range(6.0..=64.0).speed(0.2).suffix(" pt"));
+                    ui.end_row();
+                }
+            });
+        });
+}
+
+fn vec2_row(ui: &mut Ui, label: &str, value: &mut Vec2) {
+    ui.label(label);
+    ui.horizontal(|ui| {
+        ui.add(egui::DragValue::new(&mut value.x).range(0.0..=64.0).prefix("x: "));
+        ui.add(egui::DragValue::new(&mut value.y).range(0.0..=64.0).prefix("y: "));
+    });
+    ui.end_row();
+}
+
+/// A few common widgets drawn with the theme's style
+fn render_theme_sample(ui: &mut Ui, theme: &ThemeDesign) {
+    ui.scope(|ui| {
+        ui.set_style(theme.style());
+        egui::Frame::window(ui.style()).show(ui, |ui| {
+            ui.set_min_width(220.0);
+            ui.heading("Heading");
+            ui.label("Body text in the themed panel.");
+            ui.small("Small text");
+            ui.code("monospace()");
+            ui.separator();
+            ui.horizontal(|ui| {
+                let _ = ui.button("Button");
+                let _ = ui.add_enabled(false, egui::Button::new("Disabled"));
+            });
+            let mut text = "Text edit".to_string();
+            ui.text_edit_singleline(&mut text);
+            let mut checked = true;
+            ui.checkbox(&mut checked, "Checkbox");
+            ui.horizontal(|ui| {
+                let _ = ui.radio(true, "Selected");
+                let _ = ui.radio(false, "Other");
+            });
+            let mut value = 0.4;
+            ui.add(egui::Slider::new(&mut value, 0.0..=1.0));
+            ui.hyperlink_to("Hyperlink", "https://github.com/emilk/egui");
+            ui.colored_label(ui.visuals().warn_fg_color, "Warning text");
+            ui.colored_label(ui.visuals().error_fg_color, "Error text");
+            ui.collapsing("Indented section", |ui| {
+                ui.label("Indented content");
+            });
+        });
+    });
+}
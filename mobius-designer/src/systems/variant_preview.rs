@@ -0,0 +1,334 @@
+use bevy_ecs::prelude::*;
+use egui::{Rect, Ui, Vec2};
+use crate::components::*;
+use crate::codegen_thread::UiElementType;
+use crate::utils::*;
+use crate::widgets::current_widgets;
+
+/// A design element as drawn in a preview variant
+struct PreviewElement {
+    label: String,
+    kind: PreviewElementKind,
+    rect: Rect,
+    font_size: f32,
+    enabled: bool,
+}
+
+enum PreviewElementKind {
+    Button,
+    TextInput { value: String },
+    Checkbox { checked: bool },
+    RadioButton { selected: bool },
+    GroupBox,
+    /// Registered widget type, shown as a labelled placeholder of its designed size
+    Custom { icon: &'static str },
+}
+
+/// Renders the active page under several visuals, display scales and font scales side by side
+pub fn render_variant_preview_panel(ui: &mut Ui, world: &mut World) {
+    ui.heading("🎨 Variant Preview");
+    ui.label("The current page under different themes, display scales and text sizes");
+    ui.separator();
+
+    let Some(mut settings) = ({
+        let mut query = world.query::<&PreviewVariants>();
+        query.iter(world).next().cloned()
+    }) else {
+        ui.label("No preview variants found");
+        return;
+    };
+    let original_variants = settings.variants.clone();
+    let original_custom = settings.custom.clone();
+
+    render_variant_toolbar(ui, &mut settings);
+    ui.separator();
+
+    let project_theme = world.query::<&ThemeDesign>().iter(world).next().cloned().unwrap_or_default();
+    let elements = collect_page_elements(world);
+    if elements.is_empty() {
+        ui.label("The current page has no elements to preview");
+    } else {
+        let bounds = elements.iter()
+            .fold(Rect::NOTHING, |bounds, element| bounds.union(element.rect))
+            .expand(10.0);
+
+        egui::ScrollArea::both()
+            .id_salt("variant_preview_scroll")
+            .show(ui, |ui| {
+                ui.horizontal_top(|ui| {
+                    for (index, variant) in settings.variants.iter().enumerate() {
+                        ui.push_id(("preview_variant", index), |ui| {
+                            ui.vertical(|ui| {
+                                ui.strong(variant.title());
+                                let style = settings.style_for(variant.theme, &project_theme, ui.style());
+                                let issues = render_variant(ui, variant, &style, &elements, bounds);
+                                ui.set_max_width((bounds.width() * variant_zoom(ui, variant)).max(200.0));
+                                if issues.is_empty() {
+                                    ui.colored_label(egui::Color32::from_rgb(100, 200, 100), "✔ No clipping or overlaps");
+                                }
+                                for issue in issues {
+                                    ui.colored_label(egui::Color32::from_rgb(255, 120, 80), format!("⚠ {}", issue));
+                                }
+                            });
+                        });
+                        ui.add_space(12.0);
+                    }
+                });
+            });
+    }
+
+    if settings.variants != original_variants || settings.custom != original_custom {
+        let mut query = world.query::<&mut PreviewVariants>();
+        if let Some(mut stored) = query.iter_mut(world).next() {
+            *stored = settings;
+        }
+    }
+}
+
+fn render_variant_toolbar(ui: &mut Ui, settings: &mut PreviewVariants) {
+    let mut remove = None;
+    for (index, variant) in settings.variants.iter_mut().enumerate() {
+        ui.horizontal(|ui| {
+            ui.label(format!("Variant {}:", index + 1));
+            egui::ComboBox::from_id_salt(("preview_theme", index))
+                .selected_text(variant.theme.name())
+                .show_ui(ui, |ui| {
+                    for theme in [PreviewTheme::Dark, PreviewTheme::Light, PreviewTheme::Custom, PreviewTheme::Project] {
+                        ui.selectable_value(&mut variant.theme, theme, theme.name());
+                    }
+                });
+            ui.add(egui::DragValue::new(&mut variant.pixels_per_point)
+                .range(0.5..=4.0)
+                .speed(0.05)
+                .suffix(" ppp"));
+            ui.add(egui::Slider::new(&mut variant.font_scale, 0.5..=3.0).text("text scale"));
+            if ui.small_button("🗑").clicked() {
+                remove = Some(index);
+            }
+        });
+    }
+    if let Some(index) = remove {
+        settings.variants.remove(index);
+    }
+
+    ui.horizontal(|ui| {
+        if ui.button("➕ Add Variant").clicked() {
+            let variant = settings.variants.last().cloned().unwrap_or(PreviewVariant {
+                theme: PreviewTheme::Dark,
+                pixels_per_point: 1.0,
+                font_scale: 1.0,
+            });
+            settings.variants.push(variant);
+        }
+    });
+
+    if settings.variants.iter().any(|variant| variant.theme == PreviewTheme::Custom) {
+        egui::CollapsingHeader::new("Custom visuals")
+            .id_salt("preview_custom_visuals")
+            .show(ui, |ui| {
+                let custom = &mut settings.custom;
+                ui.checkbox(&mut custom.dark_base, "Based on dark visuals");
+                egui::Grid::new("preview_custom_colors").num_columns(2).show(ui, |ui| {
+                    for (label, color) in [
+                        ("Panel fill", &mut custom.panel_fill),
+                        ("Text", &mut custom.text_color),
+                        ("Widget fill", &mut custom.widget_fill),
+                        ("Accent", &mut custom.accent),
+                    ] {
+                        ui.label(label);
+                        egui::color_picker::color_edit_button_srgba(ui, color, egui::color_picker::Alpha::Opaque);
+                        ui.end_row();
+                    }
+                });
+            });
+    }
+}
+
+fn collect_page_elements(world: &mut World) -> Vec<PreviewElement> {
+    let active_page = active_page_id(world);
+    let mut elements = Vec::new();
+    let rect_of = |pos: &UiElementPosition, size: &UiElementSize| {
+        Rect::from_min_size(egui::pos2(pos.x, pos.y), Vec2::new(size.width, size.height))
+    };
+
+    let widgets = current_widgets(world);
+    let mut query = world.query::<(Entity, &UiElementPosition, &UiElementSize, Option<&UiElementPage>)>();
+    for (entity, pos, size, page) in query.iter(world) {
+        if !is_on_page(page, active_page) {
+            continue;
+        }
+        let element = world.entity(entity);
+        let Some(widget) = widgets.widget_of(element) else {
+            continue;
+        };
+        let Some(element_type) = widget.snapshot(element) else {
+            continue;
+        };
+        let kind = match &element_type {
+            UiElementType::Button { .. } => PreviewElementKind::Button,
+            UiElementType::TextInput { value, .. } => PreviewElementKind::TextInput { value: value.clone() },
+            UiElementType::Checkbox { checked, .. } => PreviewElementKind::Checkbox { checked: *checked },
+            UiElementType::RadioButton { selected, .. } => PreviewElementKind::RadioButton { selected: *selected },
+            UiElementType::GroupBox { .. } => PreviewElementKind::GroupBox,
+            UiElementType::Custom(_) => PreviewElementKind::Custom { icon: widget.icon() },
+        };
+        elements.push(PreviewElement {
+            label: element_type.label().to_string(),
+            kind,
+            rect: rect_of(pos, size),
+            font_size: element_type.font_size(),
+            enabled: element_type.enabled(),
+        });
+    }
+
+    // Group boxes first so the elements inside them are drawn on top
+    elements.sort_by_key(|element| !matches!(element.kind, PreviewElementKind::GroupBox));
+    elements
+}
+
+/// Scale at which a variant is drawn relative to the designer's own display
+fn variant_zoom(ui: &Ui, variant: &PreviewVariant) -> f32 {
+    variant.pixels_per_point / ui.ctx().pixels_per_point()
+}
+
+/// Draw one variant and return the problems found in it
+fn render_variant(
+    ui: &mut Ui,
+    variant: &PreviewVariant,
+    style: &egui::Style,
+    elements: &[PreviewElement],
+    bounds: Rect,
+) -> Vec<String> {
+    let zoom = variant_zoom(ui, variant);
+    let mut scene_rect = bounds;
+
+    ui.allocate_ui(bounds.size() * zoom, |ui| {
+        ui.set_min_size(bounds.size() * zoom);
+        egui::Scene::new()
+            .zoom_range(0.1..=8.0)
+            .sense(egui::Sense::hover())
+            .show(ui, &mut scene_rect, |ui| {
+                ui.set_style(style.clone());
+                for font_id in ui.style_mut().text_styles.values_mut() {
+                    font_id.size *= variant.font_scale;
+                }
+                ui.painter().rect_filled(bounds, 0.0, style.visuals.panel_fill);
+
+                let rendered: Vec<(&PreviewElement, Rect)> = elements.iter()
+                    .map(|element| (element, render_preview_element(ui, element, variant.font_scale)))
+                    .collect();
+
+                let (issues, flagged) = find_layout_issues(&rendered);
+                for rect in flagged {
+                    ui.painter().rect_stroke(rect.expand(1.0), 2.0, (2.0, egui::Color32::RED), egui::StrokeKind::Outside);
+                }
+                issues
+            })
+            .inner
+    })
+    .inner
+}
+
+/// Draw an element inside its designed rect and return the rect it actually needed
+fn render_preview_element(ui: &mut Ui, element: &PreviewElement, font_scale: f32) -> Rect {
+    let text = if element.font_size > 0.0 {
+        egui::RichText::new(&element.label).size(element.font_size * font_scale)
+    } else {
+        egui::RichText::new(&element.label)
+    };
+    let builder = egui::UiBuilder::new()
+        .max_rect(element.rect)
+        .layout(egui::Layout::top_down(egui::Align::Min));
+
+    ui.scope_builder(builder, |ui| {
+        if !element.enabled {
+            ui.disable();
+        }
+        match &element.kind {
+            PreviewElementKind::Button => {
+                ui.add_sized(element.rect.size(), egui::Button::new(text)).rect
+            }
+            PreviewElementKind::TextInput { value } => {
+                let mut value = value.clone();
+                let label = ui.label(text).rect;
+                let edit = ui.add(egui::TextEdit::singleline(&mut value).desired_width(element.rect.width())).rect;
+                label.union(edit)
+            }
+            PreviewElementKind::Checkbox { checked } => {
+                let mut checked = *checked;
+                ui.checkbox(&mut checked, text).rect
+            }
+            PreviewElementKind::RadioButton { selected } => {
+                ui.radio(*selected, text).rect
+            }
+            PreviewElementKind::GroupBox => {
+                let stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+                let corner_radius = ui.visuals().widgets.noninteractive.corner_radius;
+                ui.painter().rect_stroke(element.rect, corner_radius, stroke, egui::StrokeKind::Inside);
+                let title = ui.scope_builder(
+                    egui::UiBuilder::new().max_rect(element.rect.shrink(6.0)),
+                    |ui| ui.label(text).rect,
+                ).inner;
+                // The frame keeps its designed size; only a title that does not fit grows the rect
+                element.rect.union(title.expand(6.0))
+            }
+            PreviewElementKind::Custom { icon } => {
+                let stroke = ui.visuals().widgets.inactive.bg_stroke;
+                ui.painter().rect_stroke(element.rect, 2.0, stroke, egui::StrokeKind::Inside);
+                let label = ui.scope_builder(
+                    egui::UiBuilder::new().max_rect(element.rect.shrink(4.0)),
+                    |ui| ui.horizontal(|ui| {
+                        ui.label(*icon);
+                        ui.label(text);
+                    }).response.rect,
+                ).inner;
+                element.rect.union(label.expand(4.0))
+            }
+        }
+    })
+    .inner
+}
+
+/// Clipped elements need more room than designed; overlapping elements share space
+/// or cross the border of a group box.
+fn find_layout_issues(rendered: &[(&PreviewElement, Rect)]) -> (Vec<String>, Vec<Rect>) {
+    const TOLERANCE: f32 = 0.5;
+    let mut issues = Vec::new();
+    let mut flagged = Vec::new();
+
+    for (element, actual) in rendered {
+        if actual.width() > element.rect.width() + TOLERANCE || actual.height() > element.rect.height() + TOLERANCE {
+            issues.push(format!("'{}' is clipped: needs {:.0}×{:.0}, has {:.0}×{:.0}",
+                element.label, actual.width(), actual.height(), element.rect.width(), element.rect.height()));
+            flagged.push(*actual);
+        }
+    }
+
+    for (i, (first, first_rect)) in rendered.iter().enumerate() {
+        for (second, second_rect) in rendered.iter().skip(i + 1) {
+            let overlap = first_rect.intersect(*second_rect);
+            if !first_rect.intersects(*second_rect) || overlap.area() <= 1.0 {
+                continue;
+            }
+            let first_is_group = matches!(first.kind, PreviewElementKind::GroupBox);
+            let second_is_group = matches!(second.kind, PreviewElementKind::GroupBox);
+            match (first_is_group, second_is_group) {
+                (true, false) if !first_rect.contains_rect(*second_rect) => {
+                    issues.push(format!("'{}' crosses the border of group '{}'", second.label, first.label));
+                    flagged.push(*second_rect);
+                }
+                (false, true) if !second_rect.contains_rect(*first_rect) => {
+                    issues.push(format!("'{}' crosses the border of group '{}'", first.label, second.label));
+                    flagged.push(*first_rect);
+                }
+                (false, false) => {
+                    issues.push(format!("'{}' overlaps '{}'", first.label, second.label));
+                    flagged.push(overlap);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    (issues, flagged)
+}
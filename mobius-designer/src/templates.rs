@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use bevy_ecs::prelude::*;
+use mobius_ecs::{MobiusTemplate, MobiusTemplateRegistry};
+use serde::{Deserialize, Serialize};
+use crate::components::*;
+use crate::integration::TabKind;
+use crate::widgets::{current_widgets, spawn_widget};
+
+/// Element of a starter design, placed with the widget type registered under `kind`
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TemplateElement {
+    pub kind: String,
+    /// Label of the element; the widget's numbered default when empty
+    #[serde(default)]
+    pub label: String,
+    pub x: f32,
+    pub y: f32,
+    /// Size of the element; the widget's default size when missing
+    #[serde(default)]
+    pub width: Option<f32>,
+    #[serde(default)]
+    pub height: Option<f32>,
+}
+
+impl TemplateElement {
+    pub fn new(kind: &str, label: &str, x: f32, y: f32) -> Self {
+        Self { kind: kind.to_string(), label: label.to_string(), x, y, width: None, height: None }
+    }
+
+    pub fn sized(self, width: f32, height: f32) -> Self {
+        Self { width: Some(width), height: Some(height), ..self }
+    }
+}
+
+/// JSON template file: a `MobiusTemplate` that also lists the elements a new design starts with.
+/// Elements placed inside a container element come after it.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DesignTemplate {
+    #[serde(flatten)]
+    pub template: MobiusTemplate,
+    /// Starter elements; a file without them keeps the elements of the template it replaces
+    #[serde(default)]
+    pub elements: Option<Vec<TemplateElement>>,
+}
+
+/// Templates offered when starting a new design: the `MobiusTemplateRegistry` templates with their
+/// starter elements, extended by the template files in the templates directory
+pub struct TemplateGallery {
+    pub registry: MobiusTemplateRegistry,
+    pub designs: HashMap<String, Vec<TemplateElement>>,
+    /// Template files that could not be read, with the reason
+    pub errors: Vec<String>,
+}
+
+impl TemplateGallery {
+    /// Built-in templates plus the template files in the templates directory
+    pub fn load() -> Self {
+        let mut gallery = Self::builtin();
+        gallery.load_dir(&get_templates_dir());
+        gallery.errors.extend(gallery.registry.check().iter().map(ToString::to_string));
+        gallery
+    }
+
+    pub fn builtin() -> Self {
+        let registry = MobiusTemplateRegistry::default();
+        let designs = registry.get_templates()
+            .keys()
+            .filter_map(|name| Some((name.clone(), starter_elements(name)?)))
+            .collect();
+        Self { registry, designs, errors: Vec::new() }
+    }
+
+    /// Add the template files of a directory; a file replaces a built-in template of the same name
+    pub fn load_dir(&mut self, dir: &Path) {
+        self.errors.extend(self.registry.load_dir(dir).iter().map(ToString::to_string));
+
+        // Starter elements, which JSON template files list next to the template's fields
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+        paths.sort();
+        for path in paths {
+            let design = std::fs::read_to_string(&path)
+                .ok()
+                .and_then(|contents| serde_json::from_str::<DesignTemplate>(&contents).ok());
+            if let Some(DesignTemplate { template, elements: Some(elements) }) = design {
+                self.designs.insert(template.name, elements);
+            }
+        }
+    }
+
+    /// Templates sorted by name, with what they inherit; templates that don't resolve are left out
+    pub fn templates(&self) -> Vec<MobiusTemplate> {
+        let mut templates: Vec<MobiusTemplate> = self.registry.get_templates()
+            .keys()
+            .filter_map(|name| self.registry.resolve(name).ok())
+            .collect();
+        templates.sort_by(|a, b| a.name.cmp(&b.name));
+        templates
+    }
+
+    /// Starter elements of a template; a template without its own starts with its parent's
+    pub fn elements(&self, name: &str) -> &[TemplateElement] {
+        let mut visited = Vec::new();
+        let mut current = Some(name);
+        while let Some(name) = current.filter(|name| !visited.contains(name)) {
+            if let Some(elements) = self.designs.get(name) {
+                return elements;
+            }
+            visited.push(name);
+            current = self.registry.get_templates().get(name).and_then(|template| template.extends.as_deref());
+        }
+        &[]
+    }
+}
+
+/// Directory holding user template files
+pub fn get_templates_dir() -> PathBuf {
+    let mut path = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    path.push(mobius_ecs::TEMPLATES_DIR);
+    path
+}
+
+/// Title shown for a template, e.g. "Login Form" for `login_form`
+pub fn template_title(template: &MobiusTemplate) -> String {
+    template.name
+        .split(['_', '-', ' '])
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map(|first| first.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Place a template's elements on the first page of the main work area.
+/// Returns the kinds no widget is registered for.
+pub fn spawn_template_elements(world: &mut World, elements: &[TemplateElement]) -> Vec<String> {
+    let widgets = current_widgets(world);
+    let mut unknown = Vec::new();
+    for element in elements {
+        let Some(widget) = widgets.iter().find(|widget| widget.kind() == element.kind) else {
+            unknown.push(element.kind.clone());
+            continue;
+        };
+        let entity = spawn_widget(world, widget.as_ref(), element.x, element.y, TabKind::MainWork, 0);
+        let mut entity = world.entity_mut(entity);
+        if !element.label.is_empty() {
+            widget.set_label(&mut entity, element.label.clone());
+        }
+        let size = widget.default_size();
+        entity.insert(UiElementSize {
+            width: element.width.unwrap_or(size.width),
+            height: element.height.unwrap_or(size.height),
+        });
+    }
+    unknown
+}
+
+/// Starter elements of the built-in templates
+fn starter_elements(name: &str) -> Option<Vec<TemplateElement>> {
+    let elements = match name {
+        "login_form" => vec![
+            TemplateElement::new("group_box", "Sign In", 40.0, 40.0).sized(320.0, 260.0),
+            TemplateElement::new("text_input", "Username", 60.0, 80.0),
+            TemplateElement::new("text_input", "Password", 60.0, 140.0),
+            TemplateElement::new("checkbox", "Remember me", 60.0, 200.0),
+            TemplateElement::new("button", "Sign In", 60.0, 240.0),
+            TemplateElement::new("hyperlink", "Forgot password?", 180.0, 245.0),
+        ],
+        "settings_dialog" => vec![
+            TemplateElement::new("group_box", "General", 40.0, 40.0).sized(300.0, 150.0),
+            TemplateElement::new("checkbox", "Start on login", 60.0, 80.0),
+            TemplateElement::new("checkbox", "Check for updates", 60.0, 115.0),
+            TemplateElement::new("checkbox", "Send usage statistics", 60.0, 150.0),
+            TemplateElement::new("group_box", "Units", 360.0, 40.0).sized(220.0, 150.0),
+            TemplateElement::new("radio_button", "Millimeters", 380.0, 80.0),
+            TemplateElement::new("radio_button", "Mils", 380.0, 115.0),
+            TemplateElement::new("separator", "", 40.0, 210.0).sized(540.0, 10.0),
+            TemplateElement::new("button", "Apply", 380.0, 230.0),
+            TemplateElement::new("button", "Cancel", 490.0, 230.0),
+        ],
+        "log_viewer" => vec![
+            TemplateElement::new("text_input", "Filter", 40.0, 30.0),
+            TemplateElement::new("checkbox", "Auto-scroll", 280.0, 50.0),
+            TemplateElement::new("button", "Clear", 420.0, 45.0),
+            TemplateElement::new("table", "Entries", 40.0, 100.0).sized(600.0, 300.0),
+            TemplateElement::new("status_led", "Connected", 40.0, 420.0),
+        ],
+        "gerber_viewer" => vec![
+            TemplateElement::new("button", "Open Gerber...", 40.0, 30.0).sized(130.0, 30.0),
+            TemplateElement::new("button", "Zoom In", 190.0, 30.0),
+            TemplateElement::new("button", "Zoom Out", 300.0, 30.0),
+            TemplateElement::new("button", "Fit", 410.0, 30.0),
+            TemplateElement::new("tree_view", "Layers", 40.0, 80.0).sized(200.0, 320.0),
+            TemplateElement::new("scroll_area", "Board", 260.0, 80.0).sized(480.0, 320.0),
+            TemplateElement::new("progress_bar", "Loading", 40.0, 420.0).sized(300.0, 24.0),
+            TemplateElement::new("label", "Cursor: 0.000, 0.000 mm", 360.0, 420.0),
+        ],
+        "text_editor" => vec![
+            TemplateElement::new("button", "Open", 40.0, 30.0),
+            TemplateElement::new("button", "Save", 150.0, 30.0),
+            TemplateElement::new("code_editor", "Editor", 40.0, 80.0).sized(640.0, 360.0),
+        ],
+        _ => return None,
+    };
+    Some(elements)
+}
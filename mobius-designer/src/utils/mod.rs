@@ -17,6 +17,23 @@ pub fn get_grid_status(zoom: f32, spacing: f32) -> GridStatus {
     }
 }
 
+/// Id of the design page currently shown on the canvas
+pub fn active_page_id(world: &mut World) -> usize {
+    let mut query = world.query::<&DesignPages>();
+    query.iter(world).next().map(|pages| pages.active_page).unwrap_or(0)
+}
+
+/// Copy of the project's named styles, used to resolve element styles while rendering
+pub fn current_style_sheet(world: &mut World) -> StyleSheet {
+    let mut query = world.query::<&StyleSheet>();
+    query.iter(world).next().cloned().unwrap_or_default()
+}
+
+/// Elements spawned without a page component belong to the first page
+pub fn is_on_page(page: Option<&UiElementPage>, page_id: usize) -> bool {
+    page.map(|page| page.page_id).unwrap_or(0) == page_id
+}
+
 pub fn clear_all_selections(world: &mut World) {
     let mut query = world.query::<&mut UiElementSelected>();
     for mut selected in query.iter_mut(world) {
@@ -63,38 +80,12 @@ pub fn add_designer_log(
 }
 
 pub fn clear_all_ui_elements(world: &mut World) {
-    // Collect entities to despawn
-    let mut entities_to_remove = Vec::new();
-    
-    // Query buttons
-    let mut button_query = world.query::<(Entity, &UiButton)>();
-    for (entity, _) in button_query.iter(world) {
-        entities_to_remove.push(entity);
-    }
-    
-    // Query text inputs
-    let mut input_query = world.query::<(Entity, &UiTextInput)>();
-    for (entity, _) in input_query.iter(world) {
-        entities_to_remove.push(entity);
-    }
-    
-    // Query checkboxes
-    let mut checkbox_query = world.query::<(Entity, &UiCheckbox)>();
-    for (entity, _) in checkbox_query.iter(world) {
-        entities_to_remove.push(entity);
-    }
-    
-    // Query radio buttons
-    let mut radio_query = world.query::<(Entity, &UiRadioButton)>();
-    for (entity, _) in radio_query.iter(world) {
-        entities_to_remove.push(entity);
-    }
-    
-    // Query group boxes
-    let mut group_query = world.query::<(Entity, &UiGroupBox)>();
-    for (entity, _) in group_query.iter(world) {
-        entities_to_remove.push(entity);
-    }
+    // Collect the elements of every registered widget type
+    let widgets = crate::widgets::current_widgets(world);
+    let entities_to_remove: Vec<Entity> = world.iter_entities()
+        .filter(|entity| widgets.widget_of(*entity).is_some())
+        .map(|entity| entity.id())
+        .collect();
     
     // Despawn all collected entities
     for entity in entities_to_remove {
@@ -0,0 +1,822 @@
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypeRegistry;
+use egui::{Color32, Response, Ui, Vec2};
+use crate::codegen::{snake_ident, CodeGenerator, ElementStyleCode};
+use crate::codegen_thread::{UiElementSnapshot, UiElementType};
+use crate::components::*;
+use crate::resources::RunPreviewState;
+use super::{element_size, empty_response, DesignerWidget, WidgetEvents};
+
+pub struct ButtonWidget;
+
+impl DesignerWidget for ButtonWidget {
+    fn kind(&self) -> &'static str {
+        "button"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Button"
+    }
+
+    fn icon(&self) -> &'static str {
+        "➕"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiButton>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiButton>()
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiButton {
+            label: format!("Button {}", number),
+            clicked: false,
+            enabled: true,
+            click_time: None,
+            font_size: 14.0,
+        });
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiButton>().map(|button| button.label.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, label: String) {
+        if let Some(mut button) = entity.get_mut::<UiButton>() {
+            button.label = label;
+        }
+    }
+
+    fn enabled(&self, entity: EntityRef) -> bool {
+        entity.get::<UiButton>().is_some_and(|button| button.enabled)
+    }
+
+    fn set_enabled(&self, entity: &mut EntityWorldMut, enabled: bool) {
+        if let Some(mut button) = entity.get_mut::<UiButton>() {
+            button.enabled = enabled;
+        }
+    }
+
+    fn draws_own_frame(&self) -> bool {
+        true
+    }
+
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, style: &UiElementStyle, _events: &mut WidgetEvents) -> Response {
+        let Some(button) = entity.get::<UiButton>() else {
+            return empty_response(ui);
+        };
+        let size = element_size(entity);
+
+        let response = if size.width > 0.0 || size.height > 0.0 {
+            // Manual button rendering with exact size
+            let actual_width = if size.width > 0.0 { size.width } else { 80.0 };
+            let actual_height = if size.height > 0.0 { size.height } else { 25.0 };
+
+            let desired_size = Vec2::new(actual_width, actual_height);
+            let (rect, response) = ui.allocate_exact_size(desired_size, egui::Sense::click());
+
+            if ui.is_rect_visible(rect) {
+                let visuals = ui.style().interact(&response);
+                let corner_radius = style.corner_radius.map(egui::CornerRadius::from).unwrap_or(visuals.corner_radius);
+                let fill = style.fill.unwrap_or(visuals.bg_fill);
+                let stroke = style.stroke.map(|stroke| stroke.stroke()).unwrap_or(visuals.bg_stroke);
+
+                // Draw button background
+                ui.painter().rect_filled(rect, corner_radius, fill);
+                ui.painter().rect_stroke(rect, corner_radius, stroke, egui::StrokeKind::Outside);
+
+                // Draw text centered using galley for proper layout
+                let text_color = if button.enabled { visuals.text_color() } else { ui.style().visuals.weak_text_color() };
+                let mut text = style.rich_text(&button.label, button.font_size);
+                if !button.enabled {
+                    text = text.color(text_color);
+                }
+                let galley = egui::WidgetText::from(text).into_galley(
+                    ui,
+                    Some(egui::TextWrapMode::Extend),
+                    f32::INFINITY,
+                    egui::TextStyle::Button,
+                );
+                let text_pos = rect.center() - galley.size() * 0.5;
+                ui.painter().galley(text_pos, galley, text_color);
+            }
+
+            response
+        } else {
+            ui.add_enabled_ui(button.enabled, |ui| {
+                if let Some(padding) = style.padding {
+                    ui.spacing_mut().button_padding = padding;
+                }
+                ui.add(style.style_button(egui::Button::new(style.rich_text(&button.label, button.font_size))))
+            }).inner
+        };
+
+        if button.clicked {
+            ui.colored_label(Color32::GREEN, "✓");
+        }
+        response
+    }
+
+    fn render_run(
+        &self,
+        ui: &mut Ui,
+        entity: EntityRef,
+        style: &UiElementStyle,
+        state: &mut RunPreviewState,
+        log_messages: &mut Vec<String>,
+    ) -> Response {
+        let Some(button) = entity.get::<UiButton>() else {
+            return empty_response(ui);
+        };
+        let size = element_size(entity);
+        let widget = style.style_button(egui::Button::new(style.rich_text(&button.label, button.font_size)));
+        let response = ui.add_enabled_ui(button.enabled, |ui| {
+            if let Some(padding) = style.padding {
+                ui.spacing_mut().button_padding = padding;
+            }
+            if size.width > 0.0 || size.height > 0.0 {
+                ui.add_sized(Vec2::new(size.width.max(1.0), size.height.max(1.0)), widget)
+            } else {
+                ui.add(widget)
+            }
+        }).inner;
+        if response.clicked() {
+            let clicks = state.button_clicks.entry(entity.id()).or_insert(0);
+            *clicks += 1;
+            log_messages.push(format!("Button '{}' clicked ({}x)", button.label, clicks));
+        }
+        response
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let button = entity.get::<UiButton>()?;
+        Some(UiElementType::Button {
+            label: button.label.clone(),
+            enabled: button.enabled,
+            font_size: button.font_size,
+        })
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, style: &ElementStyleCode) {
+        let UiElementType::Button { label, font_size, .. } = &element.element_type else {
+            return;
+        };
+        let size = &element.size;
+        if let Some(padding) = style.button_padding() {
+            gen.writeln(&padding);
+        }
+        let button = style.button(style.text(label, *font_size));
+        gen.writeln("if ui.add_sized(");
+        gen.push_indent();
+        gen.writeln(&format!("egui::vec2({:.1}, {:.1}),", size.width, size.height));
+        gen.writeln(&format!("{},", button));
+        gen.pop_indent();
+        gen.writeln(&format!("){}.clicked() {{", style.tooltip()));
+        gen.push_indent();
+        gen.writeln(&format!("// TODO: Handle {} button click", label));
+        gen.pop_indent();
+        gen.writeln("}");
+    }
+}
+
+pub struct TextInputWidget;
+
+impl DesignerWidget for TextInputWidget {
+    fn kind(&self) -> &'static str {
+        "text_input"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Text Input"
+    }
+
+    fn icon(&self) -> &'static str {
+        "📝"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiTextInput>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiTextInput>()
+    }
+
+    fn default_size(&self) -> UiElementSize {
+        UiElementSize { width: 200.0, height: 30.0 }
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiTextInput::new(format!("Input {}", number)));
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiTextInput>().map(|input| input.label.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, label: String) {
+        if let Some(mut input) = entity.get_mut::<UiTextInput>() {
+            input.label = label;
+        }
+    }
+
+    fn enabled(&self, entity: EntityRef) -> bool {
+        entity.get::<UiTextInput>().is_some_and(|input| input.enabled)
+    }
+
+    fn set_enabled(&self, entity: &mut EntityWorldMut, enabled: bool) {
+        if let Some(mut input) = entity.get_mut::<UiTextInput>() {
+            input.enabled = enabled;
+        }
+    }
+
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, style: &UiElementStyle, events: &mut WidgetEvents) -> Response {
+        let Some(text_input) = entity.get::<UiTextInput>() else {
+            return empty_response(ui);
+        };
+        let size = element_size(entity);
+
+        ui.vertical(|ui| {
+            ui.label(style.rich_text(&text_input.label, text_input.font_size));
+
+            let mut value = text_input.value.clone();
+            let text_edit = configured_text_edit(text_input, &mut value, size.width.max(100.0), style);
+
+            let response = ui.add_enabled_ui(text_input.enabled, |ui| {
+                ui.add(text_edit)
+            }).inner;
+            show_validation_error(ui, text_input, &value);
+
+            if response.changed() {
+                let entity_id = entity.id();
+                events.log(format!("Text input '{}' changed to '{}'", text_input.label, value));
+                events.update(move |world: &mut World| {
+                    if let Some(mut input) = world.get_mut::<UiTextInput>(entity_id) {
+                        input.value = value;
+                    }
+                });
+            }
+
+            response
+        }).inner
+    }
+
+    fn render_run(
+        &self,
+        ui: &mut Ui,
+        entity: EntityRef,
+        style: &UiElementStyle,
+        state: &mut RunPreviewState,
+        _log_messages: &mut Vec<String>,
+    ) -> Response {
+        let Some(input) = entity.get::<UiTextInput>() else {
+            return empty_response(ui);
+        };
+        let size = element_size(entity);
+        let value = state.text_values.entry(entity.id()).or_insert_with(|| input.value.clone());
+        ui.vertical(|ui| {
+            ui.label(style.rich_text(&input.label, input.font_size));
+            ui.add_enabled(input.enabled, configured_text_edit(input, value, size.width.max(100.0), style));
+            show_validation_error(ui, input, value);
+        }).response
+    }
+
+    fn render_inspector(&self, ui: &mut Ui, world: &mut World, entity: Entity) {
+        let Some(input) = world.get::<UiTextInput>(entity) else {
+            return;
+        };
+        if input.validation == TextValidation::None {
+            return;
+        }
+        match input.validation_error(&input.value) {
+            Some(error) => ui.colored_label(Color32::from_rgb(255, 100, 100), format!("⚠ Current value: {}", error)),
+            None => ui.colored_label(Color32::from_rgb(100, 200, 100), "✔ Current value is valid"),
+        };
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let text_input = entity.get::<UiTextInput>()?;
+        Some(UiElementType::TextInput {
+            label: text_input.label.clone(),
+            value: text_input.value.clone(),
+            enabled: text_input.enabled,
+            font_size: text_input.font_size,
+            multiline: text_input.multiline,
+            rows: text_input.rows,
+            password: text_input.password,
+            hint: text_input.hint.clone(),
+            max_length: text_input.max_length,
+            validation: text_input.validation,
+            pattern: text_input.pattern.clone(),
+        })
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, style: &ElementStyleCode) {
+        let UiElementType::TextInput { label, font_size, multiline, rows, password, hint, max_length, .. } = &element.element_type else {
+            return;
+        };
+        let size = &element.size;
+        let mut text_edit = if *multiline {
+            format!("egui::TextEdit::multiline(&mut app.{}).desired_rows({})", snake_ident(label), rows)
+        } else {
+            format!("egui::TextEdit::singleline(&mut app.{})", snake_ident(label))
+        };
+        if *password {
+            text_edit.push_str(".password(true)");
+        }
+        if !hint.is_empty() {
+            text_edit.push_str(&format!(".hint_text(\"{}\")", hint.escape_default()));
+        }
+        if *max_length > 0 {
+            text_edit.push_str(&format!(".char_limit({})", max_length));
+        }
+        if let Some(color) = style.text_color() {
+            text_edit.push_str(&format!(".text_color({})", color));
+        }
+        gen.writeln("ui.vertical(|ui| {");
+        gen.push_indent();
+        gen.writeln(&format!("ui.label({});", style.text(label, *font_size)));
+        gen.writeln("ui.add_sized(");
+        gen.push_indent();
+        gen.writeln(&format!("egui::vec2({:.1}, {:.1}),", size.width, size.height));
+        gen.writeln(&format!("{},", text_edit));
+        gen.pop_indent();
+        gen.writeln(");");
+        gen.pop_indent();
+        if style.has_tooltip() {
+            gen.writeln(&format!("}}).response{};", style.tooltip()));
+        } else {
+            gen.writeln("});");
+        }
+    }
+
+    fn app_fields(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        vec![(snake_ident(element.element_type.label()), "String".to_string())]
+    }
+
+    fn validation_check(&self, element: &UiElementSnapshot) -> Option<String> {
+        let UiElementType::TextInput { label, validation, pattern, .. } = &element.element_type else {
+            return None;
+        };
+        let field = format!("self.{}", snake_ident(label));
+        match validation {
+            TextValidation::None => None,
+            TextValidation::Required => Some(format!("{}.trim().is_empty()", field)),
+            TextValidation::Integer => Some(format!("{}.trim().parse::<i64>().is_err()", field)),
+            TextValidation::Float => Some(format!("{}.trim().parse::<f64>().is_err()", field)),
+            TextValidation::Regex => Some(format!(
+                "!regex::Regex::new(\"^(?:{})$\").is_ok_and(|regex| regex.is_match(&{}))",
+                pattern.escape_default(),
+                field
+            )),
+        }
+    }
+
+    fn required_crates(&self, element: &UiElementSnapshot) -> Vec<(&'static str, &'static str)> {
+        match &element.element_type {
+            UiElementType::TextInput { validation: TextValidation::Regex, .. } => vec![("regex", "input validation")],
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// `TextEdit` for the input's mode: single or multiline, password masking, hint and length limit
+fn configured_text_edit<'t>(input: &UiTextInput, value: &'t mut String, width: f32, style: &UiElementStyle) -> egui::TextEdit<'t> {
+    let mut text_edit = if input.multiline {
+        egui::TextEdit::multiline(value).desired_rows(input.rows.max(1))
+    } else {
+        egui::TextEdit::singleline(value)
+    };
+    text_edit = text_edit
+        .desired_width(width)
+        .text_color_opt(style.text_color)
+        .password(input.password);
+    if !input.hint.is_empty() {
+        text_edit = text_edit.hint_text(input.hint.as_str());
+    }
+    if input.max_length > 0 {
+        text_edit = text_edit.char_limit(input.max_length);
+    }
+    text_edit
+}
+
+fn show_validation_error(ui: &mut Ui, input: &UiTextInput, value: &str) {
+    if let Some(error) = input.validation_error(value) {
+        ui.colored_label(Color32::from_rgb(255, 100, 100), egui::RichText::new(format!("⚠ {}", error)).small());
+    }
+}
+
+pub struct CheckboxWidget;
+
+impl DesignerWidget for CheckboxWidget {
+    fn kind(&self) -> &'static str {
+        "checkbox"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Checkbox"
+    }
+
+    fn icon(&self) -> &'static str {
+        "☑️"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiCheckbox>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiCheckbox>()
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiCheckbox {
+            label: format!("Checkbox {}", number),
+            checked: false,
+            enabled: true,
+            font_size: 14.0,
+        });
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiCheckbox>().map(|checkbox| checkbox.label.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, label: String) {
+        if let Some(mut checkbox) = entity.get_mut::<UiCheckbox>() {
+            checkbox.label = label;
+        }
+    }
+
+    fn enabled(&self, entity: EntityRef) -> bool {
+        entity.get::<UiCheckbox>().is_some_and(|checkbox| checkbox.enabled)
+    }
+
+    fn set_enabled(&self, entity: &mut EntityWorldMut, enabled: bool) {
+        if let Some(mut checkbox) = entity.get_mut::<UiCheckbox>() {
+            checkbox.enabled = enabled;
+        }
+    }
+
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, style: &UiElementStyle, events: &mut WidgetEvents) -> Response {
+        let Some(checkbox) = entity.get::<UiCheckbox>() else {
+            return empty_response(ui);
+        };
+
+        let mut checked = checkbox.checked;
+        let response = ui.add_enabled_ui(checkbox.enabled, |ui| {
+            ui.checkbox(&mut checked, style.rich_text(&checkbox.label, checkbox.font_size))
+        }).inner;
+
+        if response.changed() {
+            let entity_id = entity.id();
+            events.log(format!("Checkbox '{}' {}", checkbox.label, if checked { "checked" } else { "unchecked" }));
+            events.update(move |world: &mut World| {
+                if let Some(mut cb) = world.get_mut::<UiCheckbox>(entity_id) {
+                    cb.checked = checked;
+                }
+            });
+        }
+        response
+    }
+
+    fn render_run(
+        &self,
+        ui: &mut Ui,
+        entity: EntityRef,
+        style: &UiElementStyle,
+        state: &mut RunPreviewState,
+        log_messages: &mut Vec<String>,
+    ) -> Response {
+        let Some(checkbox) = entity.get::<UiCheckbox>() else {
+            return empty_response(ui);
+        };
+        let checked = state.checked.entry(entity.id()).or_insert(checkbox.checked);
+        let response = ui.add_enabled(
+            checkbox.enabled,
+            egui::Checkbox::new(checked, style.rich_text(&checkbox.label, checkbox.font_size)),
+        );
+        if response.changed() {
+            log_messages.push(format!("Checkbox '{}' {}", checkbox.label, if *checked { "checked" } else { "unchecked" }));
+        }
+        response
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let checkbox = entity.get::<UiCheckbox>()?;
+        Some(UiElementType::Checkbox {
+            label: checkbox.label.clone(),
+            checked: checkbox.checked,
+            enabled: checkbox.enabled,
+            font_size: checkbox.font_size,
+        })
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, style: &ElementStyleCode) {
+        let UiElementType::Checkbox { label, font_size, .. } = &element.element_type else {
+            return;
+        };
+        gen.writeln(&format!("ui.checkbox(&mut app.{}, {}){};",
+            snake_ident(label),
+            style.text(label, *font_size),
+            style.tooltip()
+        ));
+    }
+
+    fn app_fields(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        vec![(snake_ident(element.element_type.label()), "bool".to_string())]
+    }
+}
+
+pub struct RadioButtonWidget;
+
+impl DesignerWidget for RadioButtonWidget {
+    fn kind(&self) -> &'static str {
+        "radio_button"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Radio Button"
+    }
+
+    fn icon(&self) -> &'static str {
+        "🔘"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiRadioButton>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiRadioButton>()
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiRadioButton {
+            label: format!("Radio {}", number),
+            selected: false,
+            enabled: true,
+            font_size: 14.0,
+            group_id: "default_group".to_string(),
+            variant: String::new(),
+            order: number,
+        });
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiRadioButton>().map(|radio| radio.label.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, label: String) {
+        if let Some(mut radio) = entity.get_mut::<UiRadioButton>() {
+            radio.label = label;
+        }
+    }
+
+    fn enabled(&self, entity: EntityRef) -> bool {
+        entity.get::<UiRadioButton>().is_some_and(|radio| radio.enabled)
+    }
+
+    fn set_enabled(&self, entity: &mut EntityWorldMut, enabled: bool) {
+        if let Some(mut radio) = entity.get_mut::<UiRadioButton>() {
+            radio.enabled = enabled;
+        }
+    }
+
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, style: &UiElementStyle, events: &mut WidgetEvents) -> Response {
+        let Some(radio_button) = entity.get::<UiRadioButton>() else {
+            return empty_response(ui);
+        };
+
+        let response = ui.add_enabled_ui(radio_button.enabled, |ui| {
+            ui.radio_value(&mut true, radio_button.selected, style.rich_text(&radio_button.label, radio_button.font_size))
+        }).inner;
+
+        if response.clicked() && !radio_button.selected {
+            let group_id = radio_button.group_id.clone();
+            let entity_id = entity.id();
+            events.log(format!("Radio button '{}' selected", radio_button.label));
+            events.update(move |world: &mut World| {
+                let mut radio_query = world.query::<(Entity, &mut UiRadioButton)>();
+                for (e, mut rb) in radio_query.iter_mut(world) {
+                    if rb.group_id == group_id {
+                        rb.selected = e == entity_id;
+                    }
+                }
+            });
+        }
+        response
+    }
+
+    fn render_run(
+        &self,
+        ui: &mut Ui,
+        entity: EntityRef,
+        style: &UiElementStyle,
+        state: &mut RunPreviewState,
+        log_messages: &mut Vec<String>,
+    ) -> Response {
+        let Some(radio) = entity.get::<UiRadioButton>() else {
+            return empty_response(ui);
+        };
+        let selected = state.radio_selection.get(&radio.group_id) == Some(&entity.id());
+        let response = ui.add_enabled(
+            radio.enabled,
+            egui::RadioButton::new(selected, style.rich_text(&radio.label, radio.font_size)),
+        );
+        if response.clicked() && !selected {
+            state.radio_selection.insert(radio.group_id.clone(), entity.id());
+            log_messages.push(format!("Radio button '{}' selected", radio.label));
+        }
+        response
+    }
+
+    fn render_inspector(&self, ui: &mut Ui, world: &mut World, entity: Entity) {
+        let groups = radio_groups(world);
+        let Some(group) = groups.iter().find(|group| group.members.iter().any(|member| member.entity == entity)) else {
+            return;
+        };
+        let Some(position) = group.members.iter().position(|member| member.entity == entity) else {
+            return;
+        };
+        let idents = group.variant_idents();
+        ui.weak(format!(
+            "Generated as {}::{} ({} of {}); order and default are edited in the Radio Groups tab",
+            group.enum_name,
+            idents[position],
+            position + 1,
+            group.members.len()
+        ));
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let radio = entity.get::<UiRadioButton>()?;
+        Some(UiElementType::RadioButton {
+            label: radio.label.clone(),
+            selected: radio.selected,
+            enabled: radio.enabled,
+            font_size: radio.font_size,
+            group_id: radio.group_id.clone(),
+            variant: radio.variant.clone(),
+            order: radio.order,
+            enum_name: radio_group_enum(&radio.group_id),
+            field: radio_group_field(&radio.group_id),
+        })
+    }
+
+    /// Name the groups' enums and fields against each other
+    fn finish_snapshots(&self, elements: &mut [&mut UiElementSnapshot]) {
+        let groups = RadioGroup::collect(elements.iter().filter_map(|element| snapshot_radio_member(element)));
+        for element in elements.iter_mut() {
+            if let UiElementType::RadioButton { group_id, enum_name, field, .. } = &mut element.element_type {
+                if let Some(group) = groups.iter().find(|group| group.id == *group_id) {
+                    enum_name.clone_from(&group.enum_name);
+                    field.clone_from(&group.field);
+                }
+            }
+        }
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, style: &ElementStyleCode) {
+        let UiElementType::RadioButton { label, font_size, enum_name, field, .. } = &element.element_type else {
+            return;
+        };
+        let Some(variant) = gen.radio_variant(element.entity_id) else {
+            return;
+        };
+        gen.writeln(&format!("ui.radio_value(&mut app.{}, {}::{}, {}){};",
+            field,
+            enum_name,
+            variant,
+            style.text(label, *font_size),
+            style.tooltip()
+        ));
+    }
+
+    fn app_fields(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        match &element.element_type {
+            UiElementType::RadioButton { enum_name, field, .. } => {
+                vec![(field.clone(), enum_name.clone())]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+pub struct GroupBoxWidget;
+
+impl DesignerWidget for GroupBoxWidget {
+    fn kind(&self) -> &'static str {
+        "group_box"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Group Box"
+    }
+
+    fn icon(&self) -> &'static str {
+        "📦"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiGroupBox>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiGroupBox>()
+    }
+
+    fn default_size(&self) -> UiElementSize {
+        UiElementSize { width: 200.0, height: 150.0 }
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiGroupBox {
+            label: format!("Group {}", number),
+            enabled: true,
+            font_size: 14.0,
+            contained_widgets: Vec::new(),
+        });
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiGroupBox>().map(|group_box| group_box.label.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, label: String) {
+        if let Some(mut group_box) = entity.get_mut::<UiGroupBox>() {
+            group_box.label = label;
+        }
+    }
+
+    fn enabled(&self, entity: EntityRef) -> bool {
+        entity.get::<UiGroupBox>().is_some_and(|group_box| group_box.enabled)
+    }
+
+    fn set_enabled(&self, entity: &mut EntityWorldMut, enabled: bool) {
+        if let Some(mut group_box) = entity.get_mut::<UiGroupBox>() {
+            group_box.enabled = enabled;
+        }
+    }
+
+    fn is_container(&self) -> bool {
+        true
+    }
+
+    fn draws_own_frame(&self) -> bool {
+        true
+    }
+
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, style: &UiElementStyle, _events: &mut WidgetEvents) -> Response {
+        let Some(group_box) = entity.get::<UiGroupBox>() else {
+            return empty_response(ui);
+        };
+        let size = element_size(entity);
+
+        style.group_frame(ui.style()).show(ui, |ui| {
+            ui.set_min_size(Vec2::new(size.width.max(100.0), size.height.max(50.0)));
+            ui.label(style.rich_text(&group_box.label, group_box.font_size))
+        }).response
+    }
+
+    fn render_inspector(&self, ui: &mut Ui, world: &mut World, entity: Entity) {
+        if let Some(group_box) = world.get::<UiGroupBox>(entity) {
+            ui.label(format!("Contains {} widgets", group_box.contained_widgets.len()));
+        }
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let group_box = entity.get::<UiGroupBox>()?;
+        Some(UiElementType::GroupBox {
+            label: group_box.label.clone(),
+            enabled: group_box.enabled,
+            font_size: group_box.font_size,
+        })
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, style: &ElementStyleCode) {
+        let UiElementType::GroupBox { label, font_size, .. } = &element.element_type else {
+            return;
+        };
+        let size = &element.size;
+        if style.has_frame() {
+            gen.writeln(&format!("{}.show(ui, |ui| {{", style.frame("egui::Frame::group(ui.style())")));
+        } else {
+            gen.writeln("ui.group(|ui| {");
+        }
+        gen.push_indent();
+        gen.writeln(&format!("ui.set_min_size(egui::vec2({:.1}, {:.1}));", size.width, size.height));
+        gen.writeln(&format!("ui.label({});", style.text(label, *font_size)));
+        gen.writeln("ui.separator();");
+        gen.writeln("// TODO: Add group box contents");
+        gen.pop_indent();
+        if style.has_tooltip() {
+            gen.writeln(&format!("}}).response{};", style.tooltip()));
+        } else {
+            gen.writeln("});");
+        }
+    }
+}
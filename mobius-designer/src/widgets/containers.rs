@@ -0,0 +1,593 @@
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypeRegistry;
+use egui::{RichText, Response, Sense, Ui, Vec2};
+use crate::codegen::{CodeGenerator, ElementStyleCode};
+use crate::codegen_thread::{CustomElement, UiElementSnapshot, UiElementType};
+use crate::components::*;
+use crate::resources::RunPreviewState;
+use super::{element_component, element_size, empty_response, DesignerWidget, WidgetEvents};
+
+/// Height of a collapsing header's or tab strip's header row on the canvas
+const HEADER_HEIGHT: f32 = 24.0;
+
+/// Dimmed outline marking a container's extent on the design canvas
+fn outline(ui: &Ui, rect: egui::Rect) {
+    let stroke = egui::Stroke::new(1.0, ui.visuals().weak_text_color().gamma_multiply(0.6));
+    ui.painter().rect_stroke(rect, 2.0, stroke, egui::StrokeKind::Inside);
+}
+
+pub struct CollapsingHeaderWidget;
+
+impl CollapsingHeaderWidget {
+    /// Header row and, when open, the body's space; the header responds to clicks
+    fn show(ui: &mut Ui, header: &UiCollapsingHeader, open: bool, size: &UiElementSize, style: &UiElementStyle, design: bool) -> (Response, bool) {
+        let mut toggled = false;
+        let response = ui.add_enabled_ui(header.enabled, |ui| {
+            ui.vertical(|ui| {
+                ui.set_min_width(size.width.max(60.0));
+                let icon = if open { "⏷" } else { "⏵" };
+                let text = style.rich_text(&format!("{} {}", icon, header.label), header.font_size);
+                let row = ui.add(egui::Label::new(text).sense(Sense::click()).selectable(false));
+                toggled = row.clicked();
+                if open {
+                    let body = Vec2::new(size.width.max(60.0), (size.height - HEADER_HEIGHT).max(0.0));
+                    let (rect, _) = ui.allocate_exact_size(body, Sense::hover());
+                    if design {
+                        outline(ui, rect);
+                    }
+                }
+                row
+            })
+        });
+        (response.response.union(response.inner.inner), toggled)
+    }
+}
+
+impl DesignerWidget for CollapsingHeaderWidget {
+    fn kind(&self) -> &'static str {
+        "collapsing_header"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Collapsing Header"
+    }
+
+    fn icon(&self) -> &'static str {
+        "🔽"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiCollapsingHeader>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiCollapsingHeader>()
+    }
+
+    fn default_size(&self) -> UiElementSize {
+        UiElementSize { width: 220.0, height: 150.0 }
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiCollapsingHeader::new(&format!("Section {}", number)));
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiCollapsingHeader>().map(|header| header.label.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, label: String) {
+        if let Some(mut header) = entity.get_mut::<UiCollapsingHeader>() {
+            header.label = label;
+        }
+    }
+
+    fn enabled(&self, entity: EntityRef) -> bool {
+        entity.get::<UiCollapsingHeader>().is_some_and(|header| header.enabled)
+    }
+
+    fn set_enabled(&self, entity: &mut EntityWorldMut, enabled: bool) {
+        if let Some(mut header) = entity.get_mut::<UiCollapsingHeader>() {
+            header.enabled = enabled;
+        }
+    }
+
+    fn is_container(&self) -> bool {
+        true
+    }
+
+    fn child_slot(&self, _entity: EntityRef) -> Option<usize> {
+        Some(0)
+    }
+
+    fn shows_child(&self, entity: EntityRef, _slot: usize, _offset: Vec2, state: Option<&RunPreviewState>) -> bool {
+        let Some(header) = entity.get::<UiCollapsingHeader>() else {
+            return true;
+        };
+        match state {
+            Some(state) => state.open_sections.get(&entity.id()).copied().unwrap_or(header.default_open),
+            None => header.expanded,
+        }
+    }
+
+    /// Clicking the header opens or collapses it on the canvas, hiding the elements in it
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, style: &UiElementStyle, events: &mut WidgetEvents) -> Response {
+        let Some(header) = entity.get::<UiCollapsingHeader>() else {
+            return empty_response(ui);
+        };
+        let (response, toggled) = Self::show(ui, header, header.expanded, &element_size(entity), style, true);
+        if toggled {
+            let entity_id = entity.id();
+            events.update(move |world: &mut World| {
+                if let Some(mut header) = world.get_mut::<UiCollapsingHeader>(entity_id) {
+                    header.expanded = !header.expanded;
+                }
+            });
+        }
+        response
+    }
+
+    fn render_run(
+        &self,
+        ui: &mut Ui,
+        entity: EntityRef,
+        style: &UiElementStyle,
+        state: &mut RunPreviewState,
+        _log_messages: &mut Vec<String>,
+    ) -> Response {
+        let Some(header) = entity.get::<UiCollapsingHeader>() else {
+            return empty_response(ui);
+        };
+        let open = state.open_sections.entry(entity.id()).or_insert(header.default_open);
+        let (response, toggled) = Self::show(ui, header, *open, &element_size(entity), style, false);
+        if toggled {
+            *open = !*open;
+        }
+        response
+    }
+
+    fn render_inspector(&self, ui: &mut Ui, _world: &mut World, _entity: Entity) {
+        ui.weak("Click the header on the canvas to open or collapse it; elements dropped under it go inside");
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let header = entity.get::<UiCollapsingHeader>()?;
+        Some(UiElementType::Custom(CustomElement::new(self.kind(), &header.label, header.enabled, header.font_size, header)))
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, style: &ElementStyleCode) {
+        let Some(header) = element_component::<UiCollapsingHeader>(element) else {
+            return;
+        };
+        let has_children = gen.has_children(element.entity_id, None);
+        if has_children {
+            gen.writeln("let origin = ui.cursor().min;");
+        }
+        gen.writeln(&format!("ui.set_min_width({:.1});", element.size.width.max(60.0)));
+        gen.writeln(&format!(
+            "egui::CollapsingHeader::new({}).default_open({}).show(ui, |ui| {{",
+            style.text(&header.label, header.font_size),
+            header.default_open
+        ));
+        gen.push_indent();
+        gen.writeln(&format!("ui.set_min_height({:.1});", (element.size.height - HEADER_HEIGHT).max(0.0)));
+        if has_children {
+            gen.generate_children(element, 0, "origin");
+        }
+        gen.pop_indent();
+        if style.has_tooltip() {
+            gen.writeln(&format!("}}).header_response{};", style.tooltip()));
+        } else {
+            gen.writeln("});");
+        }
+    }
+}
+
+pub struct ScrollAreaWidget;
+
+impl DesignerWidget for ScrollAreaWidget {
+    fn kind(&self) -> &'static str {
+        "scroll_area"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Scroll Area"
+    }
+
+    fn icon(&self) -> &'static str {
+        "↕"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiScrollArea>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiScrollArea>()
+    }
+
+    fn default_size(&self) -> UiElementSize {
+        UiElementSize { width: 220.0, height: 160.0 }
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiScrollArea::new(&format!("Scroll Area {}", number)));
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiScrollArea>().map(|area| area.name.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, name: String) {
+        if let Some(mut area) = entity.get_mut::<UiScrollArea>() {
+            area.name = name;
+        }
+    }
+
+    /// Scroll areas have no enabled state
+    fn enabled(&self, _entity: EntityRef) -> bool {
+        true
+    }
+
+    fn set_enabled(&self, _entity: &mut EntityWorldMut, _enabled: bool) {}
+
+    fn is_container(&self) -> bool {
+        true
+    }
+
+    fn child_slot(&self, _entity: EntityRef) -> Option<usize> {
+        Some(0)
+    }
+
+    /// Elements starting outside the viewport are scrolled out of view, unless expanded on the canvas
+    fn shows_child(&self, entity: EntityRef, _slot: usize, offset: Vec2, state: Option<&RunPreviewState>) -> bool {
+        let Some(area) = entity.get::<UiScrollArea>() else {
+            return true;
+        };
+        if state.is_none() && area.expanded {
+            return true;
+        }
+        let size = element_size(entity);
+        offset.x < size.width && offset.y < size.height
+    }
+
+    /// The viewport with scroll bar hints; the corner button shows or hides the elements outside it
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, _style: &UiElementStyle, events: &mut WidgetEvents) -> Response {
+        let Some(area) = entity.get::<UiScrollArea>() else {
+            return empty_response(ui);
+        };
+        let size = element_size(entity);
+        let (rect, response) = ui.allocate_exact_size(Vec2::new(size.width.max(40.0), size.height.max(40.0)), Sense::hover());
+        if ui.is_rect_visible(rect) {
+            outline(ui, rect);
+            let bar_color = ui.visuals().widgets.inactive.bg_fill;
+            if area.vertical {
+                let bar = egui::Rect::from_min_max(egui::pos2(rect.right() - 6.0, rect.top() + 4.0), egui::pos2(rect.right() - 2.0, rect.center().y));
+                ui.painter().rect_filled(bar, 2.0, bar_color);
+            }
+            if area.horizontal {
+                let bar = egui::Rect::from_min_max(egui::pos2(rect.left() + 4.0, rect.bottom() - 6.0), egui::pos2(rect.center().x, rect.bottom() - 2.0));
+                ui.painter().rect_filled(bar, 2.0, bar_color);
+            }
+        }
+
+        let toggle_rect = egui::Rect::from_min_size(egui::pos2(rect.right() - 22.0, rect.top() + 2.0), Vec2::splat(18.0));
+        let hint = if area.expanded { "Hide elements outside the viewport" } else { "Show elements outside the viewport" };
+        let toggle = ui.put(toggle_rect, egui::Button::new(if area.expanded { "⊟" } else { "⊞" }).small()).on_hover_text(hint);
+        if toggle.clicked() {
+            let entity_id = entity.id();
+            events.update(move |world: &mut World| {
+                if let Some(mut area) = world.get_mut::<UiScrollArea>(entity_id) {
+                    area.expanded = !area.expanded;
+                }
+            });
+        }
+        response
+    }
+
+    fn render_run(
+        &self,
+        ui: &mut Ui,
+        entity: EntityRef,
+        _style: &UiElementStyle,
+        _state: &mut RunPreviewState,
+        _log_messages: &mut Vec<String>,
+    ) -> Response {
+        let size = element_size(entity);
+        ui.allocate_response(Vec2::new(size.width.max(40.0), size.height.max(40.0)), Sense::hover())
+    }
+
+    fn render_inspector(&self, ui: &mut Ui, _world: &mut World, _entity: Entity) {
+        ui.weak("The element's size is the viewport; elements dropped in it scroll with it");
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let area = entity.get::<UiScrollArea>()?;
+        Some(UiElementType::Custom(CustomElement::new(self.kind(), &area.name, true, 0.0, area)))
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, _style: &ElementStyleCode) {
+        let Some(area) = element_component::<UiScrollArea>(element) else {
+            return;
+        };
+        let scroll = format!(
+            "egui::ScrollArea::new([{}, {}]).max_width({:.1}).max_height({:.1}).auto_shrink(false)",
+            area.horizontal,
+            area.vertical,
+            element.size.width.max(40.0),
+            element.size.height.max(40.0)
+        );
+        if !gen.has_children(element.entity_id, None) {
+            gen.writeln(&format!("{}.show(ui, |_ui| {{}});", scroll));
+            return;
+        }
+        gen.writeln(&format!("{}.show(ui, |ui| {{", scroll));
+        gen.push_indent();
+        gen.writeln("let origin = ui.cursor().min;");
+        gen.generate_children(element, 0, "origin");
+        gen.pop_indent();
+        gen.writeln("});");
+    }
+}
+
+pub struct TabStripWidget;
+
+impl TabStripWidget {
+    /// Tab row over the strip's area; returns the tab clicked, if any
+    fn show(ui: &mut Ui, strip: &UiTabStrip, open: usize, size: &UiElementSize, style: &UiElementStyle, design: bool) -> (Response, Option<usize>) {
+        let mut clicked = None;
+        let response = ui.add_enabled_ui(strip.enabled, |ui| {
+            ui.vertical(|ui| {
+                ui.set_min_size(Vec2::new(size.width.max(60.0), size.height.max(HEADER_HEIGHT)));
+                let row = ui.horizontal(|ui| {
+                    for (index, tab) in strip.tabs.iter().enumerate() {
+                        if ui.selectable_label(index == open, style.rich_text(tab, strip.font_size)).clicked() {
+                            clicked = Some(index);
+                        }
+                    }
+                }).response;
+                ui.separator();
+                row
+            })
+        });
+        if design {
+            outline(ui, response.response.rect);
+        }
+        (response.response.union(response.inner.inner), clicked)
+    }
+
+    /// Change the tabs of a strip, moving the elements on each tab along with it.
+    /// `slot_of` maps a tab's old index to its new one.
+    fn retab(world: &mut World, entity: Entity, strip: UiTabStrip, slot_of: impl Fn(usize) -> usize) {
+        let mut query = world.query::<&mut UiElementContainer>();
+        for mut container in query.iter_mut(world) {
+            if container.parent_group == Some(entity) {
+                container.slot = slot_of(container.slot);
+            }
+        }
+        if let Some(mut current) = world.get_mut::<UiTabStrip>(entity) {
+            *current = strip;
+        }
+    }
+}
+
+impl DesignerWidget for TabStripWidget {
+    fn kind(&self) -> &'static str {
+        "tab_strip"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Tab Strip"
+    }
+
+    fn icon(&self) -> &'static str {
+        "📑"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiTabStrip>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiTabStrip>()
+    }
+
+    fn default_size(&self) -> UiElementSize {
+        UiElementSize { width: 260.0, height: 180.0 }
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiTabStrip::new(&format!("Tabs {}", number)));
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiTabStrip>().map(|strip| strip.name.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, name: String) {
+        if let Some(mut strip) = entity.get_mut::<UiTabStrip>() {
+            strip.name = name;
+        }
+    }
+
+    fn enabled(&self, entity: EntityRef) -> bool {
+        entity.get::<UiTabStrip>().is_some_and(|strip| strip.enabled)
+    }
+
+    fn set_enabled(&self, entity: &mut EntityWorldMut, enabled: bool) {
+        if let Some(mut strip) = entity.get_mut::<UiTabStrip>() {
+            strip.enabled = enabled;
+        }
+    }
+
+    fn is_container(&self) -> bool {
+        true
+    }
+
+    fn child_slot(&self, entity: EntityRef) -> Option<usize> {
+        entity.get::<UiTabStrip>().map(|strip| strip.current)
+    }
+
+    fn shows_child(&self, entity: EntityRef, slot: usize, _offset: Vec2, state: Option<&RunPreviewState>) -> bool {
+        let Some(strip) = entity.get::<UiTabStrip>() else {
+            return true;
+        };
+        let open = match state {
+            Some(state) => state.open_tabs.get(&entity.id()).copied().unwrap_or(strip.default_tab),
+            None => strip.current,
+        };
+        slot == open
+    }
+
+    /// Clicking a tab on the canvas opens it; elements dropped on the strip go to the open tab
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, style: &UiElementStyle, events: &mut WidgetEvents) -> Response {
+        let Some(strip) = entity.get::<UiTabStrip>() else {
+            return empty_response(ui);
+        };
+        let (response, clicked) = Self::show(ui, strip, strip.current, &element_size(entity), style, true);
+        if let Some(tab) = clicked.filter(|tab| *tab != strip.current) {
+            let entity_id = entity.id();
+            events.update(move |world: &mut World| {
+                if let Some(mut strip) = world.get_mut::<UiTabStrip>(entity_id) {
+                    strip.current = tab;
+                }
+            });
+        }
+        response
+    }
+
+    fn render_run(
+        &self,
+        ui: &mut Ui,
+        entity: EntityRef,
+        style: &UiElementStyle,
+        state: &mut RunPreviewState,
+        _log_messages: &mut Vec<String>,
+    ) -> Response {
+        let Some(strip) = entity.get::<UiTabStrip>() else {
+            return empty_response(ui);
+        };
+        let open = state.open_tabs.entry(entity.id()).or_insert(strip.default_tab);
+        let (response, clicked) = Self::show(ui, strip, *open, &element_size(entity), style, false);
+        if let Some(tab) = clicked {
+            *open = tab;
+        }
+        response
+    }
+
+    fn render_inspector(&self, ui: &mut Ui, world: &mut World, entity: Entity) {
+        let Some(mut strip) = world.get::<UiTabStrip>(entity).cloned() else {
+            return;
+        };
+        let len = strip.tabs.len();
+        let mut renamed = false;
+        let mut moved = None;
+        let mut removed = None;
+
+        ui.label(RichText::new("Tabs").strong());
+        for (index, tab) in strip.tabs.iter_mut().enumerate() {
+            ui.push_id(index, |ui| {
+                ui.horizontal(|ui| {
+                    if ui.radio(strip.default_tab == index, "").on_hover_text("Tab the app starts on").clicked() {
+                        strip.default_tab = index;
+                        renamed = true;
+                    }
+                    renamed |= ui.add(egui::TextEdit::singleline(tab).hint_text("Title").desired_width(120.0)).changed();
+                    if ui.add_enabled(index > 0, egui::Button::new("⬆").small()).clicked() {
+                        moved = Some((index, index - 1));
+                    }
+                    if ui.add_enabled(index + 1 < len, egui::Button::new("⬇").small()).clicked() {
+                        moved = Some((index, index + 1));
+                    }
+                    if ui.add_enabled(len > 1, egui::Button::new("🗑").small()).on_hover_text("Remove tab; its elements move to the tab before it").clicked() {
+                        removed = Some(index);
+                    }
+                });
+            });
+        }
+        let added = ui.button("➕ Add Tab").clicked();
+
+        if let Some((from, to)) = moved {
+            strip.tabs.swap(from, to);
+            let swap = |index: usize| if index == from { to } else if index == to { from } else { index };
+            strip.default_tab = swap(strip.default_tab);
+            strip.current = swap(strip.current);
+            Self::retab(world, entity, strip, swap);
+        } else if let Some(removed) = removed {
+            strip.tabs.remove(removed);
+            let shift = |index: usize| if index >= removed { index.saturating_sub(1) } else { index };
+            strip.default_tab = shift(strip.default_tab);
+            strip.current = shift(strip.current);
+            Self::retab(world, entity, strip, shift);
+        } else if added {
+            strip.tabs.push(format!("Tab {}", len + 1));
+            strip.current = len;
+            Self::retab(world, entity, strip, |index| index);
+        } else if renamed {
+            Self::retab(world, entity, strip, |index| index);
+        }
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let strip = entity.get::<UiTabStrip>()?;
+        Some(UiElementType::Custom(CustomElement::new(self.kind(), &strip.name, strip.enabled, strip.font_size, strip)))
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, style: &ElementStyleCode) {
+        let Some(strip) = element_component::<UiTabStrip>(element) else {
+            return;
+        };
+        let field = strip.tab_field();
+        let has_children = (0..strip.tabs.len()).any(|slot| gen.has_children(element.entity_id, Some(slot)));
+        if has_children {
+            gen.writeln("let origin = ui.cursor().min;");
+        }
+        gen.writeln(&format!("ui.set_min_size(egui::vec2({:.1}, {:.1}));", element.size.width.max(60.0), element.size.height.max(HEADER_HEIGHT)));
+        gen.writeln("ui.horizontal(|ui| {");
+        gen.push_indent();
+        for (index, tab) in strip.tabs.iter().enumerate() {
+            gen.writeln(&format!(
+                "ui.selectable_value(&mut app.{}, {}, {}){};",
+                field,
+                index,
+                style.text(tab, strip.font_size),
+                style.tooltip()
+            ));
+        }
+        gen.pop_indent();
+        gen.writeln("});");
+        gen.writeln("ui.separator();");
+        if !has_children {
+            return;
+        }
+        gen.writeln(&format!("match app.{} {{", field));
+        gen.push_indent();
+        for slot in 0..strip.tabs.len() {
+            if !gen.has_children(element.entity_id, Some(slot)) {
+                continue;
+            }
+            gen.writeln(&format!("{} => {{", slot));
+            gen.push_indent();
+            gen.generate_children(element, slot, "origin");
+            gen.pop_indent();
+            gen.writeln("}");
+        }
+        gen.writeln("_ => {}");
+        gen.pop_indent();
+        gen.writeln("}");
+    }
+
+    fn app_fields(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        match element_component::<UiTabStrip>(element) {
+            Some(strip) => vec![(strip.tab_field(), "usize".to_string())],
+            None => Vec::new(),
+        }
+    }
+
+    fn app_field_values(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        match element_component::<UiTabStrip>(element) {
+            Some(strip) if strip.default_tab != 0 => vec![(strip.tab_field(), strip.default_tab.to_string())],
+            _ => Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,293 @@
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypeRegistry;
+use egui::{Response, RichText, Ui};
+use crate::codegen::{snake_ident, CodeGenerator, ElementStyleCode};
+use crate::codegen_thread::{CustomElement, UiElementSnapshot, UiElementType};
+use crate::components::*;
+use crate::resources::RunPreviewState;
+use crate::syntax_highlighting::SyntaxHighlighter;
+use super::{element_component, element_size, empty_response, DesignerWidget, WidgetEvents};
+
+pub struct CodeEditorWidget;
+
+impl CodeEditorWidget {
+    /// Editor laid out as in the generated code: a line number gutter beside a highlighted
+    /// `TextEdit`, scrolling once the text is longer than the editor's rows
+    fn show(ui: &mut Ui, editor: &UiCodeEditor, text: &mut String, width: f32, interactive: bool) -> Response {
+        let extension = editor.language.extension();
+        let font_size = editor.font_size;
+        let mut layouter = |ui: &Ui, buffer: &dyn egui::TextBuffer, wrap_width: f32| {
+            let mut job = SyntaxHighlighter::shared().layout_job(buffer.as_str(), extension, font_size);
+            job.wrap.max_width = wrap_width;
+            ui.fonts(|fonts| fonts.layout_job(job))
+        };
+        let row_height = ui.fonts(|fonts| fonts.row_height(&egui::FontId::monospace(font_size)));
+        let rows = editor.rows.max(1);
+
+        ui.add_enabled_ui(editor.enabled, |ui| {
+            ui.set_max_width(width.max(60.0));
+            egui::ScrollArea::vertical()
+                .max_height(row_height * rows as f32 + 4.0)
+                .show(ui, |ui| {
+                    ui.horizontal_top(|ui| {
+                        if editor.line_numbers {
+                            egui::Frame::new().inner_margin(egui::Margin::symmetric(0, 2)).show(ui, |ui| {
+                                let numbers = RichText::new(line_numbers(text, rows)).monospace().size(font_size).weak();
+                                ui.add(egui::Label::new(numbers).selectable(false));
+                            });
+                        }
+                        ui.add(
+                            egui::TextEdit::multiline(text)
+                                .code_editor()
+                                .desired_rows(rows)
+                                .desired_width(f32::INFINITY)
+                                .interactive(interactive)
+                                .layouter(&mut layouter),
+                        )
+                    })
+                    .inner
+                })
+                .inner
+        })
+        .inner
+    }
+}
+
+/// Numbers of the text's lines, one per line and at least `rows`; the same as the generated `line_numbers`
+pub fn line_numbers(text: &str, rows: usize) -> String {
+    let lines = text.split('\n').count().max(rows);
+    (1..=lines).map(|line| line.to_string()).collect::<Vec<_>>().join("\n")
+}
+
+impl DesignerWidget for CodeEditorWidget {
+    fn kind(&self) -> &'static str {
+        "code_editor"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Code Editor"
+    }
+
+    fn icon(&self) -> &'static str {
+        "📝"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiCodeEditor>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiCodeEditor>()
+    }
+
+    /// The height follows the editor's rows
+    fn default_size(&self) -> UiElementSize {
+        UiElementSize { width: 360.0, height: 0.0 }
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiCodeEditor::new(&format!("Code {}", number)));
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiCodeEditor>().map(|editor| editor.name.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, name: String) {
+        if let Some(mut editor) = entity.get_mut::<UiCodeEditor>() {
+            editor.name = name;
+        }
+    }
+
+    fn enabled(&self, entity: EntityRef) -> bool {
+        entity.get::<UiCodeEditor>().is_some_and(|editor| editor.enabled)
+    }
+
+    fn set_enabled(&self, entity: &mut EntityWorldMut, enabled: bool) {
+        if let Some(mut editor) = entity.get_mut::<UiCodeEditor>() {
+            editor.enabled = enabled;
+        }
+    }
+
+    /// Highlighted preview of the initial text; it is edited in the Inspector
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, _style: &UiElementStyle, _events: &mut WidgetEvents) -> Response {
+        let Some(editor) = entity.get::<UiCodeEditor>() else {
+            return empty_response(ui);
+        };
+        let mut text = editor.text.clone();
+        Self::show(ui, editor, &mut text, element_size(entity).width, false)
+    }
+
+    fn render_run(
+        &self,
+        ui: &mut Ui,
+        entity: EntityRef,
+        _style: &UiElementStyle,
+        state: &mut RunPreviewState,
+        _log_messages: &mut Vec<String>,
+    ) -> Response {
+        let Some(editor) = entity.get::<UiCodeEditor>() else {
+            return empty_response(ui);
+        };
+        let text = state.text_values.entry(entity.id()).or_insert_with(|| editor.text.clone());
+        Self::show(ui, editor, text, element_size(entity).width, true)
+    }
+
+    fn render_inspector(&self, ui: &mut Ui, world: &mut World, entity: Entity) {
+        let Some(editor) = world.get::<UiCodeEditor>(entity).cloned() else {
+            return;
+        };
+        ui.label(RichText::new("Initial Text").strong());
+        let mut text = editor.text.clone();
+        let preview = UiCodeEditor { enabled: true, rows: editor.rows.min(12), ..editor.clone() };
+        let response = ui.push_id("inspector_code_editor", |ui| Self::show(ui, &preview, &mut text, ui.available_width(), true)).inner;
+        if response.changed() {
+            if let Some(mut editor) = world.get_mut::<UiCodeEditor>(entity) {
+                editor.text = text;
+            }
+        }
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let editor = entity.get::<UiCodeEditor>()?;
+        Some(UiElementType::Custom(CustomElement::new(self.kind(), &editor.name, editor.enabled, editor.font_size, editor)))
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, style: &ElementStyleCode) {
+        let Some(editor) = element_component::<UiCodeEditor>(element) else {
+            return;
+        };
+        let field = snake_ident(&editor.name);
+        let rows = editor.rows.max(1);
+        gen.writeln("let mut layouter = |ui: &egui::Ui, text: &dyn egui::TextBuffer, wrap_width: f32| {");
+        gen.push_indent();
+        gen.writeln(&format!(
+            "let mut job = CodeHighlighter::shared().layout_job(text.as_str(), \"{}\", {:.1});",
+            editor.language.extension(),
+            editor.font_size
+        ));
+        gen.writeln("job.wrap.max_width = wrap_width;");
+        gen.writeln("ui.fonts(|fonts| fonts.layout_job(job))");
+        gen.pop_indent();
+        gen.writeln("};");
+        gen.writeln(&format!("let row_height = ui.fonts(|fonts| fonts.row_height(&egui::FontId::monospace({:.1})));", editor.font_size));
+        gen.writeln(&format!("ui.set_max_width({:.1});", element.size.width.max(60.0)));
+        gen.writeln(&format!("egui::ScrollArea::vertical().max_height(row_height * {:.1} + 4.0).show(ui, |ui| {{", rows as f32));
+        gen.push_indent();
+        gen.writeln("ui.horizontal_top(|ui| {");
+        gen.push_indent();
+        if editor.line_numbers {
+            gen.writeln("egui::Frame::new().inner_margin(egui::Margin::symmetric(0, 2)).show(ui, |ui| {");
+            gen.push_indent();
+            gen.writeln(&format!(
+                "let numbers = egui::RichText::new(line_numbers(&app.{}, {})).monospace().size({:.1}).weak();",
+                field,
+                rows,
+                editor.font_size
+            ));
+            gen.writeln("ui.add(egui::Label::new(numbers).selectable(false));");
+            gen.pop_indent();
+            gen.writeln("});");
+        }
+        gen.writeln("ui.add(");
+        gen.push_indent();
+        gen.writeln(&format!(
+            "egui::TextEdit::multiline(&mut app.{}).code_editor().desired_rows({}).desired_width(f32::INFINITY).layouter(&mut layouter),",
+            field,
+            rows
+        ));
+        gen.pop_indent();
+        gen.writeln(&format!("){};", style.tooltip()));
+        gen.pop_indent();
+        gen.writeln("});");
+        gen.pop_indent();
+        gen.writeln("});");
+    }
+
+    fn app_fields(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        vec![(snake_ident(element.element_type.label()), "String".to_string())]
+    }
+
+    fn app_field_values(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        match element_component::<UiCodeEditor>(element) {
+            Some(editor) if !editor.text.is_empty() => {
+                vec![(snake_ident(&editor.name), format!("\"{}\".to_string()", editor.text.escape_default()))]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn required_crates(&self, _element: &UiElementSnapshot) -> Vec<(&'static str, &'static str)> {
+        vec![("syntect", "syntax highlighting")]
+    }
+
+    fn generate_support_code(&self, gen: &mut CodeGenerator, elements: &[&UiElementSnapshot]) {
+        gen.writeln("/// Syntax highlighting of the code editors, loaded once");
+        gen.writeln("pub struct CodeHighlighter {");
+        gen.push_indent();
+        gen.writeln("syntax_set: syntect::parsing::SyntaxSet,");
+        gen.writeln("theme: syntect::highlighting::Theme,");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("");
+        gen.writeln("impl CodeHighlighter {");
+        gen.push_indent();
+        gen.writeln("pub fn shared() -> &'static CodeHighlighter {");
+        gen.push_indent();
+        gen.writeln("static SHARED: std::sync::OnceLock<CodeHighlighter> = std::sync::OnceLock::new();");
+        gen.writeln("SHARED.get_or_init(|| CodeHighlighter {");
+        gen.push_indent();
+        gen.writeln("syntax_set: syntect::parsing::SyntaxSet::load_defaults_newlines(),");
+        gen.writeln("theme: syntect::highlighting::ThemeSet::load_defaults().themes[\"base16-ocean.dark\"].clone(),");
+        gen.pop_indent();
+        gen.writeln("})");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("");
+        gen.writeln("/// Highlighted `code` in a monospace font; `extension` picks the language");
+        gen.writeln("pub fn layout_job(&self, code: &str, extension: &str, font_size: f32) -> egui::text::LayoutJob {");
+        gen.push_indent();
+        gen.writeln("let syntax = self.syntax_set.find_syntax_by_extension(extension)");
+        gen.push_indent();
+        gen.writeln(".unwrap_or_else(|| self.syntax_set.find_syntax_plain_text());");
+        gen.pop_indent();
+        gen.writeln("let mut highlighter = syntect::easy::HighlightLines::new(syntax, &self.theme);");
+        gen.writeln("let font_id = egui::FontId::monospace(font_size);");
+        gen.writeln("let mut job = egui::text::LayoutJob::default();");
+        gen.writeln("for line in syntect::util::LinesWithEndings::from(code) {");
+        gen.push_indent();
+        gen.writeln("let ranges = highlighter.highlight_line(line, &self.syntax_set).unwrap_or_else(|_| {");
+        gen.push_indent();
+        gen.writeln("vec![(syntect::highlighting::Style { foreground: syntect::highlighting::Color::WHITE, ..Default::default() }, line)]");
+        gen.pop_indent();
+        gen.writeln("});");
+        gen.writeln("for (style, text) in ranges {");
+        gen.push_indent();
+        gen.writeln("let color = egui::Color32::from_rgb(style.foreground.r, style.foreground.g, style.foreground.b);");
+        gen.writeln("job.append(text, 0.0, egui::TextFormat::simple(font_id.clone(), color));");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("job");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("");
+
+        let uses_line_numbers = elements.iter()
+            .filter_map(|element| element_component::<UiCodeEditor>(element))
+            .any(|editor| editor.line_numbers);
+        if uses_line_numbers {
+            gen.writeln("/// Numbers of the text's lines, one per line and at least `rows`");
+            gen.writeln("pub fn line_numbers(text: &str, rows: usize) -> String {");
+            gen.push_indent();
+            gen.writeln("let lines = text.split('\\n').count().max(rows);");
+            gen.writeln("(1..=lines).map(|line| line.to_string()).collect::<Vec<_>>().join(\"\\n\")");
+            gen.pop_indent();
+            gen.writeln("}");
+            gen.writeln("");
+        }
+    }
+}
@@ -0,0 +1,321 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypeRegistry;
+use egui::{Color32, Rect, Response, TextureHandle, Ui, Vec2};
+use crate::codegen::{snake_ident, CodeGenerator, ElementStyleCode};
+use crate::codegen_thread::{CustomElement, UiElementSnapshot, UiElementType};
+use crate::components::*;
+use super::{element_component, element_size, empty_response, DesignerWidget, WidgetEvents};
+
+/// Directory of the project searched for image files
+const ASSETS_DIR: &str = "assets";
+
+pub struct ImageWidget;
+
+impl DesignerWidget for ImageWidget {
+    fn kind(&self) -> &'static str {
+        "image"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Image"
+    }
+
+    fn icon(&self) -> &'static str {
+        "🖼"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiImage>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiImage>()
+    }
+
+    fn default_size(&self) -> UiElementSize {
+        UiElementSize { width: 120.0, height: 120.0 }
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiImage {
+            name: format!("Image {}", number),
+            path: String::new(),
+            fit: ImageFit::Contain,
+        });
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiImage>().map(|image| image.name.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, name: String) {
+        if let Some(mut image) = entity.get_mut::<UiImage>() {
+            image.name = name;
+        }
+    }
+
+    /// Images have no enabled state
+    fn enabled(&self, _entity: EntityRef) -> bool {
+        true
+    }
+
+    fn set_enabled(&self, _entity: &mut EntityWorldMut, _enabled: bool) {}
+
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, _style: &UiElementStyle, _events: &mut WidgetEvents) -> Response {
+        let Some(image) = entity.get::<UiImage>() else {
+            return empty_response(ui);
+        };
+        let size = element_size(entity);
+        let size = Vec2::new(size.width.max(1.0), size.height.max(1.0));
+        match image_texture(ui.ctx(), &image.path) {
+            Ok(texture) => ui.add_sized(size, fitted_image(&texture, image.fit, size)),
+            Err(err) => image_placeholder(ui, size, &err),
+        }
+    }
+
+    fn render_inspector(&self, ui: &mut Ui, world: &mut World, entity: Entity) {
+        let Some(image) = world.get::<UiImage>(entity).cloned() else {
+            return;
+        };
+
+        let mut chosen = None;
+        ui.horizontal(|ui| {
+            ui.label("Asset:");
+            let selected = if image.path.is_empty() { "Choose…" } else { image.path.as_str() };
+            egui::ComboBox::from_id_salt(("image_asset", entity))
+                .selected_text(selected)
+                .show_ui(ui, |ui| {
+                    let assets = project_image_assets();
+                    if assets.is_empty() {
+                        ui.weak(format!("No PNG or JPEG files in {}/", ASSETS_DIR));
+                    }
+                    for asset in assets {
+                        if ui.selectable_label(asset == image.path, &asset).clicked() {
+                            chosen = Some(asset);
+                        }
+                    }
+                });
+        });
+        if let Some(path) = chosen {
+            if let Some(mut image) = world.get_mut::<UiImage>(entity) {
+                image.path = path;
+            }
+        }
+
+        match image_texture(ui.ctx(), &image.path) {
+            Ok(texture) => {
+                let [width, height] = texture.size();
+                ui.horizontal(|ui| {
+                    ui.weak(format!("{} × {} px", width, height));
+                    if ui.small_button("Size to image").clicked() {
+                        if let Some(mut size) = world.get_mut::<UiElementSize>(entity) {
+                            size.width = width as f32;
+                            size.height = height as f32;
+                        }
+                    }
+                });
+            }
+            Err(err) => {
+                ui.colored_label(Color32::from_rgb(255, 150, 0), format!("⚠ {}", err));
+            }
+        }
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let image = entity.get::<UiImage>()?;
+        Some(UiElementType::Custom(CustomElement::new(self.kind(), &image.name, true, 0.0, image)))
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, style: &ElementStyleCode) {
+        let Some(image) = element_component::<UiImage>(element) else {
+            return;
+        };
+        let path = image.path.trim();
+        if path.is_empty() {
+            gen.writeln(&format!("// No file chosen for image \"{}\"", image.name.escape_default()));
+            gen.writeln(&format!("ui.allocate_space(egui::vec2({:.1}, {:.1}));", element.size.width, element.size.height));
+            return;
+        }
+
+        // The texture is loaded on first use and kept in the app
+        let (width, height) = (element.size.width.max(1.0), element.size.height.max(1.0));
+        let size = format!("egui::vec2({:.1}, {:.1})", width, height);
+        gen.writeln(&format!(
+            "let texture = app.{}.get_or_insert_with(|| load_texture(ui.ctx(), \"{}\"));",
+            texture_field(path),
+            path.escape_default()
+        ));
+        let image = match image.fit {
+            ImageFit::Contain => format!("egui::Image::new(&*texture).fit_to_exact_size({})", size),
+            ImageFit::Stretch => format!("egui::Image::new(&*texture).fit_to_exact_size({}).maintain_aspect_ratio(false)", size),
+            ImageFit::Cover => {
+                gen.writeln("let texture_size = texture.size_vec2();");
+                gen.writeln(&format!("let scale = ({:.1} / texture_size.x).max({:.1} / texture_size.y);", width, height));
+                gen.writeln(&format!("let uv = egui::Rect::from_center_size(egui::pos2(0.5, 0.5), {} / (texture_size * scale));", size));
+                format!("egui::Image::new(&*texture).uv(uv).fit_to_exact_size({}).maintain_aspect_ratio(false)", size)
+            }
+        };
+        gen.writeln(&format!("ui.add_sized({}, {}){};", size, image, style.tooltip()));
+    }
+
+    fn app_fields(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        match element_component::<UiImage>(element) {
+            Some(image) if !image.path.trim().is_empty() => {
+                vec![(texture_field(image.path.trim()), "Option<egui::TextureHandle>".to_string())]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn required_crates(&self, element: &UiElementSnapshot) -> Vec<(&'static str, &'static str)> {
+        match element_component::<UiImage>(element) {
+            Some(image) if !image.path.trim().is_empty() => vec![("image", "loading images")],
+            _ => Vec::new(),
+        }
+    }
+
+    fn generate_support_code(&self, gen: &mut CodeGenerator, elements: &[&UiElementSnapshot]) {
+        if !elements.iter().any(|element| !self.required_crates(element).is_empty()) {
+            return;
+        }
+        gen.writeln("/// Load an image file into a texture; files that fail to load give a magenta placeholder");
+        gen.writeln("pub fn load_texture(ctx: &egui::Context, path: &str) -> egui::TextureHandle {");
+        gen.push_indent();
+        gen.writeln("let image = match image::open(path) {");
+        gen.push_indent();
+        gen.writeln("Ok(image) => {");
+        gen.push_indent();
+        gen.writeln("let rgba = image.to_rgba8();");
+        gen.writeln("egui::ColorImage::from_rgba_unmultiplied([rgba.width() as usize, rgba.height() as usize], rgba.as_raw())");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("Err(err) => {");
+        gen.push_indent();
+        gen.writeln("eprintln!(\"Failed to load {}: {}\", path, err);");
+        gen.writeln("egui::ColorImage::filled([1, 1], egui::Color32::from_rgb(255, 0, 255))");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.pop_indent();
+        gen.writeln("};");
+        gen.writeln("ctx.load_texture(path, image, egui::TextureOptions::LINEAR)");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("");
+    }
+}
+
+/// `App` field holding the texture of an image file; elements showing the same file share it
+fn texture_field(path: &str) -> String {
+    format!("{}_texture", snake_ident(&path.replace(['/', '\\', '.'], " ")))
+}
+
+/// `egui::Image` of a texture filling `size` the way `fit` asks for, matching the generated code
+fn fitted_image(texture: &TextureHandle, fit: ImageFit, size: Vec2) -> egui::Image<'static> {
+    let image = egui::Image::new(texture).fit_to_exact_size(size);
+    match fit {
+        ImageFit::Contain => image,
+        ImageFit::Stretch => image.maintain_aspect_ratio(false),
+        ImageFit::Cover => {
+            let texture_size = texture.size_vec2();
+            let scale = (size.x / texture_size.x).max(size.y / texture_size.y);
+            let uv = Rect::from_center_size(egui::pos2(0.5, 0.5), size / (texture_size * scale));
+            image.uv(uv).maintain_aspect_ratio(false)
+        }
+    }
+}
+
+/// Outlined box shown instead of an image that is not chosen or fails to load
+fn image_placeholder(ui: &mut Ui, size: Vec2, message: &str) -> Response {
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+    if ui.is_rect_visible(rect) {
+        let color = ui.visuals().weak_text_color();
+        ui.painter().rect_stroke(rect, 2.0, egui::Stroke::new(1.0, color), egui::StrokeKind::Inside);
+        ui.painter().text(rect.center(), egui::Align2::CENTER_CENTER, "🖼", egui::FontId::proportional(20.0), color);
+    }
+    response.on_hover_text(message)
+}
+
+/// Canvas texture of an image file, cached per file and reloaded when the file changes
+#[derive(Clone)]
+struct CachedTexture {
+    modified: Option<SystemTime>,
+    texture: Result<TextureHandle, String>,
+}
+
+#[derive(Clone, Default)]
+struct ImageTextures(HashMap<PathBuf, CachedTexture>);
+
+/// Texture of a project image file for the canvas and Inspector
+pub fn image_texture(ctx: &egui::Context, path: &str) -> Result<TextureHandle, String> {
+    let path = path.trim();
+    if path.is_empty() {
+        return Err("No image file chosen".to_string());
+    }
+    let full_path = project_asset_path(path);
+    let modified = std::fs::metadata(&full_path).and_then(|metadata| metadata.modified()).ok();
+
+    let cache_id = egui::Id::new("designer_image_textures");
+    let cached = ctx.data_mut(|data| data.get_temp_mut_or_default::<ImageTextures>(cache_id).0.get(&full_path).cloned());
+    if let Some(cached) = cached {
+        if cached.modified == modified {
+            return cached.texture;
+        }
+    }
+
+    // Loaded outside `data_mut`, which holds the context lock that `load_texture` needs
+    let texture = load_color_image(&full_path).map(|image| ctx.load_texture(path, image, egui::TextureOptions::LINEAR));
+    ctx.data_mut(|data| {
+        data.get_temp_mut_or_default::<ImageTextures>(cache_id)
+            .0
+            .insert(full_path, CachedTexture { modified, texture: texture.clone() })
+    });
+    texture
+}
+
+fn load_color_image(path: &Path) -> Result<egui::ColorImage, String> {
+    let image = image::open(path).map_err(|err| format!("Failed to load {}: {}", path.display(), err))?;
+    let rgba = image.to_rgba8();
+    Ok(egui::ColorImage::from_rgba_unmultiplied([rgba.width() as usize, rgba.height() as usize], rgba.as_raw()))
+}
+
+/// Location of a project file; relative paths start at the project directory
+pub fn project_asset_path(path: &str) -> PathBuf {
+    let path = Path::new(path);
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")).join(path)
+    }
+}
+
+/// PNG and JPEG files in the project's assets directory, as paths relative to the project directory
+pub fn project_image_assets() -> Vec<String> {
+    fn collect(dir: &Path, relative: &str, depth: usize, assets: &mut Vec<String>) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let relative = format!("{}/{}", relative, name);
+            let path = entry.path();
+            if path.is_dir() {
+                if depth < 4 {
+                    collect(&path, &relative, depth + 1, assets);
+                }
+            } else if matches!(
+                path.extension().and_then(|extension| extension.to_str()).map(str::to_ascii_lowercase).as_deref(),
+                Some("png" | "jpg" | "jpeg")
+            ) {
+                assets.push(relative);
+            }
+        }
+    }
+
+    let mut assets = Vec::new();
+    collect(&project_asset_path(ASSETS_DIR), ASSETS_DIR, 0, &mut assets);
+    assets.sort();
+    assets
+}
@@ -0,0 +1,442 @@
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypeRegistry;
+use egui::{Response, Ui, Vec2};
+use crate::codegen::{color_expr, snake_ident, CodeGenerator, ElementStyleCode};
+use crate::codegen_thread::{CustomElement, UiElementSnapshot, UiElementType};
+use crate::components::*;
+use crate::resources::RunPreviewState;
+use super::{element_component, element_size, empty_response, DesignerWidget, WidgetEvents};
+
+pub struct ProgressBarWidget;
+
+impl ProgressBarWidget {
+    fn progress_bar(bar: &UiProgressBar, value: f32, size: &UiElementSize) -> egui::ProgressBar {
+        let mut progress_bar = egui::ProgressBar::new(value)
+            .desired_width(size.width.max(20.0))
+            .desired_height(size.height.max(4.0))
+            .animate(bar.animate);
+        if bar.show_percentage {
+            progress_bar = progress_bar.show_percentage();
+        }
+        progress_bar
+    }
+}
+
+impl DesignerWidget for ProgressBarWidget {
+    fn kind(&self) -> &'static str {
+        "progress_bar"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Progress Bar"
+    }
+
+    fn icon(&self) -> &'static str {
+        "▬"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiProgressBar>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiProgressBar>()
+    }
+
+    fn default_size(&self) -> UiElementSize {
+        UiElementSize { width: 200.0, height: 20.0 }
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiProgressBar::new(&format!("Progress {}", number)));
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiProgressBar>().map(|bar| bar.name.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, name: String) {
+        if let Some(mut bar) = entity.get_mut::<UiProgressBar>() {
+            bar.name = name;
+        }
+    }
+
+    /// Progress bars have no enabled state
+    fn enabled(&self, _entity: EntityRef) -> bool {
+        true
+    }
+
+    fn set_enabled(&self, _entity: &mut EntityWorldMut, _enabled: bool) {}
+
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, _style: &UiElementStyle, _events: &mut WidgetEvents) -> Response {
+        let Some(bar) = entity.get::<UiProgressBar>() else {
+            return empty_response(ui);
+        };
+        ui.add(Self::progress_bar(bar, bar.value, &element_size(entity)))
+    }
+
+    fn render_run(
+        &self,
+        ui: &mut Ui,
+        entity: EntityRef,
+        _style: &UiElementStyle,
+        state: &mut RunPreviewState,
+        _log_messages: &mut Vec<String>,
+    ) -> Response {
+        let Some(bar) = entity.get::<UiProgressBar>() else {
+            return empty_response(ui);
+        };
+        let value = *state.progress.entry(entity.id()).or_insert(bar.value);
+        ui.add(Self::progress_bar(bar, value, &element_size(entity)))
+    }
+
+    fn render_run_controls(&self, ui: &mut Ui, entity: EntityRef, state: &mut RunPreviewState) {
+        let Some(bar) = entity.get::<UiProgressBar>() else {
+            return;
+        };
+        let value = state.progress.entry(entity.id()).or_insert(bar.value);
+        ui.add(egui::Slider::new(value, 0.0..=1.0).text(&bar.name));
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let bar = entity.get::<UiProgressBar>()?;
+        Some(UiElementType::Custom(CustomElement::new(self.kind(), &bar.name, true, 0.0, bar)))
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, style: &ElementStyleCode) {
+        let Some(bar) = element_component::<UiProgressBar>(element) else {
+            return;
+        };
+        let mut progress_bar = format!(
+            "egui::ProgressBar::new(app.{}).desired_width({:.1}).desired_height({:.1})",
+            snake_ident(&bar.name),
+            element.size.width.max(20.0),
+            element.size.height.max(4.0)
+        );
+        if bar.show_percentage {
+            progress_bar.push_str(".show_percentage()");
+        }
+        if bar.animate {
+            progress_bar.push_str(".animate(true)");
+        }
+        gen.writeln(&format!("ui.add({}){};", progress_bar, style.tooltip()));
+    }
+
+    fn app_fields(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        vec![(snake_ident(element.element_type.label()), "f32".to_string())]
+    }
+
+    fn app_field_values(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        match element_component::<UiProgressBar>(element) {
+            Some(bar) if bar.value != 0.0 => vec![(snake_ident(&bar.name), format!("{:?}", bar.value))],
+            _ => Vec::new(),
+        }
+    }
+}
+
+pub struct SpinnerWidget;
+
+impl SpinnerWidget {
+    fn spinner(spinner: &UiSpinner, style: &UiElementStyle) -> egui::Spinner {
+        let mut widget = egui::Spinner::new().size(spinner.size);
+        if let Some(color) = style.text_color {
+            widget = widget.color(color);
+        }
+        widget
+    }
+}
+
+impl DesignerWidget for SpinnerWidget {
+    fn kind(&self) -> &'static str {
+        "spinner"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Spinner"
+    }
+
+    fn icon(&self) -> &'static str {
+        "◌"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiSpinner>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiSpinner>()
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiSpinner::new(&format!("Busy {}", number)));
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiSpinner>().map(|spinner| spinner.name.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, name: String) {
+        if let Some(mut spinner) = entity.get_mut::<UiSpinner>() {
+            spinner.name = name;
+        }
+    }
+
+    /// Spinners have no enabled state
+    fn enabled(&self, _entity: EntityRef) -> bool {
+        true
+    }
+
+    fn set_enabled(&self, _entity: &mut EntityWorldMut, _enabled: bool) {}
+
+    /// Always spinning on the canvas so it can be found and dragged; faded when it starts hidden
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, style: &UiElementStyle, _events: &mut WidgetEvents) -> Response {
+        let Some(spinner) = entity.get::<UiSpinner>() else {
+            return empty_response(ui);
+        };
+        if !spinner.active {
+            ui.multiply_opacity(0.35);
+        }
+        ui.add(Self::spinner(spinner, style))
+    }
+
+    fn render_run(
+        &self,
+        ui: &mut Ui,
+        entity: EntityRef,
+        style: &UiElementStyle,
+        state: &mut RunPreviewState,
+        _log_messages: &mut Vec<String>,
+    ) -> Response {
+        let Some(spinner) = entity.get::<UiSpinner>() else {
+            return empty_response(ui);
+        };
+        let active = *state.spinning.entry(entity.id()).or_insert(spinner.active);
+        ui.add_visible(active, Self::spinner(spinner, style))
+    }
+
+    fn render_run_controls(&self, ui: &mut Ui, entity: EntityRef, state: &mut RunPreviewState) {
+        let Some(spinner) = entity.get::<UiSpinner>() else {
+            return;
+        };
+        let active = state.spinning.entry(entity.id()).or_insert(spinner.active);
+        ui.checkbox(active, &spinner.name);
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let spinner = entity.get::<UiSpinner>()?;
+        Some(UiElementType::Custom(CustomElement::new(self.kind(), &spinner.name, true, 0.0, spinner)))
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, style: &ElementStyleCode) {
+        let Some(spinner) = element_component::<UiSpinner>(element) else {
+            return;
+        };
+        let mut widget = format!("egui::Spinner::new().size({:.1})", spinner.size);
+        if let Some(color) = style.text_color() {
+            widget.push_str(&format!(".color({})", color));
+        }
+        gen.writeln(&format!("ui.add_visible(app.{}, {}){};", snake_ident(&spinner.name), widget, style.tooltip()));
+    }
+
+    fn app_fields(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        vec![(snake_ident(element.element_type.label()), "bool".to_string())]
+    }
+
+    fn app_field_values(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        match element_component::<UiSpinner>(element) {
+            Some(spinner) if spinner.active => vec![(snake_ident(&spinner.name), "true".to_string())],
+            _ => Vec::new(),
+        }
+    }
+}
+
+pub struct StatusLedWidget;
+
+impl StatusLedWidget {
+    fn show(ui: &mut Ui, led: &UiStatusLed, status: LedStatus, style: &UiElementStyle) -> Response {
+        ui.add_enabled_ui(led.enabled, |ui| {
+            ui.horizontal(|ui| {
+                let response = status_led(ui, status, led.diameter);
+                if led.label.is_empty() {
+                    response
+                } else {
+                    response.union(ui.label(style.rich_text(&led.label, led.font_size)))
+                }
+            })
+            .inner
+        })
+        .inner
+    }
+}
+
+impl DesignerWidget for StatusLedWidget {
+    fn kind(&self) -> &'static str {
+        "status_led"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Status LED"
+    }
+
+    fn icon(&self) -> &'static str {
+        "●"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiStatusLed>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiStatusLed>()
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiStatusLed::new(&format!("Status {}", number)));
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiStatusLed>().map(|led| led.label.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, label: String) {
+        if let Some(mut led) = entity.get_mut::<UiStatusLed>() {
+            led.label = label;
+        }
+    }
+
+    fn enabled(&self, entity: EntityRef) -> bool {
+        entity.get::<UiStatusLed>().is_some_and(|led| led.enabled)
+    }
+
+    fn set_enabled(&self, entity: &mut EntityWorldMut, enabled: bool) {
+        if let Some(mut led) = entity.get_mut::<UiStatusLed>() {
+            led.enabled = enabled;
+        }
+    }
+
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, style: &UiElementStyle, _events: &mut WidgetEvents) -> Response {
+        let Some(led) = entity.get::<UiStatusLed>() else {
+            return empty_response(ui);
+        };
+        Self::show(ui, led, led.status, style)
+    }
+
+    fn render_run(
+        &self,
+        ui: &mut Ui,
+        entity: EntityRef,
+        style: &UiElementStyle,
+        state: &mut RunPreviewState,
+        _log_messages: &mut Vec<String>,
+    ) -> Response {
+        let Some(led) = entity.get::<UiStatusLed>() else {
+            return empty_response(ui);
+        };
+        let status = *state.statuses.entry(entity.id()).or_insert(led.status);
+        Self::show(ui, led, status, style)
+    }
+
+    fn render_run_controls(&self, ui: &mut Ui, entity: EntityRef, state: &mut RunPreviewState) {
+        let Some(led) = entity.get::<UiStatusLed>() else {
+            return;
+        };
+        let status = state.statuses.entry(entity.id()).or_insert(led.status);
+        egui::ComboBox::from_id_salt(("run_led_status", entity.id()))
+            .selected_text(format!("{:?}", status))
+            .show_ui(ui, |ui| {
+                for option in LedStatus::ALL {
+                    ui.selectable_value(status, option, format!("{:?}", option));
+                }
+            });
+        ui.label(&led.label);
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let led = entity.get::<UiStatusLed>()?;
+        Some(UiElementType::Custom(CustomElement::new(self.kind(), &led.label, led.enabled, led.font_size, led)))
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, style: &ElementStyleCode) {
+        let Some(led) = element_component::<UiStatusLed>(element) else {
+            return;
+        };
+        let light = format!("status_led(ui, app.{}, {:.1}){};", snake_ident(&led.label), led.diameter, style.tooltip());
+        if led.label.is_empty() {
+            gen.writeln(&light);
+            return;
+        }
+        gen.writeln("ui.horizontal(|ui| {");
+        gen.push_indent();
+        gen.writeln(&light);
+        gen.writeln(&format!("ui.label({});", style.text(&led.label, led.font_size)));
+        gen.pop_indent();
+        gen.writeln("});");
+    }
+
+    fn app_fields(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        vec![(snake_ident(element.element_type.label()), "LedStatus".to_string())]
+    }
+
+    fn app_field_values(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        match element_component::<UiStatusLed>(element) {
+            Some(led) if led.status != LedStatus::default() => {
+                vec![(snake_ident(&led.label), format!("LedStatus::{:?}", led.status))]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn generate_support_code(&self, gen: &mut CodeGenerator, _elements: &[&UiElementSnapshot]) {
+        gen.writeln("/// State shown by a status LED");
+        gen.writeln("#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]");
+        gen.writeln("pub enum LedStatus {");
+        gen.push_indent();
+        gen.writeln("#[default]");
+        for status in LedStatus::ALL {
+            gen.writeln(&format!("{:?},", status));
+        }
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("");
+        gen.writeln("impl LedStatus {");
+        gen.push_indent();
+        gen.writeln("pub fn color(self) -> egui::Color32 {");
+        gen.push_indent();
+        gen.writeln("match self {");
+        gen.push_indent();
+        for status in LedStatus::ALL {
+            gen.writeln(&format!("LedStatus::{:?} => {},", status, color_expr(status.color())));
+        }
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("");
+        gen.writeln("/// Round light in the color of `status`");
+        gen.writeln("pub fn status_led(ui: &mut egui::Ui, status: LedStatus, diameter: f32) -> egui::Response {");
+        gen.push_indent();
+        gen.writeln("let (rect, response) = ui.allocate_exact_size(egui::vec2(diameter, diameter), egui::Sense::hover());");
+        gen.writeln("if ui.is_rect_visible(rect) {");
+        gen.push_indent();
+        gen.writeln("let color = status.color();");
+        gen.writeln("ui.painter().circle(rect.center(), diameter / 2.0, color, egui::Stroke::new(1.0, color.gamma_multiply(0.6)));");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("response");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("");
+    }
+}
+
+/// Round light in the color of `status`; the same as the generated `status_led`
+pub fn status_led(ui: &mut Ui, status: LedStatus, diameter: f32) -> Response {
+    let (rect, response) = ui.allocate_exact_size(Vec2::splat(diameter), egui::Sense::hover());
+    if ui.is_rect_visible(rect) {
+        let color = status.color();
+        ui.painter().circle(rect.center(), diameter / 2.0, color, egui::Stroke::new(1.0, color.gamma_multiply(0.6)));
+    }
+    response
+}
@@ -0,0 +1,419 @@
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypeRegistry;
+use egui::{Response, Ui, Vec2};
+use crate::codegen::{CodeGenerator, ElementStyleCode};
+use crate::codegen_thread::{CustomElement, UiElementSnapshot, UiElementType};
+use crate::components::*;
+use crate::resources::RunPreviewState;
+use super::{element_component, element_size, empty_response, DesignerWidget, WidgetEvents};
+
+pub struct LabelWidget;
+
+impl DesignerWidget for LabelWidget {
+    fn kind(&self) -> &'static str {
+        "label"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Label"
+    }
+
+    fn icon(&self) -> &'static str {
+        "🏷"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiLabel>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiLabel>()
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiLabel {
+            text: format!("Label {}", number),
+            enabled: true,
+            font_size: DEFAULT_FONT_SIZE,
+            heading: false,
+            monospace: false,
+            weak: false,
+            wrap: false,
+        });
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiLabel>().map(|label| label.text.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, text: String) {
+        if let Some(mut label) = entity.get_mut::<UiLabel>() {
+            label.text = text;
+        }
+    }
+
+    fn enabled(&self, entity: EntityRef) -> bool {
+        entity.get::<UiLabel>().is_some_and(|label| label.enabled)
+    }
+
+    fn set_enabled(&self, entity: &mut EntityWorldMut, enabled: bool) {
+        if let Some(mut label) = entity.get_mut::<UiLabel>() {
+            label.enabled = enabled;
+        }
+    }
+
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, style: &UiElementStyle, _events: &mut WidgetEvents) -> Response {
+        let Some(label) = entity.get::<UiLabel>() else {
+            return empty_response(ui);
+        };
+        let size = element_size(entity);
+
+        // Headings at the default size keep the theme's heading size, as in the generated code
+        let font_size = if label.heading && label.font_size == DEFAULT_FONT_SIZE { 0.0 } else { label.font_size };
+        let mut text = style.rich_text(&label.text, font_size);
+        if label.heading {
+            text = text.heading();
+        }
+        if label.monospace {
+            text = text.monospace();
+        }
+        if label.weak {
+            text = text.weak();
+        }
+
+        let mut widget = egui::Label::new(text).selectable(false);
+        if label.wrap {
+            ui.set_max_width(size.width.max(20.0));
+            widget = widget.wrap();
+        } else {
+            widget = widget.extend();
+        }
+        ui.add_enabled(label.enabled, widget)
+    }
+
+    fn render_inspector(&self, ui: &mut Ui, _world: &mut World, _entity: Entity) {
+        ui.weak("Text color, bold and italics are set in the Style section");
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let label = entity.get::<UiLabel>()?;
+        Some(UiElementType::Custom(CustomElement::new(self.kind(), &label.text, label.enabled, label.font_size, label)))
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, style: &ElementStyleCode) {
+        let Some(label) = element_component::<UiLabel>(element) else {
+            return;
+        };
+        let mut text = style.text(&label.text, label.font_size);
+        if label.heading || label.monospace || label.weak {
+            if text.starts_with('"') {
+                text = format!("egui::RichText::new({})", text);
+            }
+            if label.heading {
+                text.push_str(".heading()");
+            }
+            if label.monospace {
+                text.push_str(".monospace()");
+            }
+            if label.weak {
+                text.push_str(".weak()");
+            }
+        }
+        if label.wrap {
+            gen.writeln(&format!("ui.set_max_width({:.1});", element.size.width.max(20.0)));
+            gen.writeln(&format!("ui.add(egui::Label::new({}).wrap()){};", text, style.tooltip()));
+        } else {
+            gen.writeln(&format!("ui.label({}){};", text, style.tooltip()));
+        }
+    }
+}
+
+pub struct HyperlinkWidget;
+
+impl DesignerWidget for HyperlinkWidget {
+    fn kind(&self) -> &'static str {
+        "hyperlink"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Hyperlink"
+    }
+
+    fn icon(&self) -> &'static str {
+        "🔗"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiHyperlink>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiHyperlink>()
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiHyperlink {
+            label: format!("Link {}", number),
+            url: "https://example.com".to_string(),
+            enabled: true,
+            font_size: DEFAULT_FONT_SIZE,
+            open_in_new_tab: false,
+        });
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiHyperlink>().map(|link| link.label.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, label: String) {
+        if let Some(mut link) = entity.get_mut::<UiHyperlink>() {
+            link.label = label;
+        }
+    }
+
+    fn enabled(&self, entity: EntityRef) -> bool {
+        entity.get::<UiHyperlink>().is_some_and(|link| link.enabled)
+    }
+
+    fn set_enabled(&self, entity: &mut EntityWorldMut, enabled: bool) {
+        if let Some(mut link) = entity.get_mut::<UiHyperlink>() {
+            link.enabled = enabled;
+        }
+    }
+
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, style: &UiElementStyle, _events: &mut WidgetEvents) -> Response {
+        let Some(link) = entity.get::<UiHyperlink>() else {
+            return empty_response(ui);
+        };
+        // Looks like the link without opening it while designing
+        let mut text = style.rich_text(&link.label, link.font_size).underline();
+        if style.text_color.is_none() {
+            text = text.color(ui.visuals().hyperlink_color);
+        }
+        ui.add_enabled(link.enabled, egui::Label::new(text).selectable(false).extend())
+            .on_hover_text(&link.url)
+    }
+
+    fn render_run(
+        &self,
+        ui: &mut Ui,
+        entity: EntityRef,
+        style: &UiElementStyle,
+        _state: &mut RunPreviewState,
+        _log_messages: &mut Vec<String>,
+    ) -> Response {
+        let Some(link) = entity.get::<UiHyperlink>() else {
+            return empty_response(ui);
+        };
+        ui.add_enabled(
+            link.enabled,
+            egui::Hyperlink::from_label_and_url(style.rich_text(&link.label, link.font_size), &link.url)
+                .open_in_new_tab(link.open_in_new_tab),
+        )
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let link = entity.get::<UiHyperlink>()?;
+        Some(UiElementType::Custom(CustomElement::new(self.kind(), &link.label, link.enabled, link.font_size, link)))
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, style: &ElementStyleCode) {
+        let Some(link) = element_component::<UiHyperlink>(element) else {
+            return;
+        };
+        let text = style.text(&link.label, link.font_size);
+        let url = link.url.escape_default();
+        if link.open_in_new_tab {
+            gen.writeln(&format!(
+                "ui.add(egui::Hyperlink::from_label_and_url({}, \"{}\").open_in_new_tab(true)){};",
+                text,
+                url,
+                style.tooltip()
+            ));
+        } else {
+            gen.writeln(&format!("ui.hyperlink_to({}, \"{}\"){};", text, url, style.tooltip()));
+        }
+    }
+}
+
+pub struct SeparatorWidget;
+
+impl SeparatorWidget {
+    fn separator(orientation: SeparatorOrientation) -> egui::Separator {
+        match orientation {
+            SeparatorOrientation::Horizontal => egui::Separator::default().horizontal(),
+            SeparatorOrientation::Vertical => egui::Separator::default().vertical(),
+        }
+    }
+}
+
+impl DesignerWidget for SeparatorWidget {
+    fn kind(&self) -> &'static str {
+        "separator"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Separator"
+    }
+
+    fn icon(&self) -> &'static str {
+        "➖"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiSeparator>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiSeparator>()
+    }
+
+    fn default_size(&self) -> UiElementSize {
+        UiElementSize { width: 200.0, height: 12.0 }
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiSeparator {
+            name: format!("Separator {}", number),
+            orientation: SeparatorOrientation::Horizontal,
+        });
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiSeparator>().map(|separator| separator.name.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, name: String) {
+        if let Some(mut separator) = entity.get_mut::<UiSeparator>() {
+            separator.name = name;
+        }
+    }
+
+    /// Separators have no enabled state
+    fn enabled(&self, _entity: EntityRef) -> bool {
+        true
+    }
+
+    fn set_enabled(&self, _entity: &mut EntityWorldMut, _enabled: bool) {}
+
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, _style: &UiElementStyle, _events: &mut WidgetEvents) -> Response {
+        let Some(separator) = entity.get::<UiSeparator>() else {
+            return empty_response(ui);
+        };
+        let size = element_size(entity);
+        ui.add_sized(Vec2::new(size.width.max(4.0), size.height.max(4.0)), Self::separator(separator.orientation))
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let separator = entity.get::<UiSeparator>()?;
+        Some(UiElementType::Custom(CustomElement::new(self.kind(), &separator.name, true, 0.0, separator)))
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, _style: &ElementStyleCode) {
+        let Some(separator) = element_component::<UiSeparator>(element) else {
+            return;
+        };
+        let orientation = match separator.orientation {
+            SeparatorOrientation::Horizontal => "horizontal",
+            SeparatorOrientation::Vertical => "vertical",
+        };
+        gen.writeln(&format!(
+            "ui.add_sized(egui::vec2({:.1}, {:.1}), egui::Separator::default().{}());",
+            element.size.width.max(4.0),
+            element.size.height.max(4.0),
+            orientation
+        ));
+    }
+}
+
+pub struct SpacerWidget;
+
+impl DesignerWidget for SpacerWidget {
+    fn kind(&self) -> &'static str {
+        "spacer"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Spacer"
+    }
+
+    fn icon(&self) -> &'static str {
+        "⬜"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiSpacer>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiSpacer>()
+    }
+
+    fn default_size(&self) -> UiElementSize {
+        UiElementSize { width: 100.0, height: 20.0 }
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiSpacer { name: format!("Spacer {}", number) });
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiSpacer>().map(|spacer| spacer.name.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, name: String) {
+        if let Some(mut spacer) = entity.get_mut::<UiSpacer>() {
+            spacer.name = name;
+        }
+    }
+
+    /// Spacers have no enabled state
+    fn enabled(&self, _entity: EntityRef) -> bool {
+        true
+    }
+
+    fn set_enabled(&self, _entity: &mut EntityWorldMut, _enabled: bool) {}
+
+    /// Outlined on the canvas so it can be found and dragged
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, _style: &UiElementStyle, _events: &mut WidgetEvents) -> Response {
+        let size = element_size(entity);
+        let (rect, response) = ui.allocate_exact_size(Vec2::new(size.width.max(4.0), size.height.max(4.0)), egui::Sense::hover());
+        if ui.is_rect_visible(rect) {
+            let stroke = egui::Stroke::new(1.0, ui.visuals().weak_text_color());
+            ui.painter().rect_stroke(rect, 0.0, stroke, egui::StrokeKind::Inside);
+            if rect.height() >= 14.0 && rect.width() >= 40.0 {
+                ui.painter().text(
+                    rect.center(),
+                    egui::Align2::CENTER_CENTER,
+                    "spacer",
+                    egui::FontId::proportional(10.0),
+                    ui.visuals().weak_text_color(),
+                );
+            }
+        }
+        response
+    }
+
+    /// Invisible while running
+    fn render_run(
+        &self,
+        ui: &mut Ui,
+        entity: EntityRef,
+        _style: &UiElementStyle,
+        _state: &mut RunPreviewState,
+        _log_messages: &mut Vec<String>,
+    ) -> Response {
+        let size = element_size(entity);
+        ui.allocate_exact_size(Vec2::new(size.width.max(0.0), size.height.max(0.0)), egui::Sense::hover()).1
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let spacer = entity.get::<UiSpacer>()?;
+        Some(UiElementType::Custom(CustomElement::new(self.kind(), &spacer.name, true, 0.0, spacer)))
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, _style: &ElementStyleCode) {
+        gen.writeln(&format!("ui.allocate_space(egui::vec2({:.1}, {:.1}));", element.size.width, element.size.height));
+    }
+}
+
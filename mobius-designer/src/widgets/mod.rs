@@ -0,0 +1,412 @@
+pub mod builtin;
+pub mod labels;
+pub mod images;
+pub mod tables;
+pub mod pickers;
+pub mod indicators;
+pub mod containers;
+pub mod editors;
+pub mod trees;
+pub mod symbols;
+
+use std::sync::Arc;
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypeRegistry;
+use egui::{Response, Ui, Vec2};
+use crate::codegen::{CodeGenerator, ElementStyleCode};
+use crate::codegen_thread::{UiElementSnapshot, UiElementType};
+use crate::components::*;
+use crate::integration::TabKind;
+use crate::resources::RunPreviewState;
+
+pub use builtin::*;
+pub use labels::*;
+pub use images::*;
+pub use tables::*;
+pub use pickers::*;
+pub use indicators::*;
+pub use containers::*;
+pub use editors::*;
+pub use trees::*;
+pub use symbols::*;
+
+/// A kind of element that can be placed on the design canvas.
+///
+/// Every element type goes through this trait: spawning, the design canvas, run preview,
+/// the Inspector, snapshots and code generation. Register an implementation with
+/// `WidgetRegistry::register` (or `DesignerApp::register_widget`) to add a widget type
+/// without touching the designer.
+pub trait DesignerWidget: Send + Sync + 'static {
+    /// Stable name of the widget type, stored in snapshots and used to look it up
+    fn kind(&self) -> &'static str;
+
+    /// Name shown in the add menus, e.g. `"Text Input"`
+    fn display_name(&self) -> &'static str;
+
+    fn icon(&self) -> &'static str;
+
+    /// Register the widget's reflected components so the Inspector builds editors for them
+    fn register_types(&self, _registry: &mut TypeRegistry) {}
+
+    /// Whether the entity is an element of this widget type
+    fn is_instance(&self, entity: EntityRef) -> bool;
+
+    /// Size of newly spawned elements; a zero size lets the widget size itself
+    fn default_size(&self) -> UiElementSize {
+        UiElementSize::default()
+    }
+
+    /// Insert the widget's own components with their default values.
+    /// `number` counts the elements of this type, starting at 1 for the first one.
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize);
+
+    fn label(&self, entity: EntityRef) -> String;
+
+    fn set_label(&self, entity: &mut EntityWorldMut, label: String);
+
+    fn enabled(&self, entity: EntityRef) -> bool;
+
+    fn set_enabled(&self, entity: &mut EntityWorldMut, enabled: bool);
+
+    /// Containers are drawn behind the other elements in run preview
+    fn is_container(&self) -> bool {
+        false
+    }
+
+    /// Whether the widget draws the element style's frame itself instead of being wrapped in it
+    fn draws_own_frame(&self) -> bool {
+        false
+    }
+
+    /// Slot that elements dropped inside the container join, such as a tab strip's open page.
+    /// `None` for elements that hold no children; group boxes only frame what is drawn over them.
+    fn child_slot(&self, _entity: EntityRef) -> Option<usize> {
+        None
+    }
+
+    /// Whether a child in `slot`, placed `offset` from the container's top-left, is shown.
+    /// `state` is the run preview state while running and `None` on the design canvas.
+    fn shows_child(&self, _entity: EntityRef, _slot: usize, _offset: Vec2, _state: Option<&RunPreviewState>) -> bool {
+        true
+    }
+
+    /// Whether the container keeps the state of the elements in it in its own generated struct,
+    /// leaving their fields out of the `App`
+    fn encapsulates_children(&self) -> bool {
+        false
+    }
+
+    /// Draw the element on the design canvas. Dragging, selection, renaming and the
+    /// context menu are handled around it; changes go through `events`.
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, style: &UiElementStyle, events: &mut WidgetEvents) -> Response;
+
+    /// Draw the element as a working widget in run preview, keeping its state in `state`.
+    /// By default the design rendering is shown and its changes are discarded.
+    fn render_run(
+        &self,
+        ui: &mut Ui,
+        entity: EntityRef,
+        style: &UiElementStyle,
+        _state: &mut RunPreviewState,
+        _log_messages: &mut Vec<String>,
+    ) -> Response {
+        let mut updates = Vec::new();
+        let mut log_messages = Vec::new();
+        self.render_design(ui, entity, style, &mut WidgetEvents::new(&mut updates, &mut log_messages))
+    }
+
+    /// Controls in the run toolbar for a value the generated app sets from its own code,
+    /// such as a progress bar's fraction, so run preview can show it changing
+    fn render_run_controls(&self, _ui: &mut Ui, _entity: EntityRef, _state: &mut RunPreviewState) {}
+
+    /// Extra Inspector UI shown below the editors of the reflected components
+    fn render_inspector(&self, _ui: &mut Ui, _world: &mut World, _entity: Entity) {}
+
+    /// The element's properties for code generation
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType>;
+
+    /// Adjust the snapshots of all elements of this type once the whole design is snapshotted,
+    /// e.g. to give the types and fields they generate names no other element uses
+    fn finish_snapshots(&self, _elements: &mut [&mut UiElementSnapshot]) {}
+
+    /// Code drawing the element inside its positioned `Area`
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, style: &ElementStyleCode);
+
+    /// `(name, type)` of the `App` fields the generated code uses; the types must implement `Default`
+    fn app_fields(&self, _element: &UiElementSnapshot) -> Vec<(String, String)> {
+        Vec::new()
+    }
+
+    /// `(name, expression)` of `App` fields from `app_fields` that start with a value other than their default
+    fn app_field_values(&self, _element: &UiElementSnapshot) -> Vec<(String, String)> {
+        Vec::new()
+    }
+
+    /// Expression over the generated `App` (`self`) that is true when the element's value
+    /// breaks its validation rule. Elements with a check are reported by the generated `App::validate`.
+    fn validation_check(&self, _element: &UiElementSnapshot) -> Option<String> {
+        None
+    }
+
+    /// `(crate, purpose)` of the crates the element's generated code needs besides eframe
+    fn required_crates(&self, _element: &UiElementSnapshot) -> Vec<(&'static str, &'static str)> {
+        Vec::new()
+    }
+
+    /// Free functions the generated code of this widget type calls, written once for all of the tab's `elements` of this type
+    fn generate_support_code(&self, _gen: &mut CodeGenerator, _elements: &[&UiElementSnapshot]) {}
+}
+
+/// Deferred change to the world
+pub type WorldUpdate = Box<dyn FnOnce(&mut World) + Send>;
+
+/// Changes a widget makes while it is drawn, applied to the world after the frame
+pub struct WidgetEvents<'a> {
+    updates: &'a mut Vec<WorldUpdate>,
+    log_messages: &'a mut Vec<String>,
+}
+
+impl<'a> WidgetEvents<'a> {
+    pub fn new(updates: &'a mut Vec<WorldUpdate>, log_messages: &'a mut Vec<String>) -> Self {
+        Self { updates, log_messages }
+    }
+
+    pub fn update(&mut self, update: impl FnOnce(&mut World) + Send + 'static) {
+        self.updates.push(Box::new(update));
+    }
+
+    pub fn log(&mut self, message: impl Into<String>) {
+        self.log_messages.push(message.into());
+    }
+}
+
+/// The widget types known to the designer, in the order they appear in the add menus
+#[derive(Resource, Clone)]
+pub struct WidgetRegistry {
+    widgets: Vec<Arc<dyn DesignerWidget>>,
+}
+
+impl Default for WidgetRegistry {
+    /// The built-in widgets
+    fn default() -> Self {
+        let mut registry = Self { widgets: Vec::new() };
+        registry
+            .register(ButtonWidget)
+            .register(TextInputWidget)
+            .register(CheckboxWidget)
+            .register(RadioButtonWidget)
+            .register(GroupBoxWidget)
+            .register(LabelWidget)
+            .register(HyperlinkWidget)
+            .register(SeparatorWidget)
+            .register(SpacerWidget)
+            .register(ImageWidget)
+            .register(TableWidget)
+            .register(DatePickerWidget)
+            .register(TimeInputWidget)
+            .register(ColorPickerWidget)
+            .register(ProgressBarWidget)
+            .register(SpinnerWidget)
+            .register(StatusLedWidget)
+            .register(CollapsingHeaderWidget)
+            .register(ScrollAreaWidget)
+            .register(TabStripWidget)
+            .register(CodeEditorWidget)
+            .register(TreeViewWidget)
+            .register(SymbolInstanceWidget);
+        registry
+    }
+}
+
+impl std::fmt::Debug for WidgetRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.widgets.iter().map(|widget| widget.kind())).finish()
+    }
+}
+
+impl WidgetRegistry {
+    /// Add a widget type; a widget of the same kind is replaced
+    pub fn register(&mut self, widget: impl DesignerWidget) -> &mut Self {
+        let widget: Arc<dyn DesignerWidget> = Arc::new(widget);
+        match self.widgets.iter().position(|existing| existing.kind() == widget.kind()) {
+            Some(index) => self.widgets[index] = widget,
+            None => self.widgets.push(widget),
+        }
+        self
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Arc<dyn DesignerWidget>> {
+        self.widgets.iter()
+    }
+
+    pub fn get(&self, kind: &str) -> Option<&Arc<dyn DesignerWidget>> {
+        self.widgets.iter().find(|widget| widget.kind() == kind)
+    }
+
+    /// Widget type of an element, `None` for entities that are not design elements
+    pub fn widget_of(&self, entity: EntityRef) -> Option<&Arc<dyn DesignerWidget>> {
+        self.widgets.iter().find(|widget| widget.is_instance(entity))
+    }
+
+    /// Register the reflected components of every widget type
+    pub fn register_types(&self, registry: &mut TypeRegistry) {
+        for widget in &self.widgets {
+            widget.register_types(registry);
+        }
+    }
+}
+
+/// Response for an element whose component is missing
+pub fn empty_response(ui: &mut Ui) -> Response {
+    ui.allocate_response(Vec2::ZERO, egui::Sense::hover())
+}
+
+pub fn element_size(entity: EntityRef) -> UiElementSize {
+    entity.get::<UiElementSize>().cloned().unwrap_or_default()
+}
+
+/// Component of an element snapshotted with `CustomElement::new`
+pub fn element_component<T: serde::de::DeserializeOwned>(element: &UiElementSnapshot) -> Option<T> {
+    match &element.element_type {
+        UiElementType::Custom(custom) => custom.component(),
+        _ => None,
+    }
+}
+
+/// Copy of the registered widget types
+pub fn current_widgets(world: &World) -> WidgetRegistry {
+    world.get_resource::<WidgetRegistry>().cloned().unwrap_or_default()
+}
+
+/// Add a widget type to the world's registry and its components to the Inspector
+pub fn register_widget(world: &mut World, widget: impl DesignerWidget) {
+    if let Some(type_registry) = world.get_resource::<bevy_ecs::reflect::AppTypeRegistry>() {
+        widget.register_types(&mut type_registry.write());
+    }
+    world.get_resource_or_insert_with(WidgetRegistry::default).register(widget);
+}
+
+/// All design elements of one widget type
+pub fn widget_elements(world: &World, widget: &dyn DesignerWidget) -> Vec<Entity> {
+    world.iter_entities()
+        .filter(|entity| widget.is_instance(*entity))
+        .map(|entity| entity.id())
+        .collect()
+}
+
+/// Spawn an element of the given widget type with its default properties
+pub fn spawn_widget(world: &mut World, widget: &dyn DesignerWidget, x: f32, y: f32, tab_kind: TabKind, page_id: usize) -> Entity {
+    let number = widget_elements(world, widget).len() + 1;
+    let mut entity = world.spawn((
+        UiElementPosition { x, y },
+        widget.default_size(),
+        UiElementTab { tab_kind, position: 0 },
+        UiElementSelected::default(),
+        UiElementContainer { parent_group: None, slot: 0 },
+        UiElementPage { page_id },
+        UiElementStyle::default(),
+    ));
+    widget.insert_defaults(&mut entity, number);
+    let entity = entity.id();
+    assign_container(world, entity);
+    entity
+}
+
+/// Container holding an element and the slot it is in, if it was dropped into one that still exists
+pub fn element_parent(world: &World, entity: Entity) -> Option<(Entity, usize)> {
+    let container = world.get::<UiElementContainer>(entity)?;
+    let parent = container.parent_group?;
+    world.get_entity(parent).ok()?;
+    Some((parent, container.slot))
+}
+
+/// The elements inside a container, its children's children included
+pub fn container_descendants(world: &World, container: Entity) -> Vec<Entity> {
+    let mut descendants = Vec::new();
+    let mut pending = vec![container];
+    while let Some(parent) = pending.pop() {
+        for entity in world.iter_entities() {
+            let child = entity.id();
+            let in_parent = entity.get::<UiElementContainer>().is_some_and(|c| c.parent_group == Some(parent));
+            if in_parent && child != container && !descendants.contains(&child) {
+                descendants.push(child);
+                pending.push(child);
+            }
+        }
+    }
+    descendants
+}
+
+/// Whether none of the element's containers hide it, e.g. by being collapsed or showing another tab
+pub fn is_element_shown(world: &World, widgets: &WidgetRegistry, entity: Entity, state: Option<&RunPreviewState>) -> bool {
+    let mut child = entity;
+    let mut depth = 0;
+    while let Some((parent, slot)) = element_parent(world, child) {
+        let (Some(child_pos), Some(parent_pos)) = (world.get::<UiElementPosition>(child), world.get::<UiElementPosition>(parent)) else {
+            return true;
+        };
+        let offset = Vec2::new(child_pos.x - parent_pos.x, child_pos.y - parent_pos.y);
+        let container = world.entity(parent);
+        if let Some(widget) = widgets.widget_of(container) {
+            if !widget.shows_child(container, slot, offset, state) {
+                return false;
+            }
+        }
+        child = parent;
+        depth += 1;
+        if depth > 64 {
+            break;
+        }
+    }
+    true
+}
+
+/// Put an element into the innermost shown container under its top-left corner, or take it out
+/// of its container when it was moved off it. Returns whether its container changed.
+pub fn assign_container(world: &mut World, entity: Entity) -> bool {
+    let widgets = current_widgets(world);
+    let Some(pos) = world.get::<UiElementPosition>(entity).cloned() else {
+        return false;
+    };
+    let page = world.get::<UiElementPage>(entity).map(|page| page.page_id);
+    let tab = world.get::<UiElementTab>(entity).map(|tab| tab.tab_kind.clone());
+    let excluded = container_descendants(world, entity);
+
+    let mut innermost: Option<(Entity, usize, f32)> = None;
+    for candidate in world.iter_entities() {
+        let id = candidate.id();
+        if id == entity || excluded.contains(&id) {
+            continue;
+        }
+        let Some(slot) = widgets.widget_of(candidate).and_then(|widget| widget.child_slot(candidate)) else {
+            continue;
+        };
+        if candidate.get::<UiElementPage>().map(|page| page.page_id) != page
+            || candidate.get::<UiElementTab>().map(|tab| tab.tab_kind.clone()) != tab
+        {
+            continue;
+        }
+        let (Some(origin), Some(size)) = (candidate.get::<UiElementPosition>(), candidate.get::<UiElementSize>()) else {
+            continue;
+        };
+        let rect = egui::Rect::from_min_size(egui::pos2(origin.x, origin.y), Vec2::new(size.width, size.height));
+        let area = rect.width() * rect.height();
+        if rect.contains(egui::pos2(pos.x, pos.y))
+            && innermost.is_none_or(|(.., smallest)| area < smallest)
+            && is_element_shown(world, &widgets, id, None)
+        {
+            innermost = Some((id, slot, area));
+        }
+    }
+
+    let parent = innermost.map(|(container, slot, _)| (container, slot));
+    if element_parent(world, entity) == parent {
+        return false;
+    }
+    let (parent_group, slot) = match parent {
+        Some((container, slot)) => (Some(container), slot),
+        None => (None, 0),
+    };
+    world.entity_mut(entity).insert(UiElementContainer { parent_group, slot });
+    true
+}
@@ -0,0 +1,541 @@
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypeRegistry;
+use chrono::{Datelike, NaiveTime, Timelike};
+use egui::widgets::color_picker::{color_edit_button_srgba, Alpha};
+use egui::{Color32, Response, Ui};
+use crate::codegen::{color_expr, snake_ident, CodeGenerator, ElementStyleCode};
+use crate::codegen_thread::{CustomElement, UiElementSnapshot, UiElementType};
+use crate::components::*;
+use crate::resources::RunPreviewState;
+use super::{element_component, empty_response, DesignerWidget, WidgetEvents};
+
+/// Format `egui_extras::DatePickerButton` uses when none is set
+const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+
+pub struct DatePickerWidget;
+
+impl DesignerWidget for DatePickerWidget {
+    fn kind(&self) -> &'static str {
+        "date_picker"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Date Picker"
+    }
+
+    fn icon(&self) -> &'static str {
+        "📅"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiDatePicker>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiDatePicker>()
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiDatePicker::new(&format!("Date {}", number)));
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiDatePicker>().map(|picker| picker.label.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, label: String) {
+        if let Some(mut picker) = entity.get_mut::<UiDatePicker>() {
+            picker.label = label;
+        }
+    }
+
+    fn enabled(&self, entity: EntityRef) -> bool {
+        entity.get::<UiDatePicker>().is_some_and(|picker| picker.enabled)
+    }
+
+    fn set_enabled(&self, entity: &mut EntityWorldMut, enabled: bool) {
+        if let Some(mut picker) = entity.get_mut::<UiDatePicker>() {
+            picker.enabled = enabled;
+        }
+    }
+
+    /// Picking a date on the canvas changes the default date
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, style: &UiElementStyle, events: &mut WidgetEvents) -> Response {
+        let Some(picker) = entity.get::<UiDatePicker>() else {
+            return empty_response(ui);
+        };
+        let mut date = picker.date;
+        let response = ui.add_enabled_ui(picker.enabled, |ui| {
+            labeled(ui, style, &picker.label, picker.font_size, |ui| ui.add(date_button(&mut date, picker)))
+        }).inner;
+
+        if date != picker.date {
+            let entity_id = entity.id();
+            events.log(format!("Date picker '{}' set to {}", picker.label, date));
+            events.update(move |world: &mut World| {
+                if let Some(mut picker) = world.get_mut::<UiDatePicker>(entity_id) {
+                    picker.date = date;
+                }
+            });
+        }
+        response
+    }
+
+    fn render_run(
+        &self,
+        ui: &mut Ui,
+        entity: EntityRef,
+        style: &UiElementStyle,
+        state: &mut RunPreviewState,
+        _log_messages: &mut Vec<String>,
+    ) -> Response {
+        let Some(picker) = entity.get::<UiDatePicker>() else {
+            return empty_response(ui);
+        };
+        let date = state.dates.entry(entity.id()).or_insert(picker.date);
+        ui.add_enabled_ui(picker.enabled, |ui| {
+            labeled(ui, style, &picker.label, picker.font_size, |ui| ui.add(date_button(date, picker)))
+        }).inner
+    }
+
+    fn render_inspector(&self, ui: &mut Ui, world: &mut World, entity: Entity) {
+        let Some(picker) = world.get::<UiDatePicker>(entity).cloned() else {
+            return;
+        };
+        let mut date = picker.date;
+        ui.horizontal(|ui| {
+            ui.label("Default:");
+            ui.add(date_button(&mut date, &picker).id_salt("inspector_default_date"));
+        });
+        if date != picker.date {
+            if let Some(mut picker) = world.get_mut::<UiDatePicker>(entity) {
+                picker.date = date;
+            }
+        }
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let picker = entity.get::<UiDatePicker>()?;
+        Some(UiElementType::Custom(CustomElement::new(self.kind(), &picker.label, picker.enabled, picker.font_size, picker)))
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, style: &ElementStyleCode) {
+        let Some(picker) = element_component::<UiDatePicker>(element) else {
+            return;
+        };
+        let mut button = format!("egui_extras::DatePickerButton::new(&mut app.{})", snake_ident(&picker.label));
+        if !picker.format.is_empty() && picker.format != DEFAULT_DATE_FORMAT {
+            button.push_str(&format!(".format(\"{}\")", picker.format.escape_default()));
+        }
+        if !picker.calendar_week {
+            button.push_str(".calendar_week(false)");
+        }
+        write_labeled(gen, style, &picker.label, picker.font_size, &format!("ui.add({}){};", button, style.tooltip()));
+    }
+
+    fn app_fields(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        vec![(snake_ident(element.element_type.label()), "chrono::NaiveDate".to_string())]
+    }
+
+    fn app_field_values(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        let Some(picker) = element_component::<UiDatePicker>(element) else {
+            return Vec::new();
+        };
+        vec![(
+            snake_ident(&picker.label),
+            format!(
+                "chrono::NaiveDate::from_ymd_opt({}, {}, {}).unwrap_or_default()",
+                picker.date.year(),
+                picker.date.month(),
+                picker.date.day()
+            ),
+        )]
+    }
+
+    fn required_crates(&self, _element: &UiElementSnapshot) -> Vec<(&'static str, &'static str)> {
+        vec![("chrono", "dates and times"), ("egui_extras", "date pickers, with its `datepicker` feature")]
+    }
+}
+
+fn date_button<'a>(date: &'a mut chrono::NaiveDate, picker: &UiDatePicker) -> egui_extras::DatePickerButton<'a> {
+    let mut button = egui_extras::DatePickerButton::new(date).calendar_week(picker.calendar_week);
+    if !picker.format.is_empty() {
+        button = button.format(picker.format.clone());
+    }
+    button
+}
+
+pub struct TimeInputWidget;
+
+impl DesignerWidget for TimeInputWidget {
+    fn kind(&self) -> &'static str {
+        "time_input"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Time Input"
+    }
+
+    fn icon(&self) -> &'static str {
+        "🕓"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiTimeInput>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiTimeInput>()
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiTimeInput::new(&format!("Time {}", number)));
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiTimeInput>().map(|input| input.label.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, label: String) {
+        if let Some(mut input) = entity.get_mut::<UiTimeInput>() {
+            input.label = label;
+        }
+    }
+
+    fn enabled(&self, entity: EntityRef) -> bool {
+        entity.get::<UiTimeInput>().is_some_and(|input| input.enabled)
+    }
+
+    fn set_enabled(&self, entity: &mut EntityWorldMut, enabled: bool) {
+        if let Some(mut input) = entity.get_mut::<UiTimeInput>() {
+            input.enabled = enabled;
+        }
+    }
+
+    /// Editing the time on the canvas changes the default time
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, style: &UiElementStyle, events: &mut WidgetEvents) -> Response {
+        let Some(input) = entity.get::<UiTimeInput>() else {
+            return empty_response(ui);
+        };
+        let mut time = input.time;
+        let response = ui.add_enabled_ui(input.enabled, |ui| {
+            labeled(ui, style, &input.label, input.font_size, |ui| time_edit(ui, &mut time, input.show_seconds))
+        }).inner;
+
+        if time != input.time {
+            let entity_id = entity.id();
+            events.update(move |world: &mut World| {
+                if let Some(mut input) = world.get_mut::<UiTimeInput>(entity_id) {
+                    input.time = time;
+                }
+            });
+        }
+        response
+    }
+
+    fn render_run(
+        &self,
+        ui: &mut Ui,
+        entity: EntityRef,
+        style: &UiElementStyle,
+        state: &mut RunPreviewState,
+        _log_messages: &mut Vec<String>,
+    ) -> Response {
+        let Some(input) = entity.get::<UiTimeInput>() else {
+            return empty_response(ui);
+        };
+        let time = state.times.entry(entity.id()).or_insert(input.time);
+        ui.add_enabled_ui(input.enabled, |ui| {
+            labeled(ui, style, &input.label, input.font_size, |ui| time_edit(ui, time, input.show_seconds))
+        }).inner
+    }
+
+    fn render_inspector(&self, ui: &mut Ui, world: &mut World, entity: Entity) {
+        let Some(input) = world.get::<UiTimeInput>(entity).cloned() else {
+            return;
+        };
+        let mut time = input.time;
+        ui.horizontal(|ui| {
+            ui.label("Default:");
+            time_edit(ui, &mut time, input.show_seconds);
+        });
+        if time != input.time {
+            if let Some(mut input) = world.get_mut::<UiTimeInput>(entity) {
+                input.time = time;
+            }
+        }
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let input = entity.get::<UiTimeInput>()?;
+        Some(UiElementType::Custom(CustomElement::new(self.kind(), &input.label, input.enabled, input.font_size, input)))
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, style: &ElementStyleCode) {
+        let Some(input) = element_component::<UiTimeInput>(element) else {
+            return;
+        };
+        let line = format!(
+            "time_edit(ui, &mut app.{}, {}){};",
+            snake_ident(&input.label),
+            input.show_seconds,
+            style.tooltip()
+        );
+        write_labeled(gen, style, &input.label, input.font_size, &line);
+    }
+
+    fn app_fields(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        vec![(snake_ident(element.element_type.label()), "chrono::NaiveTime".to_string())]
+    }
+
+    fn app_field_values(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        let Some(input) = element_component::<UiTimeInput>(element) else {
+            return Vec::new();
+        };
+        if input.time == NaiveTime::default() {
+            return Vec::new();
+        }
+        vec![(
+            snake_ident(&input.label),
+            format!(
+                "chrono::NaiveTime::from_hms_opt({}, {}, {}).unwrap_or_default()",
+                input.time.hour(),
+                input.time.minute(),
+                input.time.second()
+            ),
+        )]
+    }
+
+    fn required_crates(&self, _element: &UiElementSnapshot) -> Vec<(&'static str, &'static str)> {
+        vec![("chrono", "dates and times")]
+    }
+
+    fn generate_support_code(&self, gen: &mut CodeGenerator, _elements: &[&UiElementSnapshot]) {
+        gen.writeln("/// Hours and minutes, and optionally seconds, editing a time of day");
+        gen.writeln("pub fn time_edit(ui: &mut egui::Ui, time: &mut chrono::NaiveTime, show_seconds: bool) -> egui::Response {");
+        gen.push_indent();
+        gen.writeln("use chrono::Timelike;");
+        gen.writeln("fn two_digits(value: &mut u32, max: u32) -> egui::DragValue<'_> {");
+        gen.push_indent();
+        gen.writeln("egui::DragValue::new(value).range(0..=max).custom_formatter(|n, _| format!(\"{:02}\", n as u32))");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("ui.horizontal(|ui| {");
+        gen.push_indent();
+        gen.writeln("ui.spacing_mut().item_spacing.x = 2.0;");
+        gen.writeln("let (mut hour, mut minute, mut second) = (time.hour(), time.minute(), time.second());");
+        gen.writeln("let mut response = ui.add(two_digits(&mut hour, 23));");
+        gen.writeln("ui.label(\":\");");
+        gen.writeln("response = response.union(ui.add(two_digits(&mut minute, 59)));");
+        gen.writeln("if show_seconds {");
+        gen.push_indent();
+        gen.writeln("ui.label(\":\");");
+        gen.writeln("response = response.union(ui.add(two_digits(&mut second, 59)));");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("if let Some(edited) = chrono::NaiveTime::from_hms_opt(hour, minute, second) {");
+        gen.push_indent();
+        gen.writeln("*time = edited;");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("response");
+        gen.pop_indent();
+        gen.writeln("})");
+        gen.writeln(".inner");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("");
+    }
+}
+
+/// Hours and minutes, and optionally seconds, editing a time of day; the same as the generated `time_edit`
+pub fn time_edit(ui: &mut Ui, time: &mut NaiveTime, show_seconds: bool) -> Response {
+    fn two_digits(value: &mut u32, max: u32) -> egui::DragValue<'_> {
+        egui::DragValue::new(value).range(0..=max).custom_formatter(|n, _| format!("{:02}", n as u32))
+    }
+    ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing.x = 2.0;
+        let (mut hour, mut minute, mut second) = (time.hour(), time.minute(), time.second());
+        let mut response = ui.add(two_digits(&mut hour, 23));
+        ui.label(":");
+        response = response.union(ui.add(two_digits(&mut minute, 59)));
+        if show_seconds {
+            ui.label(":");
+            response = response.union(ui.add(two_digits(&mut second, 59)));
+        }
+        if let Some(edited) = NaiveTime::from_hms_opt(hour, minute, second) {
+            *time = edited;
+        }
+        response
+    })
+    .inner
+}
+
+pub struct ColorPickerWidget;
+
+impl DesignerWidget for ColorPickerWidget {
+    fn kind(&self) -> &'static str {
+        "color_picker"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Color Picker"
+    }
+
+    fn icon(&self) -> &'static str {
+        "🎨"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiColorPicker>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiColorPicker>()
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiColorPicker::new(&format!("Color {}", number)));
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiColorPicker>().map(|picker| picker.label.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, label: String) {
+        if let Some(mut picker) = entity.get_mut::<UiColorPicker>() {
+            picker.label = label;
+        }
+    }
+
+    fn enabled(&self, entity: EntityRef) -> bool {
+        entity.get::<UiColorPicker>().is_some_and(|picker| picker.enabled)
+    }
+
+    fn set_enabled(&self, entity: &mut EntityWorldMut, enabled: bool) {
+        if let Some(mut picker) = entity.get_mut::<UiColorPicker>() {
+            picker.enabled = enabled;
+        }
+    }
+
+    /// Picking a color on the canvas changes the default color
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, style: &UiElementStyle, events: &mut WidgetEvents) -> Response {
+        let Some(picker) = entity.get::<UiColorPicker>() else {
+            return empty_response(ui);
+        };
+        let mut color = picker.color;
+        let response = ui.add_enabled_ui(picker.enabled, |ui| {
+            labeled(ui, style, &picker.label, picker.font_size, |ui| color_button(ui, &mut color, picker.alpha))
+        }).inner;
+
+        if color != picker.color {
+            let entity_id = entity.id();
+            events.update(move |world: &mut World| {
+                if let Some(mut picker) = world.get_mut::<UiColorPicker>(entity_id) {
+                    picker.color = color;
+                }
+            });
+        }
+        response
+    }
+
+    fn render_run(
+        &self,
+        ui: &mut Ui,
+        entity: EntityRef,
+        style: &UiElementStyle,
+        state: &mut RunPreviewState,
+        _log_messages: &mut Vec<String>,
+    ) -> Response {
+        let Some(picker) = entity.get::<UiColorPicker>() else {
+            return empty_response(ui);
+        };
+        let color = state.colors.entry(entity.id()).or_insert(picker.color);
+        ui.add_enabled_ui(picker.enabled, |ui| {
+            labeled(ui, style, &picker.label, picker.font_size, |ui| color_button(ui, color, picker.alpha))
+        }).inner
+    }
+
+    fn render_inspector(&self, ui: &mut Ui, world: &mut World, entity: Entity) {
+        let Some(picker) = world.get::<UiColorPicker>(entity).cloned() else {
+            return;
+        };
+        let mut color = picker.color;
+        ui.horizontal(|ui| {
+            ui.label("Default:");
+            color_button(ui, &mut color, picker.alpha);
+        });
+        if color != picker.color {
+            if let Some(mut picker) = world.get_mut::<UiColorPicker>(entity) {
+                picker.color = color;
+            }
+        }
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let picker = entity.get::<UiColorPicker>()?;
+        Some(UiElementType::Custom(CustomElement::new(self.kind(), &picker.label, picker.enabled, picker.font_size, picker)))
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, style: &ElementStyleCode) {
+        let Some(picker) = element_component::<UiColorPicker>(element) else {
+            return;
+        };
+        let field = snake_ident(&picker.label);
+        let line = if picker.alpha {
+            format!("ui.color_edit_button_srgba(&mut app.{}){};", field, style.tooltip())
+        } else {
+            format!(
+                "egui::widgets::color_picker::color_edit_button_srgba(ui, &mut app.{}, egui::widgets::color_picker::Alpha::Opaque){};",
+                field,
+                style.tooltip()
+            )
+        };
+        write_labeled(gen, style, &picker.label, picker.font_size, &line);
+    }
+
+    fn app_fields(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        vec![(snake_ident(element.element_type.label()), "egui::Color32".to_string())]
+    }
+
+    fn app_field_values(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        match element_component::<UiColorPicker>(element) {
+            Some(picker) if picker.color != Color32::default() => vec![(snake_ident(&picker.label), color_expr(picker.color))],
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// `color_edit_button_srgba`, opaque unless the picker allows alpha
+fn color_button(ui: &mut Ui, color: &mut Color32, alpha: bool) -> Response {
+    if !alpha {
+        // Drop any translucency left over from when alpha was allowed
+        *color = color.to_opaque();
+    }
+    color_edit_button_srgba(ui, color, if alpha { Alpha::BlendOrAdditive } else { Alpha::Opaque })
+}
+
+/// Widget with its label in front of it, as the generated code lays it out
+fn labeled(ui: &mut Ui, style: &UiElementStyle, label: &str, font_size: f32, add_widget: impl FnOnce(&mut Ui) -> Response) -> Response {
+    if label.is_empty() {
+        return add_widget(ui);
+    }
+    ui.horizontal(|ui| {
+        ui.label(style.rich_text(label, font_size));
+        add_widget(ui)
+    })
+    .inner
+}
+
+fn write_labeled(gen: &mut CodeGenerator, style: &ElementStyleCode, label: &str, font_size: f32, line: &str) {
+    if label.is_empty() {
+        gen.writeln(line);
+        return;
+    }
+    gen.writeln("ui.horizontal(|ui| {");
+    gen.push_indent();
+    gen.writeln(&format!("ui.label({});", style.text(label, font_size)));
+    gen.writeln(line);
+    gen.pop_indent();
+    gen.writeln("});");
+}
@@ -0,0 +1,327 @@
+use std::collections::HashMap;
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypeRegistry;
+use egui::{Color32, Response, RichText, Sense, Ui, Vec2};
+use serde::{Deserialize, Serialize};
+use crate::codegen::{CodeGenerator, ElementStyleCode};
+use crate::codegen_thread::{CustomElement, UiElementSnapshot, UiElementType};
+use crate::components::*;
+use crate::integration::TabKind;
+use crate::resources::RunPreviewState;
+use super::{container_descendants, current_widgets, element_component, element_size, empty_response, DesignerWidget, WidgetEvents};
+
+/// Outline color of a component's master on the canvas
+const MASTER_COLOR: Color32 = Color32::from_rgb(170, 110, 230);
+
+/// Master of the named component
+pub fn symbol_master(world: &mut World, symbol: &str) -> Option<Entity> {
+    let mut query = world.query::<(Entity, &UiSymbolInstance)>();
+    query.iter(world).find(|(_, instance)| instance.master && instance.symbol == symbol).map(|(entity, _)| entity)
+}
+
+/// Snapshot of an instance, which only gets its state when its component is generated on the same tab
+#[derive(Serialize, Deserialize)]
+struct InstanceSnapshot {
+    #[serde(flatten)]
+    instance: UiSymbolInstance,
+    /// Whether the component's master is on the instance's tab
+    #[serde(default)]
+    master_on_tab: bool,
+}
+
+pub struct SymbolInstanceWidget;
+
+impl SymbolInstanceWidget {
+    /// Labeled elements of a master in the order of the generated function's `labels`
+    fn labeled_parts(gen: &CodeGenerator, master: Entity) -> Vec<(Entity, String)> {
+        gen.descendants(master)
+            .into_iter()
+            .map(|part| (part.entity_id, part.element_type.label().to_string()))
+            .filter(|(_, label)| !label.is_empty())
+            .collect()
+    }
+}
+
+impl DesignerWidget for SymbolInstanceWidget {
+    fn kind(&self) -> &'static str {
+        "symbol_instance"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Component"
+    }
+
+    fn icon(&self) -> &'static str {
+        "◈"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiSymbolInstance>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiSymbolInstance>()
+    }
+
+    fn default_size(&self) -> UiElementSize {
+        UiElementSize { width: 240.0, height: 120.0 }
+    }
+
+    /// A new, empty component; elements dropped into its master make it up
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        let symbol = format!("Component {}", number);
+        entity.insert(UiSymbolInstance { master: true, ..UiSymbolInstance::new(&symbol, &symbol) });
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiSymbolInstance>().map(|instance| instance.name.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, name: String) {
+        if let Some(mut instance) = entity.get_mut::<UiSymbolInstance>() {
+            instance.name = name;
+        }
+    }
+
+    /// Components have no enabled state
+    fn enabled(&self, _entity: EntityRef) -> bool {
+        true
+    }
+
+    fn set_enabled(&self, _entity: &mut EntityWorldMut, _enabled: bool) {}
+
+    fn is_container(&self) -> bool {
+        true
+    }
+
+    /// Only the master takes elements; the other instances show copies of its elements
+    fn child_slot(&self, entity: EntityRef) -> Option<usize> {
+        entity.get::<UiSymbolInstance>().filter(|instance| instance.master).map(|_| 0)
+    }
+
+    fn encapsulates_children(&self) -> bool {
+        true
+    }
+
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, _style: &UiElementStyle, _events: &mut WidgetEvents) -> Response {
+        let Some(instance) = entity.get::<UiSymbolInstance>() else {
+            return empty_response(ui);
+        };
+        let size = element_size(entity);
+        let (rect, response) = ui.allocate_exact_size(Vec2::new(size.width.max(40.0), size.height.max(24.0)), Sense::hover());
+        let (color, tag) = if instance.master {
+            (MASTER_COLOR, format!("◈ {} (master)", instance.symbol))
+        } else {
+            (ui.visuals().weak_text_color(), format!("◈ {}", instance.symbol))
+        };
+        ui.painter().rect_stroke(rect, 4.0, egui::Stroke::new(1.5, color), egui::StrokeKind::Inside);
+        ui.painter().text(rect.right_top() + egui::vec2(-6.0, 4.0), egui::Align2::RIGHT_TOP, tag, egui::FontId::proportional(11.0), color);
+        response
+    }
+
+    /// Only takes up the component's space; its elements are drawn by themselves
+    fn render_run(
+        &self,
+        ui: &mut Ui,
+        entity: EntityRef,
+        _style: &UiElementStyle,
+        _state: &mut RunPreviewState,
+        _log_messages: &mut Vec<String>,
+    ) -> Response {
+        let size = element_size(entity);
+        ui.allocate_exact_size(Vec2::new(size.width.max(1.0), size.height.max(1.0)), Sense::hover()).1
+    }
+
+    fn render_inspector(&self, ui: &mut Ui, world: &mut World, entity: Entity) {
+        let Some(instance) = world.get::<UiSymbolInstance>(entity).cloned() else {
+            return;
+        };
+        if instance.master {
+            ui.label(RichText::new(format!("Master of '{}'", instance.symbol)).strong());
+            ui.weak("Elements dropped into the master make up the component; every instance follows its changes");
+            if ui.button("➕ Place Instance").clicked() {
+                crate::systems::place_symbol_instance(world, &instance.symbol);
+            }
+            return;
+        }
+
+        ui.label(RichText::new(format!("Instance of '{}'", instance.symbol)).strong());
+        let Some(master) = symbol_master(world, &instance.symbol) else {
+            ui.colored_label(Color32::YELLOW, "⚠ The component's master was deleted");
+            return;
+        };
+        ui.label("Labels");
+        let widgets = current_widgets(world);
+        let parts: Vec<(Entity, String)> = container_descendants(world, master)
+            .into_iter()
+            .filter_map(|part| Some((part, widgets.widget_of(world.entity(part))?.label(world.entity(part)))))
+            .filter(|(_, label)| !label.is_empty())
+            .collect();
+        let mut overrides = instance.overrides.clone();
+        let mut changed = false;
+        for (part, master_label) in parts {
+            ui.push_id(part, |ui| {
+                ui.horizontal(|ui| {
+                    let mut label = instance.label_override(part).unwrap_or(&master_label).to_string();
+                    let overridden = instance.label_override(part).is_some();
+                    let edit = egui::TextEdit::singleline(&mut label).hint_text(&master_label).desired_width(140.0);
+                    if ui.add(edit).on_hover_text(format!("Master: {}", master_label)).changed() {
+                        overrides.retain(|o| o.part != part);
+                        if label != master_label {
+                            overrides.push(SymbolLabelOverride { part, label });
+                        }
+                        changed = true;
+                    }
+                    if ui.add_enabled(overridden, egui::Button::new("↺").small()).on_hover_text("Use the master's label").clicked() {
+                        overrides.retain(|o| o.part != part);
+                        changed = true;
+                    }
+                });
+            });
+        }
+        if changed {
+            if let Some(mut instance) = world.get_mut::<UiSymbolInstance>(entity) {
+                instance.overrides = overrides;
+            }
+        }
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let instance = entity.get::<UiSymbolInstance>()?;
+        Some(UiElementType::Custom(CustomElement::new(self.kind(), &instance.name, true, 0.0, instance)))
+    }
+
+    /// Note which instances have their master on the same tab; the others can't use the component's state type
+    fn finish_snapshots(&self, elements: &mut [&mut UiElementSnapshot]) {
+        let masters: Vec<(String, TabKind)> = elements.iter()
+            .filter_map(|element| {
+                let instance = element_component::<UiSymbolInstance>(element).filter(|instance| instance.master)?;
+                Some((instance.symbol, element.tab.tab_kind.clone()))
+            })
+            .collect();
+        for element in elements.iter_mut() {
+            let Some(instance) = element_component::<UiSymbolInstance>(element) else {
+                continue;
+            };
+            let master_on_tab = instance.master
+                || masters.iter().any(|(symbol, tab)| *symbol == instance.symbol && *tab == element.tab.tab_kind);
+            let snapshot = InstanceSnapshot { instance, master_on_tab };
+            element.element_type = UiElementType::Custom(CustomElement::new(self.kind(), &snapshot.instance.name, true, 0.0, &snapshot));
+        }
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, _style: &ElementStyleCode) {
+        let Some(instance) = element_component::<UiSymbolInstance>(element) else {
+            return;
+        };
+        let master = gen.elements().iter()
+            .find(|other| element_component::<UiSymbolInstance>(other).is_some_and(|m| m.master && m.symbol == instance.symbol))
+            .map(|master| master.entity_id);
+        let Some(master) = master else {
+            gen.writeln(&format!("// Component '{}' has no master to generate it from", instance.symbol.escape_default()));
+            gen.writeln(&format!("ui.allocate_space(egui::vec2({:.1}, {:.1}));", element.size.width, element.size.height));
+            return;
+        };
+        let labels: Vec<String> = Self::labeled_parts(gen, master)
+            .into_iter()
+            .map(|(part, label)| format!("\"{}\"", instance.label_override(part).unwrap_or(&label).escape_default()))
+            .collect();
+        gen.writeln(&format!("{}(ui, &mut app.{}, &[{}]);", instance.function(), instance.state_field(), labels.join(", ")));
+    }
+
+    fn app_fields(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        match element_component::<InstanceSnapshot>(element) {
+            Some(InstanceSnapshot { instance, master_on_tab: true }) => vec![(instance.state_field(), instance.state_type())],
+            _ => Vec::new(),
+        }
+    }
+
+    fn generate_support_code(&self, gen: &mut CodeGenerator, elements: &[&UiElementSnapshot]) {
+        let mut generated = Vec::new();
+        for element in elements {
+            let Some(master) = element_component::<UiSymbolInstance>(element).filter(|instance| instance.master) else {
+                continue;
+            };
+            if generated.contains(&master.symbol) {
+                continue;
+            }
+            generated.push(master.symbol.clone());
+
+            // The state struct holds the fields the master's elements would add to the `App`
+            let mut fields: Vec<(String, String)> = Vec::new();
+            let mut values: HashMap<String, String> = HashMap::new();
+            for part in gen.descendants(element.entity_id) {
+                let Some(widget) = gen.widget(part.element_type.kind()) else {
+                    continue;
+                };
+                for field in widget.app_fields(&part) {
+                    if !fields.iter().any(|(name, _)| *name == field.0) {
+                        fields.push(field);
+                    }
+                }
+                values.extend(widget.app_field_values(&part));
+            }
+
+            let state_type = master.state_type();
+            gen.writeln(&format!("/// State of one instance of the \"{}\" component", master.symbol.escape_default()));
+            if values.is_empty() {
+                gen.writeln("#[derive(Default)]");
+            }
+            gen.writeln(&format!("pub struct {} {{", state_type));
+            gen.push_indent();
+            for (name, field_type) in &fields {
+                gen.writeln(&format!("{}: {},", name, field_type));
+            }
+            gen.pop_indent();
+            gen.writeln("}");
+            gen.writeln("");
+            if !values.is_empty() {
+                gen.writeln(&format!("impl Default for {} {{", state_type));
+                gen.push_indent();
+                gen.writeln("fn default() -> Self {");
+                gen.push_indent();
+                gen.writeln("Self {");
+                gen.push_indent();
+                for (name, _) in &fields {
+                    let value = values.get(name).map_or("Default::default()", String::as_str);
+                    gen.writeln(&format!("{}: {},", name, value));
+                }
+                gen.pop_indent();
+                gen.writeln("}");
+                gen.pop_indent();
+                gen.writeln("}");
+                gen.pop_indent();
+                gen.writeln("}");
+                gen.writeln("");
+            }
+
+            let parts = Self::labeled_parts(gen, element.entity_id);
+            let labels: HashMap<Entity, String> = parts.iter()
+                .enumerate()
+                .map(|(index, (part, _))| (*part, format!("labels[{}]", index)))
+                .collect();
+            let has_children = gen.has_children(element.entity_id, None);
+            gen.writeln(&format!(
+                "/// Draws the \"{}\" component; `labels` are the texts of its labeled elements, top to bottom",
+                master.symbol.escape_default()
+            ));
+            gen.writeln(&format!(
+                "pub fn {}(ui: &mut egui::Ui, {}: &mut {}, {}: &[&str; {}]) {{",
+                master.function(),
+                if fields.is_empty() { "_app" } else { "app" },
+                state_type,
+                if parts.is_empty() { "_labels" } else { "labels" },
+                parts.len()
+            ));
+            gen.push_indent();
+            gen.writeln(&format!("ui.set_min_size(egui::vec2({:.1}, {:.1}));", element.size.width, element.size.height));
+            if has_children {
+                gen.writeln("let origin = ui.cursor().min;");
+                gen.generate_children_labeled(element, 0, "origin", labels);
+            }
+            gen.pop_indent();
+            gen.writeln("}");
+            gen.writeln("");
+        }
+    }
+}
@@ -0,0 +1,525 @@
+use std::collections::HashMap;
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypeRegistry;
+use egui::{Response, RichText, Ui, Vec2};
+use crate::codegen::{CodeGenerator, ElementStyleCode};
+use crate::codegen_thread::{CustomElement, UiElementSnapshot, UiElementType};
+use crate::components::*;
+use crate::resources::RunPreviewState;
+use super::{element_component, element_size, empty_response, DesignerWidget, WidgetEvents};
+
+pub struct TableWidget;
+
+impl DesignerWidget for TableWidget {
+    fn kind(&self) -> &'static str {
+        "table"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Table"
+    }
+
+    fn icon(&self) -> &'static str {
+        "▦"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiTable>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiTable>()
+    }
+
+    fn default_size(&self) -> UiElementSize {
+        UiElementSize { width: 300.0, height: 160.0 }
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiTable::new(&format!("Table {}", number)));
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiTable>().map(|table| table.name.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, name: String) {
+        if let Some(mut table) = entity.get_mut::<UiTable>() {
+            table.name = name;
+        }
+    }
+
+    /// Tables have no enabled state
+    fn enabled(&self, _entity: EntityRef) -> bool {
+        true
+    }
+
+    fn set_enabled(&self, _entity: &mut EntityWorldMut, _enabled: bool) {}
+
+    /// The sample rows without scrolling or column resizing, which would fight with dragging the element
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, _style: &UiElementStyle, _events: &mut WidgetEvents) -> Response {
+        let Some(table) = entity.get::<UiTable>() else {
+            return empty_response(ui);
+        };
+        show_sample_table(ui, table, element_size(entity), egui::Id::new(("design_table", entity.id())), false)
+    }
+
+    /// The sample rows with scrolling, column resizing and sorting
+    fn render_run(
+        &self,
+        ui: &mut Ui,
+        entity: EntityRef,
+        _style: &UiElementStyle,
+        _state: &mut RunPreviewState,
+        _log_messages: &mut Vec<String>,
+    ) -> Response {
+        let Some(table) = entity.get::<UiTable>() else {
+            return empty_response(ui);
+        };
+        show_sample_table(ui, table, element_size(entity), egui::Id::new(("run_table", entity.id())), true)
+    }
+
+    fn render_inspector(&self, ui: &mut Ui, world: &mut World, entity: Entity) {
+        let Some(mut table) = world.get::<UiTable>(entity).cloned() else {
+            return;
+        };
+        if edit_columns(ui, &mut table) {
+            if let Some(mut current) = world.get_mut::<UiTable>(entity) {
+                current.columns = table.columns.clone();
+            }
+        }
+
+        // Named as in the generated code, where a table named like an earlier one is numbered
+        let mut query = world.query_filtered::<(Entity, &UiTable), Without<UiSymbolCopy>>();
+        let names = generated_names(query.iter(world).map(|(entity, table)| (entity, table.name.clone())));
+        if let Some(name) = names.get(&entity).filter(|name| **name != table.name) {
+            ui.colored_label(egui::Color32::from_rgb(255, 150, 0), format!("⚠ Another table has this name; generated as \"{}\"", name));
+            table.name = name.clone();
+        }
+
+        let fields: Vec<String> = table.column_fields().iter()
+            .zip(&table.columns)
+            .map(|(field, column)| format!("{}: {}", field, column.value_type.rust_type()))
+            .collect();
+        ui.label(
+            RichText::new(format!("struct {} {{ {} }}  →  app.{}", table.row_struct(), fields.join(", "), table.rows_field()))
+                .monospace()
+                .weak(),
+        );
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let table = entity.get::<UiTable>()?;
+        Some(UiElementType::Custom(CustomElement::new(self.kind(), &table.name, true, 0.0, table)))
+    }
+
+    /// Number tables named like an earlier one, so each gets its own row struct and fields
+    fn finish_snapshots(&self, elements: &mut [&mut UiElementSnapshot]) {
+        let names = generated_names(
+            elements.iter().filter_map(|element| Some((element.entity_id, element_component::<UiTable>(element)?.name))),
+        );
+        for element in elements.iter_mut() {
+            let Some(mut table) = element_component::<UiTable>(element) else {
+                continue;
+            };
+            if let Some(name) = names.get(&element.entity_id).filter(|name| **name != table.name) {
+                table.name = name.clone();
+                element.element_type = UiElementType::Custom(CustomElement::new(self.kind(), name, true, 0.0, &table));
+            }
+        }
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, _style: &ElementStyleCode) {
+        let Some(table) = element_component::<UiTable>(element) else {
+            return;
+        };
+        let (width, height) = (element.size.width, element.size.height);
+        if table.columns.is_empty() {
+            gen.writeln(&format!("// Table \"{}\" has no columns", table.name.escape_default()));
+            gen.writeln(&format!("ui.allocate_space(egui::vec2({:.1}, {:.1}));", width, height));
+            return;
+        }
+        let fields = table.column_fields();
+        let rows = format!("app.{}", table.rows_field());
+        let sort = format!("app.{}", table.sort_field());
+
+        gen.writeln(&format!("ui.allocate_ui(egui::vec2({:.1}, {:.1}), |ui| {{", width, height));
+        gen.push_indent();
+        gen.writeln("egui_extras::TableBuilder::new(ui)");
+        gen.push_indent();
+        gen.writeln(&format!(".striped({})", table.striped));
+        gen.writeln(&format!(".max_scroll_height({:.1})", (height - table.row_height).max(0.0)));
+        for column in &table.columns {
+            gen.writeln(&format!(".column({})", column_code(column)));
+        }
+
+        gen.writeln(&format!(".header({:.1}, |mut header| {{", table.row_height));
+        gen.push_indent();
+        for (index, (column, field)) in table.columns.iter().zip(&fields).enumerate() {
+            gen.writeln("header.col(|ui| {");
+            gen.push_indent();
+            let header = column.header.escape_default().to_string();
+            if column.sortable {
+                gen.writeln(&format!(
+                    "let label = match {} {{ Some(({}, true)) => \"{} ⬆\", Some(({}, false)) => \"{} ⬇\", _ => \"{}\" }};",
+                    sort, index, header, index, header, header
+                ));
+                gen.writeln("if ui.button(label).clicked() {");
+                gen.push_indent();
+                gen.writeln(&format!("let ascending = {} != Some(({}, true));", sort, index));
+                gen.writeln(&format!("{} = Some(({}, ascending));", sort, index));
+                let compare = match column.value_type {
+                    ColumnType::Float => "total_cmp",
+                    _ => "cmp",
+                };
+                gen.writeln(&format!("{}.sort_by(|a, b| a.{}.{}(&b.{}));", rows, field, compare, field));
+                gen.writeln("if !ascending {");
+                gen.push_indent();
+                gen.writeln(&format!("{}.reverse();", rows));
+                gen.pop_indent();
+                gen.writeln("}");
+                gen.pop_indent();
+                gen.writeln("}");
+            } else {
+                gen.writeln(&format!("ui.strong(\"{}\");", header));
+            }
+            gen.pop_indent();
+            gen.writeln("});");
+        }
+        gen.pop_indent();
+        gen.writeln("})");
+
+        gen.writeln(".body(|body| {");
+        gen.push_indent();
+        gen.writeln(&format!("body.rows({:.1}, {}.len(), |mut row| {{", table.row_height, rows));
+        gen.push_indent();
+        gen.writeln(&format!("let item = &{}[row.index()];", rows));
+        for (column, field) in table.columns.iter().zip(&fields) {
+            gen.writeln("row.col(|ui| {");
+            gen.push_indent();
+            let label = match column.value_type {
+                ColumnType::Text => format!("ui.label(&item.{});", field),
+                ColumnType::Integer | ColumnType::Float => format!("ui.label(item.{}.to_string());", field),
+                ColumnType::Bool => format!("ui.label(if item.{} {{ \"✔\" }} else {{ \"\" }});", field),
+            };
+            match layout_code(column.align) {
+                Some(layout) => {
+                    gen.writeln(&format!("ui.with_layout({}, |ui| {{", layout));
+                    gen.push_indent();
+                    gen.writeln(&label);
+                    gen.pop_indent();
+                    gen.writeln("});");
+                }
+                None => gen.writeln(&label),
+            }
+            gen.pop_indent();
+            gen.writeln("});");
+        }
+        gen.pop_indent();
+        gen.writeln("});");
+        gen.pop_indent();
+        gen.writeln("});");
+        gen.pop_indent();
+        gen.pop_indent();
+        gen.writeln("});");
+    }
+
+    fn app_fields(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        let Some(table) = element_component::<UiTable>(element).filter(|table| !table.columns.is_empty()) else {
+            return Vec::new();
+        };
+        let mut fields = vec![(table.rows_field(), format!("Vec<{}>", table.row_struct()))];
+        if table.is_sortable() {
+            fields.push((table.sort_field(), "Option<(usize, bool)>".to_string()));
+        }
+        fields
+    }
+
+    fn app_field_values(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        match element_component::<UiTable>(element) {
+            Some(table) if !table.columns.is_empty() && table.sample_rows > 0 => {
+                vec![(table.rows_field(), format!("{}::samples()", table.row_struct()))]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn required_crates(&self, element: &UiElementSnapshot) -> Vec<(&'static str, &'static str)> {
+        match element_component::<UiTable>(element) {
+            Some(table) if !table.columns.is_empty() => vec![("egui_extras", "tables")],
+            _ => Vec::new(),
+        }
+    }
+
+    /// A row struct per table, with the sample rows as its `samples()`
+    fn generate_support_code(&self, gen: &mut CodeGenerator, elements: &[&UiElementSnapshot]) {
+        for table in elements.iter().filter_map(|element| element_component::<UiTable>(element)) {
+            if table.columns.is_empty() {
+                continue;
+            }
+            let row_struct = table.row_struct();
+            let fields = table.column_fields();
+
+            gen.writeln(&format!("/// Row of the \"{}\" table", table.name.escape_default()));
+            gen.writeln("#[derive(Clone, Debug, Default, PartialEq)]");
+            gen.writeln(&format!("pub struct {} {{", row_struct));
+            gen.push_indent();
+            for (column, field) in table.columns.iter().zip(&fields) {
+                gen.writeln(&format!("pub {}: {},", field, column.value_type.rust_type()));
+            }
+            gen.pop_indent();
+            gen.writeln("}");
+            gen.writeln("");
+
+            if table.sample_rows > 0 {
+                gen.writeln(&format!("impl {} {{", row_struct));
+                gen.push_indent();
+                gen.writeln("/// Placeholder rows shown until the app fills in its own");
+                gen.writeln("pub fn samples() -> Vec<Self> {");
+                gen.push_indent();
+                gen.writeln("vec![");
+                gen.push_indent();
+                for row in table.sample_data() {
+                    let values: Vec<String> = fields.iter()
+                        .zip(&row)
+                        .map(|(field, value)| format!("{}: {}", field, value.code()))
+                        .collect();
+                    gen.writeln(&format!("Self {{ {} }},", values.join(", ")));
+                }
+                gen.pop_indent();
+                gen.writeln("]");
+                gen.pop_indent();
+                gen.writeln("}");
+                gen.pop_indent();
+                gen.writeln("}");
+                gen.writeln("");
+            }
+        }
+    }
+}
+
+/// Names the tables are generated with, by entity: a table whose row struct or fields another
+/// table already uses is numbered, e.g. the second "People" table becomes "People 2"
+fn generated_names(tables: impl IntoIterator<Item = (Entity, String)>) -> HashMap<Entity, String> {
+    let mut tables: Vec<(Entity, String)> = tables.into_iter().collect();
+    tables.sort_by_key(|(entity, _)| *entity);
+    let mut taken: Vec<(String, String)> = Vec::new();
+    let mut names = HashMap::new();
+    for (entity, name) in tables {
+        let idents = |name: &str| {
+            let table = UiTable::new(name);
+            (table.row_struct(), table.rows_field())
+        };
+        let mut numbered = name.clone();
+        let mut suffix = 2;
+        while taken.iter().any(|(row_struct, rows_field)| {
+            let (new_struct, new_field) = idents(&numbered);
+            *row_struct == new_struct || *rows_field == new_field
+        }) {
+            numbered = format!("{} {}", name, suffix);
+            suffix += 1;
+        }
+        taken.push(idents(&numbered));
+        names.insert(entity, numbered);
+    }
+    names
+}
+
+fn column(column: &TableColumn, interactive: bool) -> egui_extras::Column {
+    let width = column.width.max(1.0);
+    let built = match column.width_policy {
+        ColumnWidth::Auto => egui_extras::Column::auto(),
+        ColumnWidth::Initial => egui_extras::Column::initial(width),
+        ColumnWidth::Exact => egui_extras::Column::exact(width),
+        ColumnWidth::Remainder => egui_extras::Column::remainder(),
+    };
+    built.resizable(interactive && column.resizable)
+}
+
+/// `egui_extras::Column` expression matching `column`
+fn column_code(column: &TableColumn) -> String {
+    let width = column.width.max(1.0);
+    let built = match column.width_policy {
+        ColumnWidth::Auto => "egui_extras::Column::auto()".to_string(),
+        ColumnWidth::Initial => format!("egui_extras::Column::initial({:.1})", width),
+        ColumnWidth::Exact => format!("egui_extras::Column::exact({:.1})", width),
+        ColumnWidth::Remainder => "egui_extras::Column::remainder()".to_string(),
+    };
+    format!("{}.resizable({})", built, column.resizable)
+}
+
+fn layout(align: ColumnAlign) -> Option<egui::Layout> {
+    match align {
+        ColumnAlign::Left => None,
+        ColumnAlign::Center => Some(egui::Layout::top_down(egui::Align::Center)),
+        ColumnAlign::Right => Some(egui::Layout::right_to_left(egui::Align::Center)),
+    }
+}
+
+/// Expression of the cell layout for `align`, `None` for the table's default left alignment
+fn layout_code(align: ColumnAlign) -> Option<&'static str> {
+    match align {
+        ColumnAlign::Left => None,
+        ColumnAlign::Center => Some("egui::Layout::top_down(egui::Align::Center)"),
+        ColumnAlign::Right => Some("egui::Layout::right_to_left(egui::Align::Center)"),
+    }
+}
+
+/// `TableBuilder` over the table's sample rows, laid out like the generated code
+fn show_sample_table(ui: &mut Ui, table: &UiTable, size: UiElementSize, id: egui::Id, interactive: bool) -> Response {
+    let size = Vec2::new(size.width.max(20.0), size.height.max(20.0));
+    ui.allocate_ui(size, |ui| {
+        ui.set_min_size(size);
+        ui.shrink_clip_rect(ui.max_rect());
+        if table.columns.is_empty() {
+            ui.weak("No columns");
+            return;
+        }
+
+        let mut rows = table.sample_data();
+        let sort_id = id.with("sort");
+        let sort = if interactive { ui.data(|data| data.get_temp::<(usize, bool)>(sort_id)) } else { None };
+        if let Some((index, ascending)) = sort {
+            rows.sort_by(|a, b| a[index].compare(&b[index]));
+            if !ascending {
+                rows.reverse();
+            }
+        }
+
+        let mut builder = egui_extras::TableBuilder::new(ui)
+            .id_salt(id)
+            .striped(table.striped)
+            .vscroll(interactive)
+            .max_scroll_height((size.y - table.row_height).max(0.0));
+        for table_column in &table.columns {
+            builder = builder.column(column(table_column, interactive));
+        }
+
+        let mut clicked = None;
+        builder
+            .header(table.row_height, |mut header| {
+                for (index, column) in table.columns.iter().enumerate() {
+                    header.col(|ui| {
+                        if column.sortable {
+                            let label = match sort {
+                                Some((sorted, true)) if sorted == index => format!("{} ⬆", column.header),
+                                Some((sorted, false)) if sorted == index => format!("{} ⬇", column.header),
+                                _ => column.header.clone(),
+                            };
+                            if ui.button(label).clicked() {
+                                clicked = Some(index);
+                            }
+                        } else {
+                            ui.strong(&column.header);
+                        }
+                    });
+                }
+            })
+            .body(|body| {
+                body.rows(table.row_height, rows.len(), |mut row| {
+                    let values = &rows[row.index()];
+                    for (column, value) in table.columns.iter().zip(values) {
+                        row.col(|ui| match layout(column.align) {
+                            Some(layout) => {
+                                ui.with_layout(layout, |ui| ui.label(value.display()));
+                            }
+                            None => {
+                                ui.label(value.display());
+                            }
+                        });
+                    }
+                });
+            });
+
+        if let Some(index) = clicked.filter(|_| interactive) {
+            let ascending = sort != Some((index, true));
+            ui.data_mut(|data| data.insert_temp(sort_id, (index, ascending)));
+        }
+    })
+    .response
+}
+
+/// Column editor; true when a column was changed, added, moved or removed
+fn edit_columns(ui: &mut Ui, table: &mut UiTable) -> bool {
+    let mut changed = false;
+    let mut moved = None;
+    let mut removed = None;
+    let len = table.columns.len();
+
+    ui.label(RichText::new("Columns").strong());
+    for (index, column) in table.columns.iter_mut().enumerate() {
+        ui.push_id(index, |ui| {
+            egui::Frame::group(ui.style()).show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    changed |= ui.add(egui::TextEdit::singleline(&mut column.header).hint_text("Header").desired_width(110.0)).changed();
+                    changed |= choice(ui, "type", &mut column.value_type, &[
+                        (ColumnType::Text, "Text"),
+                        (ColumnType::Integer, "Integer"),
+                        (ColumnType::Float, "Float"),
+                        (ColumnType::Bool, "Bool"),
+                    ]);
+                    if ui.add_enabled(index > 0, egui::Button::new("⬆").small()).clicked() {
+                        moved = Some((index, index - 1));
+                    }
+                    if ui.add_enabled(index + 1 < len, egui::Button::new("⬇").small()).clicked() {
+                        moved = Some((index, index + 1));
+                    }
+                    if ui.small_button("🗑").on_hover_text("Remove column").clicked() {
+                        removed = Some(index);
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Width:");
+                    changed |= choice(ui, "width", &mut column.width_policy, &[
+                        (ColumnWidth::Auto, "Auto"),
+                        (ColumnWidth::Initial, "Initial"),
+                        (ColumnWidth::Exact, "Exact"),
+                        (ColumnWidth::Remainder, "Remainder"),
+                    ]);
+                    let sized = matches!(column.width_policy, ColumnWidth::Initial | ColumnWidth::Exact);
+                    changed |= ui.add_enabled(sized, egui::DragValue::new(&mut column.width).range(1.0..=2000.0).suffix(" px")).changed();
+                    ui.label("Align:");
+                    changed |= choice(ui, "align", &mut column.align, &[
+                        (ColumnAlign::Left, "Left"),
+                        (ColumnAlign::Center, "Center"),
+                        (ColumnAlign::Right, "Right"),
+                    ]);
+                });
+                ui.horizontal(|ui| {
+                    changed |= ui.checkbox(&mut column.resizable, "Resizable").changed();
+                    changed |= ui.checkbox(&mut column.sortable, "Sortable").changed();
+                });
+            });
+        });
+    }
+
+    if let Some((from, to)) = moved {
+        table.columns.swap(from, to);
+        changed = true;
+    }
+    if let Some(index) = removed {
+        table.columns.remove(index);
+        changed = true;
+    }
+    if ui.button("➕ Add Column").clicked() {
+        let header = format!("Column {}", table.columns.len() + 1);
+        table.columns.push(TableColumn::new(&header, ColumnType::Text));
+        changed = true;
+    }
+    changed
+}
+
+/// Combo box over a fixed set of options; true when another option was chosen
+fn choice<T: Copy + PartialEq>(ui: &mut Ui, id: &str, value: &mut T, options: &[(T, &str)]) -> bool {
+    let selected = options.iter().find(|(option, _)| option == value).map_or("", |(_, name)| *name);
+    let mut changed = false;
+    egui::ComboBox::from_id_salt(id)
+        .selected_text(selected)
+        .width(90.0)
+        .show_ui(ui, |ui| {
+            for (option, name) in options {
+                changed |= ui.selectable_value(value, *option, *name).changed();
+            }
+        });
+    changed
+}
@@ -0,0 +1,386 @@
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypeRegistry;
+use egui::{Response, RichText, Ui};
+use crate::codegen::{CodeGenerator, ElementStyleCode};
+use crate::codegen_thread::{CustomElement, UiElementSnapshot, UiElementType};
+use crate::components::*;
+use crate::resources::RunPreviewState;
+use super::{element_component, element_size, empty_response, DesignerWidget, WidgetEvents};
+
+/// Change to the sample hierarchy requested in the Inspector; each moves a node with its descendants
+enum NodeEdit {
+    Outdent(usize),
+    Indent(usize),
+    AddChild(usize),
+    Remove(usize),
+}
+
+pub struct TreeViewWidget;
+
+impl TreeViewWidget {
+    /// Tree laid out as in the generated code; returns the index of the node clicked, if any
+    fn show(ui: &mut Ui, tree: &UiTreeView, id: egui::Id, width: f32, is_open: &dyn Fn(usize) -> bool, selected: Option<usize>) -> (Response, Option<usize>) {
+        let inner = ui.add_enabled_ui(tree.enabled, |ui| {
+            ui.set_min_width(width.max(60.0));
+            Self::show_nodes(ui, tree, 0, tree.nodes.len(), id, is_open, selected)
+        });
+        (inner.response, inner.inner)
+    }
+
+    /// The nodes of `start..end` at the level of `start`, leaves as selectable labels and
+    /// nodes with children as collapsing headers around them
+    fn show_nodes(
+        ui: &mut Ui,
+        tree: &UiTreeView,
+        start: usize,
+        end: usize,
+        id: egui::Id,
+        is_open: &dyn Fn(usize) -> bool,
+        selected: Option<usize>,
+    ) -> Option<usize> {
+        let mut clicked = None;
+        for index in tree.level(start, end) {
+            let subtree_end = tree.subtree_end(index);
+            let mut text = RichText::new(&tree.nodes[index].label).size(tree.font_size);
+            if subtree_end == index + 1 {
+                if ui.selectable_label(selected == Some(index), text).clicked() {
+                    clicked = Some(index);
+                }
+                continue;
+            }
+            if selected == Some(index) {
+                text = text.background_color(ui.visuals().selection.bg_fill);
+            }
+            let header = egui::CollapsingHeader::new(text)
+                .id_salt(id.with(index))
+                .open(Some(is_open(index)))
+                .show(ui, |ui| Self::show_nodes(ui, tree, index + 1, subtree_end, id, is_open, selected));
+            if header.header_response.clicked() {
+                clicked = Some(index);
+            }
+            if let Some(Some(child)) = header.body_returned {
+                clicked = Some(child);
+            }
+        }
+        clicked
+    }
+
+    /// Constructor expression of the generated `TreeNode` for the node at `index`
+    fn node_expr(tree: &UiTreeView, index: usize) -> String {
+        let node = &tree.nodes[index];
+        let children = tree.level(index + 1, tree.subtree_end(index));
+        if children.is_empty() {
+            return format!("TreeNode::leaf({:?})", node.label);
+        }
+        let children: Vec<String> = children.into_iter().map(|child| Self::node_expr(tree, child)).collect();
+        format!("TreeNode::new({:?}, {}, vec![{}])", node.label, node.expanded, children.join(", "))
+    }
+}
+
+impl DesignerWidget for TreeViewWidget {
+    fn kind(&self) -> &'static str {
+        "tree_view"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Tree View"
+    }
+
+    fn icon(&self) -> &'static str {
+        "🌳"
+    }
+
+    fn register_types(&self, registry: &mut TypeRegistry) {
+        registry.register::<UiTreeView>();
+    }
+
+    fn is_instance(&self, entity: EntityRef) -> bool {
+        entity.contains::<UiTreeView>()
+    }
+
+    /// The height follows the expanded nodes
+    fn default_size(&self) -> UiElementSize {
+        UiElementSize { width: 220.0, height: 0.0 }
+    }
+
+    fn insert_defaults(&self, entity: &mut EntityWorldMut, number: usize) {
+        entity.insert(UiTreeView::new(&format!("Tree {}", number)));
+    }
+
+    fn label(&self, entity: EntityRef) -> String {
+        entity.get::<UiTreeView>().map(|tree| tree.name.clone()).unwrap_or_default()
+    }
+
+    fn set_label(&self, entity: &mut EntityWorldMut, name: String) {
+        if let Some(mut tree) = entity.get_mut::<UiTreeView>() {
+            tree.name = name;
+        }
+    }
+
+    fn enabled(&self, entity: EntityRef) -> bool {
+        entity.get::<UiTreeView>().is_some_and(|tree| tree.enabled)
+    }
+
+    fn set_enabled(&self, entity: &mut EntityWorldMut, enabled: bool) {
+        if let Some(mut tree) = entity.get_mut::<UiTreeView>() {
+            tree.enabled = enabled;
+        }
+    }
+
+    /// Clicking a node with children expands or collapses it on the canvas, which is also how the app starts
+    fn render_design(&self, ui: &mut Ui, entity: EntityRef, _style: &UiElementStyle, events: &mut WidgetEvents) -> Response {
+        let Some(tree) = entity.get::<UiTreeView>() else {
+            return empty_response(ui);
+        };
+        let id = egui::Id::new(("design_tree", entity.id()));
+        let is_open = |index: usize| tree.nodes[index].expanded;
+        let (response, clicked) = Self::show(ui, tree, id, element_size(entity).width, &is_open, None);
+        if let Some(index) = clicked.filter(|&index| tree.subtree_end(index) > index + 1) {
+            let entity_id = entity.id();
+            events.update(move |world: &mut World| {
+                if let Some(mut tree) = world.get_mut::<UiTreeView>(entity_id) {
+                    if let Some(node) = tree.nodes.get_mut(index) {
+                        node.expanded = !node.expanded;
+                    }
+                }
+            });
+        }
+        response
+    }
+
+    fn render_run(
+        &self,
+        ui: &mut Ui,
+        entity: EntityRef,
+        _style: &UiElementStyle,
+        state: &mut RunPreviewState,
+        log_messages: &mut Vec<String>,
+    ) -> Response {
+        let Some(tree) = entity.get::<UiTreeView>() else {
+            return empty_response(ui);
+        };
+        let entity_id = entity.id();
+        let selected = state.tree_selection.get(&entity_id).copied();
+        let (response, clicked) = {
+            let open_nodes = &state.open_nodes;
+            let is_open = |index: usize| open_nodes.get(&(entity_id, index)).copied().unwrap_or(tree.nodes[index].expanded);
+            Self::show(ui, tree, egui::Id::new(("run_tree", entity_id)), element_size(entity).width, &is_open, selected)
+        };
+        if let Some(index) = clicked {
+            if tree.subtree_end(index) > index + 1 {
+                let open = state.open_nodes.entry((entity_id, index)).or_insert(tree.nodes[index].expanded);
+                *open = !*open;
+            }
+            if selected != Some(index) {
+                state.tree_selection.insert(entity_id, index);
+                log_messages.push(format!("Tree '{}' selected '{}'", tree.name, tree.nodes[index].label));
+            }
+        }
+        response
+    }
+
+    fn render_inspector(&self, ui: &mut Ui, world: &mut World, entity: Entity) {
+        let Some(mut tree) = world.get::<UiTreeView>(entity).cloned() else {
+            return;
+        };
+        let mut changed = false;
+        let mut edit = None;
+
+        ui.label(RichText::new("Sample Nodes").strong());
+        for index in 0..tree.nodes.len() {
+            let can_indent = index > 0 && tree.nodes[index - 1].depth >= tree.nodes[index].depth;
+            let has_children = tree.subtree_end(index) > index + 1;
+            let node = &mut tree.nodes[index];
+            ui.push_id(index, |ui| {
+                ui.horizontal(|ui| {
+                    ui.add_space(node.depth as f32 * 12.0);
+                    changed |= ui.add_enabled(has_children, egui::Checkbox::without_text(&mut node.expanded))
+                        .on_hover_text("Starts expanded")
+                        .changed();
+                    changed |= ui.add(egui::TextEdit::singleline(&mut node.label).hint_text("Label").desired_width(110.0)).changed();
+                    if ui.add_enabled(node.depth > 0, egui::Button::new("⬅").small()).on_hover_text("Outdent").clicked() {
+                        edit = Some(NodeEdit::Outdent(index));
+                    }
+                    if ui.add_enabled(can_indent, egui::Button::new("➡").small()).on_hover_text("Indent under the node above").clicked() {
+                        edit = Some(NodeEdit::Indent(index));
+                    }
+                    if ui.add(egui::Button::new("➕").small()).on_hover_text("Add child").clicked() {
+                        edit = Some(NodeEdit::AddChild(index));
+                    }
+                    if ui.add(egui::Button::new("🗑").small()).on_hover_text("Remove with its children").clicked() {
+                        edit = Some(NodeEdit::Remove(index));
+                    }
+                });
+            });
+        }
+        if ui.button("➕ Add Root Node").clicked() {
+            let label = format!("Node {}", tree.nodes.len() + 1);
+            tree.nodes.push(TreeSampleNode::new(&label, 0));
+            changed = true;
+        }
+
+        if let Some(edit) = edit {
+            match edit {
+                NodeEdit::Outdent(index) => {
+                    let end = tree.subtree_end(index);
+                    tree.nodes[index..end].iter_mut().for_each(|node| node.depth -= 1);
+                }
+                NodeEdit::Indent(index) => {
+                    let end = tree.subtree_end(index);
+                    tree.nodes[index..end].iter_mut().for_each(|node| node.depth += 1);
+                }
+                NodeEdit::AddChild(index) => {
+                    let end = tree.subtree_end(index);
+                    let label = format!("Node {}", tree.nodes.len() + 1);
+                    let depth = tree.nodes[index].depth + 1;
+                    tree.nodes[index].expanded = true;
+                    tree.nodes.insert(end, TreeSampleNode::new(&label, depth));
+                }
+                NodeEdit::Remove(index) => {
+                    let end = tree.subtree_end(index);
+                    tree.nodes.drain(index..end);
+                }
+            }
+            changed = true;
+        }
+        if changed {
+            if let Some(mut current) = world.get_mut::<UiTreeView>(entity) {
+                current.nodes = tree.nodes;
+            }
+        }
+    }
+
+    fn snapshot(&self, entity: EntityRef) -> Option<UiElementType> {
+        let tree = entity.get::<UiTreeView>()?;
+        Some(UiElementType::Custom(CustomElement::new(self.kind(), &tree.name, tree.enabled, tree.font_size, tree)))
+    }
+
+    fn generate_code(&self, gen: &mut CodeGenerator, element: &UiElementSnapshot, _style: &ElementStyleCode) {
+        let Some(tree) = element_component::<UiTreeView>(element) else {
+            return;
+        };
+        let selected = tree.selected_field();
+        gen.writeln(&format!("ui.set_min_width({:.1});", element.size.width.max(60.0)));
+        gen.writeln(&format!(
+            "if let Some(path) = tree_view(ui, {:?}, &app.{}, app.{}.as_deref(), {:.1}) {{",
+            tree.nodes_field(),
+            tree.nodes_field(),
+            selected,
+            tree.font_size
+        ));
+        gen.push_indent();
+        gen.writeln(&format!("if app.{}.as_ref() != Some(&path) {{", selected));
+        gen.push_indent();
+        gen.writeln(&format!("app.{} = Some(path);", selected));
+        gen.writeln(&format!("// TODO: Handle {} selection change", tree.name));
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.pop_indent();
+        gen.writeln("}");
+    }
+
+    fn app_fields(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        let Some(tree) = element_component::<UiTreeView>(element) else {
+            return Vec::new();
+        };
+        vec![
+            (tree.nodes_field(), "Vec<TreeNode>".to_string()),
+            (tree.selected_field(), "Option<Vec<usize>>".to_string()),
+        ]
+    }
+
+    fn app_field_values(&self, element: &UiElementSnapshot) -> Vec<(String, String)> {
+        match element_component::<UiTreeView>(element) {
+            Some(tree) if !tree.nodes.is_empty() => {
+                let roots: Vec<String> = tree.level(0, tree.nodes.len()).into_iter().map(|root| Self::node_expr(&tree, root)).collect();
+                vec![(tree.nodes_field(), format!("vec![{}]", roots.join(", ")))]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn generate_support_code(&self, gen: &mut CodeGenerator, _elements: &[&UiElementSnapshot]) {
+        gen.writeln("/// Node of a tree view");
+        gen.writeln("#[derive(Clone, Debug, Default)]");
+        gen.writeln("pub struct TreeNode {");
+        gen.push_indent();
+        gen.writeln("pub label: String,");
+        gen.writeln("/// Whether the node starts expanded");
+        gen.writeln("pub default_open: bool,");
+        gen.writeln("pub children: Vec<TreeNode>,");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("");
+        gen.writeln("impl TreeNode {");
+        gen.push_indent();
+        gen.writeln("pub fn new(label: &str, default_open: bool, children: Vec<TreeNode>) -> Self {");
+        gen.push_indent();
+        gen.writeln("Self { label: label.to_string(), default_open, children }");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("");
+        gen.writeln("pub fn leaf(label: &str) -> Self {");
+        gen.push_indent();
+        gen.writeln("Self::new(label, false, Vec::new())");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("");
+        gen.writeln("/// Draws `nodes` as nested collapsing headers; returns the path of child indices to the node clicked, if any");
+        gen.writeln("pub fn tree_view(ui: &mut egui::Ui, id_salt: &str, nodes: &[TreeNode], selected: Option<&[usize]>, font_size: f32) -> Option<Vec<usize>> {");
+        gen.push_indent();
+        gen.writeln("tree_level(ui, egui::Id::new(id_salt), nodes, &mut Vec::new(), selected, font_size)");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("");
+        gen.writeln("fn tree_level(ui: &mut egui::Ui, id: egui::Id, nodes: &[TreeNode], path: &mut Vec<usize>, selected: Option<&[usize]>, font_size: f32) -> Option<Vec<usize>> {");
+        gen.push_indent();
+        gen.writeln("let mut clicked = None;");
+        gen.writeln("for (index, node) in nodes.iter().enumerate() {");
+        gen.push_indent();
+        gen.writeln("path.push(index);");
+        gen.writeln("let is_selected = selected == Some(path.as_slice());");
+        gen.writeln("let mut text = egui::RichText::new(&node.label).size(font_size);");
+        gen.writeln("if node.children.is_empty() {");
+        gen.push_indent();
+        gen.writeln("if ui.selectable_label(is_selected, text).clicked() {");
+        gen.push_indent();
+        gen.writeln("clicked = Some(path.clone());");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.pop_indent();
+        gen.writeln("} else {");
+        gen.push_indent();
+        gen.writeln("if is_selected {");
+        gen.push_indent();
+        gen.writeln("text = text.background_color(ui.visuals().selection.bg_fill);");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("let header = egui::CollapsingHeader::new(text)");
+        gen.push_indent();
+        gen.writeln(".id_salt(id.with(&path))");
+        gen.writeln(".default_open(node.default_open)");
+        gen.writeln(".show(ui, |ui| tree_level(ui, id, &node.children, path, selected, font_size));");
+        gen.pop_indent();
+        gen.writeln("if header.header_response.clicked() {");
+        gen.push_indent();
+        gen.writeln("clicked = Some(path.clone());");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("if let Some(Some(child)) = header.body_returned {");
+        gen.push_indent();
+        gen.writeln("clicked = Some(child);");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("path.pop();");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("clicked");
+        gen.pop_indent();
+        gen.writeln("}");
+        gen.writeln("");
+    }
+}
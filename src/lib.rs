@@ -3,6 +3,7 @@ use bevy_ecs::prelude::*;
 use egui::Ui;
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use chrono::Local;
 use chrono_tz::Tz;
 
@@ -10,7 +11,7 @@ use chrono_tz::Tz;
 // Core Components and Systems (Simplified for MVP)
 // =============================================================================
 
-#[derive(Component, Clone, PartialEq, Debug)]
+#[derive(Component, Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub enum TabType {
     MainWork,
     Settings,
@@ -30,6 +31,7 @@ pub struct MobiusApp {
     pub app_name      : String,
     pub template_name : String,
     pub active_tab    : TabType,
+    pub tabs          : Vec<TemplateTab>,
 }
 
 #[derive(Component)]
@@ -38,7 +40,8 @@ pub struct MainWorkArea {
     pub background_color : [f32; 4],
 }
 
-#[derive(Component)]
+#[derive(Component, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SettingsPanel {
     pub global_units_mils  : bool,
     pub user_timezone      : Option<String>,
@@ -46,6 +49,17 @@ pub struct SettingsPanel {
     pub interface_language : String,
 }
 
+impl Default for SettingsPanel {
+    fn default() -> Self {
+        Self {
+            global_units_mils: false,
+            user_timezone: None,
+            use_24_hour_clock: true,
+            interface_language: "English".to_string(),
+        }
+    }
+}
+
 #[derive(Component)]
 pub struct EventLoggerPanel {
     pub entries           : Vec<LogEntry>,
@@ -70,13 +84,20 @@ pub struct ControlsPanel {
     pub buttons: Vec<ControlButton>,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ControlButton {
     pub id: String,
     pub label: String,
+    #[serde(default = "default_true")]
     pub enabled: bool,
 }
 
+impl ControlButton {
+    pub fn new(id: &str, label: &str) -> Self {
+        Self { id: id.to_string(), label: label.to_string(), enabled: true }
+    }
+}
+
 #[derive(Component)]
 pub struct GenericTab {
     pub title: String,
@@ -84,41 +105,329 @@ pub struct GenericTab {
 }
 
 // =============================================================================
-// Template System (Simplified)
+// Template System
 // =============================================================================
 
-#[derive(Serialize, Deserialize, Clone)]
+/// Directory, relative to the working directory, that template files are loaded from
+pub const TEMPLATES_DIR: &str = "templates";
+
+/// Description of a Mobius application: its tabs, the panel components spawned for them,
+/// its initial settings and control buttons. Templates are data, read from RON or JSON files.
+///
+/// A template can build on another one named in `extends`; `MobiusTemplateRegistry::resolve`
+/// combines them. Fields a template leaves out are inherited from its parent, or get the
+/// defaults of its `main_area_type` when it has no parent.
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct MobiusTemplate {
     pub name: String,
+    /// Inherited when empty
+    #[serde(default)]
     pub description: String,
-    pub main_area_type: MainAreaType,
+    /// Template this one builds on
+    #[serde(default)]
+    pub extends: Option<String>,
+    /// Inherited when missing; `Generic` for a template without parent
+    #[serde(default)]
+    pub main_area_type: Option<MainAreaType>,
+    /// Tabs of the application, in order, replacing the parent's; the first one is active at start
+    #[serde(default)]
+    pub tabs: Option<Vec<TemplateTab>>,
+    /// Tabs added after the inherited ones
+    #[serde(default)]
+    pub add_tabs: Vec<TemplateTab>,
+    /// Panel components to spawn. Without a parent they are the template's panels; with one, each
+    /// replaces the parent's panel on the same tab.
+    #[serde(default)]
+    pub panels: Option<Vec<TemplatePanel>>,
+    /// Panels for tabs the parent has no panel on
+    #[serde(default)]
+    pub add_panels: Vec<TemplatePanel>,
+    /// Settings the template sets; the others are inherited
+    #[serde(default)]
+    pub settings: TemplateSettings,
 }
 
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub enum MainAreaType {
     GerberViewer,
     TextEditor,
     Generic,
 }
 
-impl MobiusTemplate {
-    pub fn gerber_viewer() -> Self {
+/// Tab of a templated application
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct TemplateTab {
+    pub title: String,
+    pub tab_type: TabType,
+}
+
+impl TemplateTab {
+    pub fn new(title: &str, tab_type: TabType) -> Self {
+        Self { title: title.to_string(), tab_type }
+    }
+}
+
+/// Panel component spawned for a template, on the tab of its kind
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub enum TemplatePanel {
+    MainWork {
+        content: String,
+        #[serde(default = "default_background_color")]
+        background_color: [f32; 4],
+    },
+    EventLogger {
+        #[serde(default = "default_max_entries")]
+        max_entries: usize,
+        #[serde(default = "default_true")]
+        auto_scroll: bool,
+    },
+    Controls {
+        buttons: Vec<ControlButton>,
+    },
+    /// Placeholder tab, shown on `TabType::Generic(title)`
+    Generic {
+        title: String,
+        #[serde(default)]
+        content: String,
+    },
+}
+
+impl TemplatePanel {
+    /// Tab the panel is shown on; a template has one panel per tab
+    pub fn tab_type(&self) -> TabType {
+        match self {
+            TemplatePanel::MainWork { .. } => TabType::MainWork,
+            TemplatePanel::EventLogger { .. } => TabType::EventLogger,
+            TemplatePanel::Controls { .. } => TabType::Controls,
+            TemplatePanel::Generic { title, .. } => TabType::Generic(title.clone()),
+        }
+    }
+}
+
+/// Initial `SettingsPanel` values set by a template
+#[derive(Serialize, Deserialize, Clone, Default, PartialEq, Debug)]
+#[serde(default)]
+pub struct TemplateSettings {
+    pub global_units_mils: Option<bool>,
+    pub user_timezone: Option<String>,
+    pub use_24_hour_clock: Option<bool>,
+    pub interface_language: Option<String>,
+}
+
+impl TemplateSettings {
+    /// These settings, with the unset ones taken from `parent`
+    fn inherit(&self, parent: &TemplateSettings) -> Self {
         Self {
-            name: "gerber_viewer".to_string(),
-            description: "PCB Gerber file viewer".to_string(),
-            main_area_type: MainAreaType::GerberViewer,
+            global_units_mils: self.global_units_mils.or(parent.global_units_mils),
+            user_timezone: self.user_timezone.clone().or_else(|| parent.user_timezone.clone()),
+            use_24_hour_clock: self.use_24_hour_clock.or(parent.use_24_hour_clock),
+            interface_language: self.interface_language.clone().or_else(|| parent.interface_language.clone()),
         }
     }
+
+    /// Settings panel with these settings, and the defaults for the unset ones
+    pub fn panel(&self) -> SettingsPanel {
+        let defaults = SettingsPanel::default();
+        SettingsPanel {
+            global_units_mils: self.global_units_mils.unwrap_or(defaults.global_units_mils),
+            user_timezone: self.user_timezone.clone().or(defaults.user_timezone),
+            use_24_hour_clock: self.use_24_hour_clock.unwrap_or(defaults.use_24_hour_clock),
+            interface_language: self.interface_language.clone().unwrap_or(defaults.interface_language),
+        }
+    }
+}
+
+fn default_tabs() -> Vec<TemplateTab> {
+    vec![
+        TemplateTab::new("Main", TabType::MainWork),
+        TemplateTab::new("Settings", TabType::Settings),
+        TemplateTab::new("Logs", TabType::EventLogger),
+        TemplateTab::new("Controls", TabType::Controls),
+    ]
+}
+
+fn default_background_color() -> [f32; 4] {
+    [0.1, 0.1, 0.1, 1.0]
+}
+
+fn default_max_entries() -> usize {
+    1000
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl MobiusTemplate {
+    pub fn gerber_viewer() -> Self {
+        Self::builtin(include_str!("templates/gerber_viewer.ron"))
+    }
     
     pub fn text_editor() -> Self {
-        Self {
-            name: "text_editor".to_string(),
-            description: "Code/text editor".to_string(),
-            main_area_type: MainAreaType::TextEditor,
+        Self::builtin(include_str!("templates/text_editor.ron"))
+    }
+
+    pub fn login_form() -> Self {
+        Self::builtin(include_str!("templates/login_form.ron"))
+    }
+
+    pub fn settings_dialog() -> Self {
+        Self::builtin(include_str!("templates/settings_dialog.ron"))
+    }
+
+    pub fn log_viewer() -> Self {
+        Self::builtin(include_str!("templates/log_viewer.ron"))
+    }
+
+    pub fn base_engineering_app() -> Self {
+        Self::builtin(include_str!("templates/base_engineering_app.ron"))
+    }
+
+    fn builtin(source: &str) -> Self {
+        Self::from_ron(source).expect("built-in template is valid RON")
+    }
+
+    /// Optional fields are written without `Some(..)`
+    fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(source)
+    }
+
+    /// Read a template from a `.ron` or `.json` file
+    pub fn from_file(path: &Path) -> Result<Self, TemplateError> {
+        let contents = std::fs::read_to_string(path).map_err(|e| TemplateError::Io {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+        let parse_error = |message: String| TemplateError::Parse { path: path.to_path_buf(), message };
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("ron") => Self::from_ron(&contents).map_err(|e| parse_error(e.to_string())),
+            Some("json") => serde_json::from_str(&contents).map_err(|e| parse_error(e.to_string())),
+            _ => Err(TemplateError::UnsupportedFormat(path.to_path_buf())),
+        }
+    }
+
+    /// Tabs of the template, the standard ones when it doesn't set them
+    pub fn tabs(&self) -> Vec<TemplateTab> {
+        self.tabs.clone().unwrap_or_else(default_tabs)
+    }
+
+    /// Panel components spawned for the template
+    pub fn panels(&self) -> Vec<TemplatePanel> {
+        self.panels.clone().unwrap_or_else(|| {
+            let content = match self.main_area_type.clone().unwrap_or(MainAreaType::Generic) {
+                MainAreaType::GerberViewer => "Gerber Viewer - Drop .gbr files here",
+                MainAreaType::TextEditor => "Text Editor - Start typing...",
+                MainAreaType::Generic => "Generic work area",
+            };
+            vec![
+                TemplatePanel::MainWork { content: content.to_string(), background_color: default_background_color() },
+                TemplatePanel::EventLogger { max_entries: default_max_entries(), auto_scroll: true },
+                TemplatePanel::Controls {
+                    buttons: vec![ControlButton::new("btn1", "Action 1"), ControlButton::new("btn2", "Action 2")],
+                },
+            ]
+        })
+    }
+
+    /// The resolved `parent` with this template's fields applied on top
+    fn inherit(&self, parent: MobiusTemplate) -> Result<MobiusTemplate, TemplateError> {
+        let conflict = |field: &str, message: String| TemplateError::Conflict {
+            template: self.name.clone(),
+            field: field.to_string(),
+            message,
+        };
+        let mut resolved = MobiusTemplate {
+            name: self.name.clone(),
+            description: if self.description.is_empty() { parent.description.clone() } else { self.description.clone() },
+            extends: None,
+            main_area_type: self.main_area_type.clone().or(parent.main_area_type.clone()),
+            tabs: None,
+            add_tabs: Vec::new(),
+            panels: None,
+            add_panels: Vec::new(),
+            settings: self.settings.inherit(&parent.settings),
+        };
+
+        let mut tabs = self.tabs.clone().unwrap_or_else(|| parent.tabs());
+        for tab in &self.add_tabs {
+            if tabs.iter().any(|existing| existing.tab_type == tab.tab_type) {
+                return Err(conflict("add_tabs", format!("a {:?} tab already exists; list the tabs in `tabs` to replace them", tab.tab_type)));
+            }
+            tabs.push(tab.clone());
+        }
+        resolved.tabs = Some(tabs);
+
+        // Panels are matched by the tab they are shown on
+        let mut panels = match (&self.panels, &parent.panels) {
+            (Some(own), Some(inherited)) => {
+                let mut panels = inherited.clone();
+                for panel in own {
+                    let Some(slot) = panels.iter_mut().find(|existing| existing.tab_type() == panel.tab_type()) else {
+                        return Err(conflict("panels", format!(
+                            "'{}' has no {:?} panel to replace; add it with `add_panels`",
+                            self.extends.as_deref().unwrap_or_default(),
+                            panel.tab_type()
+                        )));
+                    };
+                    *slot = panel.clone();
+                }
+                panels
+            }
+            (Some(own), None) => own.clone(),
+            (None, _) => {
+                resolved.panels = parent.panels.clone();
+                resolved.panels()
+            }
+        };
+        for panel in &self.add_panels {
+            if panels.iter().any(|existing| existing.tab_type() == panel.tab_type()) {
+                return Err(conflict("add_panels", format!("a {:?} panel already exists; list it in `panels` to replace it", panel.tab_type())));
+            }
+            panels.push(panel.clone());
+        }
+        resolved.panels = Some(panels);
+
+        Ok(resolved)
+    }
+}
+
+/// Why a template could not be loaded or resolved
+#[derive(Debug)]
+pub enum TemplateError {
+    Io { path: PathBuf, message: String },
+    Parse { path: PathBuf, message: String },
+    /// Template files are `.ron` or `.json`
+    UnsupportedFormat(PathBuf),
+    UnknownTemplate(String),
+    UnknownParent { template: String, parent: String },
+    /// Templates that extend each other, starting and ending with the same template
+    Cycle(Vec<String>),
+    /// A field of `template` that doesn't fit what it inherits
+    Conflict { template: String, field: String, message: String },
+}
+
+impl std::fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateError::Io { path, message } => write!(f, "{}: {}", path.display(), message),
+            TemplateError::Parse { path, message } => write!(f, "{}: invalid template: {}", path.display(), message),
+            TemplateError::UnsupportedFormat(path) => write!(f, "{}: template files must be .ron or .json", path.display()),
+            TemplateError::UnknownTemplate(name) => write!(f, "unknown template '{}'", name),
+            TemplateError::UnknownParent { template, parent } => {
+                write!(f, "template '{}' extends '{}', which does not exist", template, parent)
+            }
+            TemplateError::Cycle(chain) => write!(f, "templates extend each other in a cycle: {}", chain.join(" → ")),
+            TemplateError::Conflict { template, field, message } => {
+                write!(f, "template '{}', field `{}`: {}", template, field, message)
+            }
         }
     }
 }
 
+impl std::error::Error for TemplateError {}
+
 // =============================================================================
 // Registry and Spawning
 // =============================================================================
@@ -136,6 +445,10 @@ impl Default for MobiusTemplateRegistry {
         
         registry.templates.insert("gerber_viewer".to_string(), MobiusTemplate::gerber_viewer());
         registry.templates.insert("text_editor".to_string(), MobiusTemplate::text_editor());
+        registry.templates.insert("login_form".to_string(), MobiusTemplate::login_form());
+        registry.templates.insert("settings_dialog".to_string(), MobiusTemplate::settings_dialog());
+        registry.templates.insert("log_viewer".to_string(), MobiusTemplate::log_viewer());
+        registry.templates.insert("base_engineering_app".to_string(), MobiusTemplate::base_engineering_app());
         
         registry
     }
@@ -145,77 +458,128 @@ impl MobiusTemplateRegistry {
     pub fn get_templates(&self) -> &HashMap<String, MobiusTemplate> {
         &self.templates
     }
+
+    /// Add a template, replacing any template of the same name
+    pub fn register(&mut self, template: MobiusTemplate) {
+        self.templates.insert(template.name.clone(), template);
+    }
+
+    /// Built-in templates plus the template files in `TEMPLATES_DIR`, and the files that failed to load
+    pub fn with_templates_dir() -> (Self, Vec<TemplateError>) {
+        let mut registry = Self::default();
+        let errors = registry.load_dir(Path::new(TEMPLATES_DIR));
+        (registry, errors)
+    }
+
+    /// Register the `.ron` and `.json` template files of a directory; a file replaces a template
+    /// of the same name. A missing directory has no templates.
+    pub fn load_dir(&mut self, dir: &Path) -> Vec<TemplateError> {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return Vec::new();
+        };
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "ron" || ext == "json"))
+            .collect();
+        paths.sort();
+
+        let mut errors = Vec::new();
+        for path in paths {
+            match MobiusTemplate::from_file(&path) {
+                Ok(template) => self.register(template),
+                Err(e) => errors.push(e),
+            }
+        }
+        errors
+    }
+    
+    /// The template with everything it inherits through `extends`
+    pub fn resolve(&self, template_name: &str) -> Result<MobiusTemplate, TemplateError> {
+        let mut chain = Vec::new();
+        self.resolve_chain(template_name, &mut chain)
+    }
+
+    fn resolve_chain(&self, template_name: &str, chain: &mut Vec<String>) -> Result<MobiusTemplate, TemplateError> {
+        let seen = chain.iter().any(|name| name == template_name);
+        chain.push(template_name.to_string());
+        if seen {
+            let start = chain.iter().position(|name| name == template_name).unwrap_or_default();
+            return Err(TemplateError::Cycle(chain[start..].to_vec()));
+        }
+        let template = self.templates.get(template_name).ok_or_else(|| TemplateError::UnknownTemplate(template_name.to_string()))?;
+        let parent = match &template.extends {
+            Some(parent) if !self.templates.contains_key(parent) => {
+                return Err(TemplateError::UnknownParent { template: template_name.to_string(), parent: parent.clone() });
+            }
+            Some(parent) => self.resolve_chain(parent, chain)?,
+            None => MobiusTemplate {
+                name: String::new(),
+                description: String::new(),
+                extends: None,
+                main_area_type: None,
+                tabs: None,
+                add_tabs: Vec::new(),
+                panels: None,
+                add_panels: Vec::new(),
+                settings: TemplateSettings::default(),
+            },
+        };
+        template.inherit(parent)
+    }
+
+    /// Errors of the templates that don't resolve
+    pub fn check(&self) -> Vec<TemplateError> {
+        let mut names: Vec<&String> = self.templates.keys().collect();
+        names.sort();
+        names.into_iter().filter_map(|name| self.resolve(name).err()).collect()
+    }
     
+    /// Spawn the app and panel entities of a template. `None` if the template is unknown or
+    /// doesn't resolve; `resolve` tells why.
     pub fn spawn_from_template(&self, commands: &mut Commands, template_name: &str) -> Option<Entity> {
-        let template = self.templates.get(template_name)?;
+        let template = self.resolve(template_name).ok()?;
+        let tabs = template.tabs();
         
         // Create main app entity
         let app_entity = commands.spawn(MobiusApp {
             app_name: template.name.clone(),
             template_name: template_name.to_string(),
-            active_tab: TabType::MainWork,
+            active_tab: tabs.first().map(|tab| tab.tab_type.clone()).unwrap_or_default(),
+            tabs,
         }).id();
         
-        // Create main work area
-        let main_content = match template.main_area_type {
-            MainAreaType::GerberViewer => "Gerber Viewer - Drop .gbr files here",
-            MainAreaType::TextEditor => "Text Editor - Start typing...",
-            MainAreaType::Generic => "Generic work area",
-        };
-        
-        commands.spawn((
-            MainWorkArea {
-                content: main_content.to_string(),
-                background_color: [0.1, 0.1, 0.1, 1.0],
-            },
-            TabType::MainWork,
-        ));
-        
-        // Create settings panel
-        commands.spawn((
-            SettingsPanel {
-                global_units_mils: false,
-                user_timezone: None,
-                use_24_hour_clock: true,
-                interface_language: "English".to_string(),
-            },
-            TabType::Settings,
-        ));
-        
-        // Create event logger
-        commands.spawn((
-            EventLoggerPanel {
-                entries: vec![
-                    LogEntry {
-                        timestamp: "12:34:56".to_string(),
-                        level: LogLevel::Info,
-                        message: format!("Application '{}' started", template.name),
-                    }
-                ],
-                max_entries: 1000,
-                auto_scroll: true,
-            },
-            TabType::EventLogger,
-        ));
+        for panel in template.panels() {
+            match panel {
+                TemplatePanel::MainWork { content, background_color } => {
+                    commands.spawn((MainWorkArea { content, background_color }, TabType::MainWork));
+                }
+                TemplatePanel::EventLogger { max_entries, auto_scroll } => {
+                    commands.spawn((
+                        EventLoggerPanel {
+                            entries: vec![
+                                LogEntry {
+                                    timestamp: Local::now().format("%H:%M:%S").to_string(),
+                                    level: LogLevel::Info,
+                                    message: format!("Application '{}' started", template.name),
+                                }
+                            ],
+                            max_entries,
+                            auto_scroll,
+                        },
+                        TabType::EventLogger,
+                    ));
+                }
+                TemplatePanel::Controls { buttons } => {
+                    commands.spawn((ControlsPanel { buttons }, TabType::Controls));
+                }
+                TemplatePanel::Generic { title, content } => {
+                    commands.spawn((GenericTab { title: title.clone(), content }, TabType::Generic(title)));
+                }
+            }
+        }
         
-        // Create controls panel
-        commands.spawn((
-            ControlsPanel {
-                buttons: vec![
-                    ControlButton {
-                        id: "btn1".to_string(),
-                        label: "Action 1".to_string(),
-                        enabled: true,
-                    },
-                    ControlButton {
-                        id: "btn2".to_string(),
-                        label: "Action 2".to_string(),
-                        enabled: true,
-                    },
-                ],
-            },
-            TabType::Controls,
-        ));
+        // Settings apply to the whole application
+        commands.spawn((template.settings.panel(), TabType::Settings));
         
         Some(app_entity)
     }
@@ -466,10 +830,174 @@ pub fn show_controls_panel(ui: &mut Ui, world: &mut World) {
     }
 }
 
-pub fn show_generic_tab(ui: &mut Ui, _world: &World, tab_name: &str) {
+pub fn show_generic_tab(ui: &mut Ui, world: &World, tab_name: &str) {
     ui.heading(format!("Generic Tab: {}", tab_name));
     ui.separator();
-    ui.label("This is a placeholder for generic tab content");
+    let content = world.iter_entities()
+        .find_map(|entity| entity.get::<GenericTab>().filter(|tab| tab.title == tab_name).map(|tab| tab.content.clone()));
+    ui.label(content.filter(|content| !content.is_empty()).unwrap_or_else(|| "This is a placeholder for generic tab content".to_string()));
+}
+
+// =============================================================================
+// Plugins
+// =============================================================================
+
+/// Draws the content of a tab, or a section of the settings tab
+pub type PanelRenderFn = Box<dyn Fn(&mut Ui, &mut World) + Send + Sync>;
+
+/// Runs once per frame, before the tabs are drawn
+pub type SystemFn = Box<dyn FnMut(&mut World) + Send + Sync>;
+
+/// Part of a Mobius application: the tabs it adds and how they are drawn, the components it
+/// starts with, sections of the settings tab and per-frame systems. Applications compose a
+/// `MobiusPlugins` list instead of matching on `TabType` themselves.
+pub trait MobiusPlugin: Send + Sync {
+    fn name(&self) -> &str;
+
+    /// Register the plugin's tabs, settings sections and systems
+    fn build(&self, plugins: &mut MobiusPlugins);
+
+    /// Spawn the plugin's initial components
+    fn spawn(&self, _world: &mut World) {}
+}
+
+struct PluginTab {
+    tab_type: TabType,
+    title: String,
+    render: PanelRenderFn,
+}
+
+struct SettingsSection {
+    title: String,
+    render: PanelRenderFn,
+}
+
+/// The plugins an application is made of, and what they registered
+#[derive(Default)]
+pub struct MobiusPlugins {
+    plugins: Vec<Box<dyn MobiusPlugin>>,
+    tabs: Vec<PluginTab>,
+    settings_sections: Vec<SettingsSection>,
+    systems: Vec<SystemFn>,
+}
+
+impl MobiusPlugins {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a plugin and let it register its tabs, settings sections and systems
+    pub fn add_plugin(mut self, plugin: impl MobiusPlugin + 'static) -> Self {
+        plugin.build(&mut self);
+        self.plugins.push(Box::new(plugin));
+        self
+    }
+
+    /// Draw the tab with `render`. A tab registered again keeps its place and title
+    /// but is drawn by the plugin added last.
+    pub fn add_tab(
+        &mut self,
+        tab_type: TabType,
+        title: &str,
+        render: impl Fn(&mut Ui, &mut World) + Send + Sync + 'static,
+    ) -> &mut Self {
+        let render: PanelRenderFn = Box::new(render);
+        match self.tabs.iter_mut().find(|tab| tab.tab_type == tab_type) {
+            Some(tab) => tab.render = render,
+            None => self.tabs.push(PluginTab { tab_type, title: title.to_string(), render }),
+        }
+        self
+    }
+
+    /// Add a section below the application settings on the settings tab
+    pub fn add_settings_section(
+        &mut self,
+        title: &str,
+        render: impl Fn(&mut Ui, &mut World) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.settings_sections.push(SettingsSection { title: title.to_string(), render: Box::new(render) });
+        self
+    }
+
+    pub fn add_system(&mut self, system: impl FnMut(&mut World) + Send + Sync + 'static) -> &mut Self {
+        self.systems.push(Box::new(system));
+        self
+    }
+
+    /// Names of the plugins, in the order they were added
+    pub fn names(&self) -> Vec<&str> {
+        self.plugins.iter().map(|plugin| plugin.name()).collect()
+    }
+
+    /// Registered tabs with their titles, in order
+    pub fn tabs(&self) -> impl Iterator<Item = (&TabType, &str)> {
+        self.tabs.iter().map(|tab| (&tab.tab_type, tab.title.as_str()))
+    }
+
+    /// Tabs of an application: the template's tabs, followed by the registered tabs it lacks
+    pub fn app_tabs(&self, template_tabs: &[TemplateTab]) -> Vec<TemplateTab> {
+        let mut tabs = template_tabs.to_vec();
+        for tab in &self.tabs {
+            if !tabs.iter().any(|other| other.tab_type == tab.tab_type) {
+                tabs.push(TemplateTab::new(&tab.title, tab.tab_type.clone()));
+            }
+        }
+        tabs
+    }
+
+    /// Spawn the initial components of every plugin
+    pub fn spawn(&self, world: &mut World) {
+        for plugin in &self.plugins {
+            plugin.spawn(world);
+        }
+    }
+
+    /// Run every plugin's systems once; call it each frame
+    pub fn run_systems(&mut self, world: &mut World) {
+        for system in &mut self.systems {
+            system(world);
+        }
+    }
+
+    /// Draw a tab's content. Generic tabs no plugin draws show their placeholder.
+    pub fn show_tab(&self, ui: &mut Ui, world: &mut World, tab_type: &TabType) {
+        match self.tabs.iter().find(|tab| &tab.tab_type == tab_type) {
+            Some(tab) => (tab.render)(ui, world),
+            None => match tab_type {
+                TabType::Generic(name) => show_generic_tab(ui, world, name),
+                _ => {
+                    ui.label(format!("No plugin draws the {:?} tab", tab_type));
+                }
+            },
+        }
+
+        if *tab_type == TabType::Settings {
+            for section in &self.settings_sections {
+                ui.add_space(20.0);
+                ui.group(|ui| {
+                    ui.label(&section.title);
+                    (section.render)(ui, world);
+                });
+            }
+        }
+    }
+}
+
+/// The standard tabs: main work area, settings, event logger and controls
+pub struct CorePlugin;
+
+impl MobiusPlugin for CorePlugin {
+    fn name(&self) -> &str {
+        "core"
+    }
+
+    fn build(&self, plugins: &mut MobiusPlugins) {
+        plugins
+            .add_tab(TabType::MainWork, "📋 Main", show_main_work_area)
+            .add_tab(TabType::Settings, "⚙️ Settings", show_settings_panel)
+            .add_tab(TabType::EventLogger, "📜 Logs", show_event_logger_panel)
+            .add_tab(TabType::Controls, "🎛️ Controls", show_controls_panel);
+    }
 }
 
 // =============================================================================
@@ -518,16 +1046,29 @@ pub struct {}App {{
     world: World,
     active_tab: TabType,
     app_entity: Option<Entity>,
+    tabs: Vec<TemplateTab>,
+    plugins: MobiusPlugins,
 }}
 
 impl App for {}App {{
     fn update(&mut self, ctx: &egui::Context, _frame: &mut Frame) {{
         // Initialize if needed
         if self.app_entity.is_none() {{
+            // Add your own plugins to the list to add tabs, settings and systems
+            self.plugins = MobiusPlugins::new().add_plugin(CorePlugin);
             let registry = MobiusTemplateRegistry::default();
             let mut commands = self.world.commands();
             self.app_entity = registry.spawn_from_template(&mut commands, "{}");
+            self.world.flush();
+
+            // Tabs in the template's order, starting on its first
+            if let Some(app) = self.app_entity.and_then(|entity| self.world.get::<MobiusApp>(entity)) {{
+                self.tabs = self.plugins.app_tabs(&app.tabs);
+                self.active_tab = app.active_tab.clone();
+            }}
+            self.plugins.spawn(&mut self.world);
         }}
+        self.plugins.run_systems(&mut self.world);
         
         // Top menu bar
         egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {{
@@ -538,10 +1079,9 @@ impl App for {}App {{
                     }}
                 }});
                 ui.menu_button("View", |ui| {{
-                    ui.selectable_value(&mut self.active_tab, TabType::MainWork, "Main Work Area");
-                    ui.selectable_value(&mut self.active_tab, TabType::Settings, "Settings");
-                    ui.selectable_value(&mut self.active_tab, TabType::EventLogger, "Event Logger");
-                    ui.selectable_value(&mut self.active_tab, TabType::Controls, "Controls");
+                    for tab in &self.tabs {{
+                        ui.selectable_value(&mut self.active_tab, tab.tab_type.clone(), &tab.title);
+                    }}
                 }});
             }});
         }});
@@ -549,22 +1089,15 @@ impl App for {}App {{
         // Tab bar
         egui::TopBottomPanel::top("tab_panel").show(ctx, |ui| {{
             ui.horizontal(|ui| {{
-                ui.selectable_value(&mut self.active_tab, TabType::MainWork, "📋 Main");
-                ui.selectable_value(&mut self.active_tab, TabType::Settings, "⚙️ Settings");
-                ui.selectable_value(&mut self.active_tab, TabType::EventLogger, "📜 Logs");
-                ui.selectable_value(&mut self.active_tab, TabType::Controls, "🎛️ Controls");
+                for tab in &self.tabs {{
+                    ui.selectable_value(&mut self.active_tab, tab.tab_type.clone(), &tab.title);
+                }}
             }});
         }});
 
         // Main content area
         egui::CentralPanel::default().show(ctx, |ui| {{
-            match &self.active_tab {{
-                TabType::MainWork => show_main_work_area(ui, &self.world),
-                TabType::Settings => show_settings_panel(ui, &mut self.world),
-                TabType::EventLogger => show_event_logger_panel(ui, &mut self.world),
-                TabType::Controls => show_controls_panel(ui, &self.world),
-                TabType::Generic(name) => show_generic_tab(ui, &self.world, name),
-            }}
+            self.plugins.show_tab(ui, &mut self.world, &self.active_tab);
         }});
     }}
 }}
@@ -608,5 +1141,178 @@ mod tests {
         let registry = MobiusTemplateRegistry::default();
         assert!(registry.templates.contains_key("gerber_viewer"));
         assert!(registry.templates.contains_key("text_editor"));
+        assert!(registry.templates.contains_key("login_form"));
+        assert!(registry.templates.contains_key("settings_dialog"));
+        assert!(registry.templates.contains_key("log_viewer"));
+    }
+
+    #[test]
+    fn test_spawn_from_template_data() {
+        let registry = MobiusTemplateRegistry::default();
+        let mut world = World::new();
+        let app = registry.spawn_from_template(&mut world.commands(), "log_viewer").unwrap();
+        world.flush();
+
+        let app = world.get::<MobiusApp>(app).unwrap();
+        assert_eq!(app.active_tab, TabType::EventLogger);
+        assert_eq!(app.tabs.len(), 3);
+        let controls = world.query::<&ControlsPanel>().single(&world).unwrap();
+        assert_eq!(controls.buttons[0].label, "Clear Log");
+        assert_eq!(world.query::<&EventLoggerPanel>().single(&world).unwrap().max_entries, 10000);
+        assert_eq!(world.query::<&MainWorkArea>().iter(&world).count(), 0);
+        assert_eq!(world.query::<&SettingsPanel>().iter(&world).count(), 1);
+    }
+
+    #[test]
+    fn test_load_template_files() {
+        let dir = std::env::temp_dir().join(format!("mobius_templates_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("inspector.ron"), r#"(
+            name: "inspector",
+            description: "Property inspector",
+            main_area_type: Generic,
+            tabs: [(title: "Layers", tab_type: Generic("Layers"))],
+            panels: [Generic(title: "Layers", content: "Layer list")],
+            settings: (use_24_hour_clock: false),
+        )"#).unwrap();
+        std::fs::write(dir.join("minimal.json"), r#"{"name": "minimal", "description": "Minimal", "main_area_type": "Generic"}"#).unwrap();
+        std::fs::write(dir.join("broken.ron"), "(name: ").unwrap();
+        std::fs::write(dir.join("notes.txt"), "not a template").unwrap();
+
+        let mut registry = MobiusTemplateRegistry::default();
+        let errors = registry.load_dir(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("broken.ron"));
+        let inspector = registry.resolve("inspector").unwrap();
+        assert_eq!(inspector.tabs(), vec![TemplateTab::new("Layers", TabType::Generic("Layers".to_string()))]);
+        assert!(!inspector.settings.panel().use_24_hour_clock);
+        // Missing sections fall back to the standard tabs and panels
+        let minimal = registry.resolve("minimal").unwrap();
+        assert_eq!(minimal.tabs().len(), 4);
+        assert_eq!(minimal.panels().len(), 3);
+    }
+
+    fn template(source: &str) -> MobiusTemplate {
+        MobiusTemplate::from_ron(source).unwrap()
+    }
+
+    #[test]
+    fn test_template_inheritance() {
+        let registry = MobiusTemplateRegistry::default();
+        assert!(registry.check().is_empty());
+
+        let gerber = registry.resolve("gerber_viewer").unwrap();
+        assert_eq!(gerber.main_area_type, Some(MainAreaType::GerberViewer));
+        let tabs: Vec<TabType> = gerber.tabs().into_iter().map(|tab| tab.tab_type).collect();
+        assert_eq!(tabs.len(), 5);
+        assert_eq!(tabs[4], TabType::Generic("Layers".to_string()));
+        let panels = gerber.panels();
+        // Replaced panels keep their place, the event logger is inherited
+        assert!(matches!(&panels[0], TemplatePanel::MainWork { content, .. } if content.starts_with("Gerber")));
+        assert!(matches!(&panels[1], TemplatePanel::EventLogger { .. }));
+        assert!(matches!(&panels[2], TemplatePanel::Controls { buttons } if buttons[0].id == "open_gerber"));
+        assert_eq!(panels[3].tab_type(), TabType::Generic("Layers".to_string()));
+        assert!(gerber.settings.panel().global_units_mils);
+
+        let mut registry = MobiusTemplateRegistry::default();
+        registry.register(template(r#"(name: "fine_pitch", extends: "gerber_viewer", settings: (global_units_mils: false))"#));
+        let fine_pitch = registry.resolve("fine_pitch").unwrap();
+        assert_eq!(fine_pitch.description, "PCB Gerber file viewer");
+        assert_eq!(fine_pitch.panels().len(), 4);
+        assert!(!fine_pitch.settings.panel().global_units_mils);
+        assert!(fine_pitch.settings.panel().use_24_hour_clock);
+    }
+
+    #[test]
+    fn test_template_inheritance_errors() {
+        let mut registry = MobiusTemplateRegistry::default();
+        registry.register(template(r#"(name: "a", extends: "b")"#));
+        registry.register(template(r#"(name: "b", extends: "c")"#));
+        registry.register(template(r#"(name: "c", extends: "a")"#));
+        registry.register(template(r#"(name: "orphan", extends: "missing")"#));
+        registry.register(template(r#"(name: "double_logs", extends: "base_engineering_app", add_tabs: [(title: "Log", tab_type: EventLogger)])"#));
+        registry.register(template(r#"(name: "no_layers", extends: "base_engineering_app", panels: [Generic(title: "Layers")])"#));
+
+        let cycle = registry.resolve("a").unwrap_err();
+        assert!(matches!(&cycle, TemplateError::Cycle(chain) if chain == &["a", "b", "c", "a"]));
+        assert_eq!(cycle.to_string(), "templates extend each other in a cycle: a → b → c → a");
+        assert!(matches!(registry.resolve("orphan"), Err(TemplateError::UnknownParent { parent, .. }) if parent == "missing"));
+        let conflict = registry.resolve("double_logs").unwrap_err();
+        assert!(matches!(&conflict, TemplateError::Conflict { template, field, .. } if template == "double_logs" && field == "add_tabs"));
+        let conflict = registry.resolve("no_layers").unwrap_err();
+        assert!(conflict.to_string().starts_with("template 'no_layers', field `panels`"));
+        assert_eq!(registry.check().len(), 6);
+    }
+
+    struct LayersPlugin;
+
+    impl MobiusPlugin for LayersPlugin {
+        fn name(&self) -> &str {
+            "layers"
+        }
+
+        fn build(&self, plugins: &mut MobiusPlugins) {
+            plugins
+                .add_tab(TabType::Generic("Layers".to_string()), "🗂 Layers", |ui, _world| {
+                    ui.label("Layers");
+                })
+                .add_tab(TabType::MainWork, "Board", |ui, _world| {
+                    ui.label("Board");
+                })
+                .add_settings_section("Layers", |ui, _world| {
+                    ui.label("Layer colors");
+                })
+                .add_system(|world| {
+                    for mut area in world.query::<&mut MainWorkArea>().iter_mut(world) {
+                        area.content = "Board".to_string();
+                    }
+                });
+        }
+
+        fn spawn(&self, world: &mut World) {
+            world.spawn(GenericTab { title: "Layers".to_string(), content: "Top, Bottom".to_string() });
+        }
+    }
+
+    #[test]
+    fn test_plugins() {
+        let mut plugins = MobiusPlugins::new().add_plugin(CorePlugin).add_plugin(LayersPlugin);
+        assert_eq!(plugins.names(), ["core", "layers"]);
+        let tabs: Vec<(TabType, String)> = plugins.tabs().map(|(tab, title)| (tab.clone(), title.to_string())).collect();
+        assert_eq!(tabs.len(), 5);
+        // The main work area is drawn by the layers plugin but keeps the core plugin's place and title
+        assert_eq!(tabs[0], (TabType::MainWork, "📋 Main".to_string()));
+        assert_eq!(tabs[4], (TabType::Generic("Layers".to_string()), "🗂 Layers".to_string()));
+
+        let mut world = World::new();
+        world.spawn(MainWorkArea { content: String::new(), background_color: [0.0; 4] });
+        plugins.spawn(&mut world);
+        assert_eq!(world.query::<&GenericTab>().single(&world).unwrap().content, "Top, Bottom");
+        plugins.run_systems(&mut world);
+        assert_eq!(world.query::<&MainWorkArea>().single(&world).unwrap().content, "Board");
+    }
+
+    #[test]
+    fn test_plugin_app_tabs() {
+        let registry = MobiusTemplateRegistry::default();
+        let mut world = World::new();
+        let app = registry.spawn_from_template(&mut world.commands(), "gerber_viewer").unwrap();
+        world.flush();
+        let template_tabs = world.get::<MobiusApp>(app).unwrap().tabs.clone();
+
+        // The template's Layers tab is listed even though no plugin draws it
+        let tabs = MobiusPlugins::new().add_plugin(CorePlugin).app_tabs(&template_tabs);
+        assert_eq!(tabs, template_tabs);
+        assert!(tabs.iter().any(|tab| tab.tab_type == TabType::Generic("Layers".to_string())));
+
+        // Tabs of plugins the template lacks come after the template's
+        let registry = MobiusTemplateRegistry::default();
+        let log_viewer = registry.resolve("log_viewer").unwrap();
+        let tabs = MobiusPlugins::new().add_plugin(CorePlugin).add_plugin(LayersPlugin).app_tabs(&log_viewer.tabs());
+        assert_eq!(&tabs[..log_viewer.tabs().len()], log_viewer.tabs().as_slice());
+        assert_eq!(tabs.len(), 5);
+        assert_eq!(tabs[4].tab_type, TabType::Generic("Layers".to_string()));
     }
-}
\ No newline at end of file
+}